@@ -2,7 +2,7 @@ mod node;
 mod proxy;
 mod vit_station;
 
-use crate::data::Challenge;
+use crate::data::{AccountVote, Challenge};
 use crate::Fund;
 use crate::Proposal;
 use crate::SimpleVoteStatus;
@@ -13,7 +13,6 @@ use chain_impl_mockchain::{
     fragment::{Fragment, FragmentId},
 };
 use chain_ser::deser::Deserialize;
-use jormungandr_lib::interfaces::AccountIdentifier;
 use jormungandr_lib::interfaces::{AccountState, FragmentLog, VotePlanStatus};
 use jormungandr_testing_utils::testing::node::Explorer;
 pub use jormungandr_testing_utils::testing::node::RestSettings as WalletBackendSettings;
@@ -151,15 +150,73 @@ impl WalletBackend {
     ) -> Result<bool, WalletBackendError> {
         Ok(fragment_ids.iter().all(|x| {
             let hash = jormungandr_lib::crypto::hash::Hash::from_str(&x.to_string()).unwrap();
-            self.explorer_client.transaction(hash).is_ok()
+            self.explorer_client
+                .transaction(hash)
+                .map(|response| response.data.is_some() && response.errors.is_none())
+                .unwrap_or(false)
         }))
     }
 
+    /// Votes cast by account joined with proposals from vit station. Votes for proposals
+    /// which are not known to vit station are skipped. When node does not serve account votes
+    /// (plain jormungandr), only given votes sent from this wallet are reported, with statuses
+    /// taken from fragment logs
     pub fn vote_statuses(
         &self,
-        _identifier: AccountIdentifier,
+        account_id: AccountId,
+        sent_votes: &[AccountVote],
     ) -> Result<Vec<SimpleVoteStatus>, WalletBackendError> {
-        unimplemented!()
+        let proposals = self.proposals()?;
+        let votes: Vec<AccountVote> = match self.node_client.account_votes(account_id)? {
+            Some(votes) => votes,
+            None => self.sent_votes_statuses(sent_votes)?,
+        };
+
+        Ok(votes
+            .into_iter()
+            .filter_map(|vote| {
+                let proposal = proposals.iter().find(|proposal| {
+                    proposal.chain_voteplan_id == vote.vote_plan_id
+                        && proposal.chain_proposal_index == vote.proposal_index as i64
+                })?;
+                Some(SimpleVoteStatus {
+                    chain_proposal_id: proposal.chain_proposal_id_as_str(),
+                    proposal_title: proposal.proposal_title.clone(),
+                    choice: match vote.choice {
+                        Some(choice) => proposal
+                            .chain_vote_options
+                            .0
+                            .iter()
+                            .find(|(_, option)| **option == choice)
+                            .map(|(text, _)| text.to_string())
+                            .unwrap_or_else(|| choice.to_string()),
+                        None => "private".to_string(),
+                    },
+                    fragment_id: vote.fragment_id,
+                    status: format!("{:?}", vote.status),
+                })
+            })
+            .collect())
+    }
+
+    fn sent_votes_statuses(
+        &self,
+        sent_votes: &[AccountVote],
+    ) -> Result<Vec<AccountVote>, WalletBackendError> {
+        let fragment_logs = self.fragment_logs()?;
+        Ok(sent_votes
+            .iter()
+            .cloned()
+            .map(|mut vote| {
+                if let Some(log) = FragmentId::from_str(&vote.fragment_id)
+                    .ok()
+                    .and_then(|id| fragment_logs.get(&id))
+                {
+                    vote.status = log.status().clone();
+                }
+                vote
+            })
+            .collect())
     }
 
     pub fn settings(&self) -> Result<Settings, WalletBackendError> {
@@ -180,6 +237,8 @@ pub enum WalletBackendError {
     VitStationConnectionError(#[from] VitRestError),
     #[error("node rest error")]
     NodeConnectionError(#[from] NodeRestError),
+    #[error("node request error")]
+    NodeRequestError(#[from] reqwest::Error),
     #[error("node rest error")]
     ProxyConnectionError(#[from] ProxyClientError),
     #[error("io error")]
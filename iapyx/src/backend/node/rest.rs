@@ -1,5 +1,6 @@
 #![allow(dead_code)]
 
+use crate::data::AccountVote;
 use chain_core::property::Deserialize;
 use chain_crypto::{bech32::Bech32, Ed25519, PublicKey};
 use chain_impl_mockchain::fragment::Fragment;
@@ -8,11 +9,14 @@ use jormungandr_lib::interfaces::{AccountState, FragmentLog, NodeStatsDto, VoteP
 pub use jormungandr_testing_utils::testing::node::RestError;
 use jormungandr_testing_utils::testing::node::{JormungandrRest, RestSettings};
 use regex::Regex;
+use reqwest::StatusCode;
 use std::collections::HashMap;
 use std::str::FromStr;
 use wallet::AccountId;
 #[derive(Clone)]
 pub struct WalletNodeRestClient {
+    address: String,
+    settings: RestSettings,
     rest_client: JormungandrRest,
 }
 
@@ -21,6 +25,8 @@ impl WalletNodeRestClient {
         let re = Regex::new(r"/v0/?").unwrap();
         let address = re.replace_all(&address, "");
         Self {
+            address: address.to_string(),
+            settings: settings.clone(),
             rest_client: JormungandrRest::new_with_custom_settings(address.to_string(), settings),
         }
     }
@@ -57,10 +63,12 @@ impl WalletNodeRestClient {
     }
 
     pub fn disable_logs(&mut self) {
+        self.settings.enable_debug = false;
         self.rest_client.disable_logger();
     }
 
     pub fn enable_logs(&mut self) {
+        self.settings.enable_debug = true;
         self.rest_client.enable_logger();
     }
 
@@ -85,4 +93,47 @@ impl WalletNodeRestClient {
     pub fn vote_plan_statuses(&self) -> Result<Vec<VotePlanStatus>, RestError> {
         self.rest_client.vote_plan_statuses()
     }
+
+    /// Votes cast by account. Endpoint is served by vitup mock, jormungandr does not provide it,
+    /// so `None` is returned when node responds with 404
+    pub fn account_votes(
+        &self,
+        account_id: AccountId,
+    ) -> Result<Option<Vec<AccountVote>>, reqwest::Error> {
+        let public_key: PublicKey<Ed25519> = account_id.into();
+        let path = format!(
+            "{}/v1/votes/account/{}",
+            self.address,
+            hex::encode(public_key.as_ref())
+        );
+        self.print_request_path(&path);
+        let response = self.client()?.get(&path).send()?;
+        self.print_response(&response);
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        response.error_for_status()?.json().map(Some)
+    }
+
+    /// Client which respects rest settings, e.g. trusts custom certificate
+    fn client(&self) -> Result<reqwest::blocking::Client, reqwest::Error> {
+        let builder = reqwest::blocking::Client::builder();
+        match &self.settings.certificate {
+            Some(certificate) => builder.add_root_certificate(certificate.clone()),
+            None => builder,
+        }
+        .build()
+    }
+
+    fn print_request_path(&self, path: &str) {
+        if self.settings.enable_debug {
+            println!("Request: {}", path);
+        }
+    }
+
+    fn print_response(&self, response: &reqwest::blocking::Response) {
+        if self.settings.enable_debug {
+            println!("Response: {:?}", response);
+        }
+    }
 }
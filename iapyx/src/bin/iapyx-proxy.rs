@@ -1,4 +1,6 @@
+use iapyx::utils::metrics::{render, RequestMetrics};
 use iapyx::{cli::args::proxy::IapyxProxyCommand, Protocol};
+use prometheus::Registry;
 use structopt::StructOpt;
 use warp::Filter;
 use warp_reverse_proxy::reverse_proxy_filter;
@@ -83,14 +85,36 @@ async fn main() {
             server_stub.http_node_address(),
         ));
 
-        root.and(fragments)
+        let votes = warp::path!("votes" / ..).and(reverse_proxy_filter(
+            "".to_string(),
+            server_stub.http_node_address(),
+        ));
+
+        root.and(fragments.or(votes))
     };
 
     let vit_version = warp::path!("vit-version").and(reverse_proxy_filter(
         "".to_string(),
         server_stub.http_vit_address(),
     ));
-    let app = api.and(v0.or(v1).or(vit_version));
+
+    let registry = Registry::new();
+    let request_metrics = RequestMetrics::new(&registry).unwrap();
+    let metrics = warp::path!("metrics")
+        .and(warp::get())
+        .map(move || render(&registry));
+
+    let app = api
+        .and(v0.or(v1).or(vit_version))
+        .or(metrics)
+        .with(warp::log::custom(move |info| {
+            request_metrics.observe(
+                info.method().as_str(),
+                info.path(),
+                info.status().as_u16(),
+                info.elapsed(),
+            )
+        }));
 
     match server_stub.protocol() {
         Protocol::Https {
@@ -1,3 +1,4 @@
+use crate::data::AccountVote;
 use crate::SimpleVoteStatus;
 use crate::Wallet;
 use crate::{data::Proposal as VitProposal, WalletBackend};
@@ -18,6 +19,8 @@ pub struct Controller {
     backend: WalletBackend,
     wallet: Wallet,
     settings: Settings,
+    /// Votes sent from this controller, used when backend does not serve account votes
+    sent_votes: Vec<AccountVote>,
 }
 
 impl Controller {
@@ -32,6 +35,7 @@ impl Controller {
             backend,
             wallet: Wallet::generate(words_length)?,
             settings,
+            sent_votes: Vec::new(),
         })
     }
 
@@ -45,6 +49,7 @@ impl Controller {
             backend,
             wallet: Wallet::recover(mnemonics, password)?,
             settings,
+            sent_votes: Vec::new(),
         })
     }
 
@@ -69,6 +74,7 @@ impl Controller {
             backend,
             wallet: Wallet::recover_from_account(account)?,
             settings,
+            sent_votes: Vec::new(),
         })
     }
 
@@ -95,6 +101,7 @@ impl Controller {
             backend,
             wallet: Wallet::recover_from_utxo(secret.as_ref().try_into().unwrap())?,
             settings,
+            sent_votes: Vec::new(),
         })
     }
 
@@ -112,6 +119,7 @@ impl Controller {
             backend,
             wallet: Wallet::recover_from_utxo(&data)?,
             settings,
+            sent_votes: Vec::new(),
         })
     }
 
@@ -222,12 +230,8 @@ impl Controller {
                 proposal_index,
             })?;
 
-        let transaction = self.wallet.vote(
-            self.settings.clone(),
-            &proposal.clone().into(),
-            Choice::new(choice),
-        )?;
-        Ok(self.backend.send_fragment(transaction.to_vec())?)
+        let proposal = proposal.clone();
+        self.vote(&proposal, Choice::new(choice))
     }
 
     pub fn vote(
@@ -238,7 +242,19 @@ impl Controller {
         let transaction =
             self.wallet
                 .vote(self.settings.clone(), &proposal.clone().into(), choice)?;
-        Ok(self.backend.send_fragment(transaction.to_vec())?)
+        let fragment_id = self.backend.send_fragment(transaction.to_vec())?;
+        self.sent_votes.push(AccountVote {
+            vote_plan_id: proposal.chain_voteplan_id.clone(),
+            proposal_index: proposal.chain_proposal_index as u8,
+            choice: if proposal.chain_voteplan_payload == "public" {
+                Some(choice.as_byte())
+            } else {
+                None
+            },
+            fragment_id: fragment_id.to_string(),
+            status: FragmentStatus::Pending,
+        });
+        Ok(fragment_id)
     }
 
     pub fn get_proposals(&mut self) -> Result<Vec<VitProposal>, ControllerError> {
@@ -256,9 +272,7 @@ impl Controller {
     }
 
     pub fn active_votes(&self) -> Result<Vec<SimpleVoteStatus>, ControllerError> {
-        Ok(self
-            .backend
-            .vote_statuses(self.wallet.identifier(self.settings.discrimination))?)
+        Ok(self.backend.vote_statuses(self.id(), &self.sent_votes)?)
     }
 }
 
@@ -1,5 +1,6 @@
 use chain_impl_mockchain::{certificate::VotePlanId, vote::Options};
 use itertools::Itertools;
+use jormungandr_lib::interfaces::FragmentStatus;
 use jormungandr_testing_utils::wallet::committee::election_key_from_base32;
 use serde::{Deserialize, Serialize};
 use std::{collections::HashMap, convert::TryFrom, fmt, str};
@@ -206,18 +207,31 @@ pub struct SimpleVoteStatus {
     pub chain_proposal_id: String,
     pub proposal_title: String,
     pub choice: String,
+    pub fragment_id: String,
+    pub status: String,
 }
 
 impl fmt::Display for SimpleVoteStatus {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "# {}, '{}' -> Choice:  {}",
-            self.chain_proposal_id, self.proposal_title, self.choice
+            "# {}, '{}' -> Choice:  {} [{}: {}]",
+            self.chain_proposal_id, self.proposal_title, self.choice, self.fragment_id, self.status
         )
     }
 }
 
+/// Vote cast by account, as returned by `/api/v1/votes/account/{account_id}`.
+/// Choice is only known for public votes
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct AccountVote {
+    pub vote_plan_id: String,
+    pub proposal_index: u8,
+    pub choice: Option<u8>,
+    pub fragment_id: String,
+    pub status: FragmentStatus,
+}
+
 pub type VoteOptionsMap = HashMap<String, u8>;
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
@@ -0,0 +1,68 @@
+use prometheus::{
+    Encoder, HistogramOpts, HistogramVec, IntCounterVec, Opts, Registry, TextEncoder,
+};
+use std::time::Duration;
+
+/// Request counts and latency histograms per route. Path segments which look like
+/// identifiers (numbers, hashes, keys) are replaced with `:id`, so every route
+/// has single label value regardless of parameters
+#[derive(Clone)]
+pub struct RequestMetrics {
+    requests: IntCounterVec,
+    latency: HistogramVec,
+}
+
+impl RequestMetrics {
+    pub fn new(registry: &Registry) -> Result<Self, prometheus::Error> {
+        let requests = IntCounterVec::new(
+            Opts::new("http_requests_total", "Number of handled http requests"),
+            &["method", "route", "status"],
+        )?;
+        let latency = HistogramVec::new(
+            HistogramOpts::new(
+                "http_request_duration_seconds",
+                "Latency of http requests in seconds",
+            ),
+            &["method", "route"],
+        )?;
+        registry.register(Box::new(requests.clone()))?;
+        registry.register(Box::new(latency.clone()))?;
+        Ok(Self { requests, latency })
+    }
+
+    pub fn observe(&self, method: &str, path: &str, status: u16, elapsed: Duration) {
+        let route = route_label(path);
+        self.requests
+            .with_label_values(&[method, &route, &status.to_string()])
+            .inc();
+        self.latency
+            .with_label_values(&[method, &route])
+            .observe(elapsed.as_secs_f64());
+    }
+}
+
+pub fn route_label(path: &str) -> String {
+    path.split('/')
+        .map(|segment| {
+            if is_identifier(segment) {
+                ":id"
+            } else {
+                segment
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn is_identifier(segment: &str) -> bool {
+    !segment.is_empty() && (segment.chars().all(|c| c.is_ascii_digit()) || segment.len() >= 32)
+}
+
+/// Encodes all metrics from registry in prometheus text format
+pub fn render(registry: &Registry) -> String {
+    let mut buffer = Vec::new();
+    TextEncoder::new()
+        .encode(&registry.gather(), &mut buffer)
+        .unwrap();
+    String::from_utf8(buffer).unwrap()
+}
@@ -1,3 +1,4 @@
 pub mod datetime;
+pub mod metrics;
 pub mod seed;
 pub mod serde;
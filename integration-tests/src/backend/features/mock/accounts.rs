@@ -0,0 +1,56 @@
+use super::{account_id, funded_account, get_status, start_mock};
+use assert_fs::TempDir;
+use jormungandr_testing_utils::wallet::Wallet;
+
+#[test]
+pub fn account_cannot_be_created_twice_before_next_block() {
+    let testing_directory = TempDir::new().unwrap();
+    let mock = start_mock(&testing_directory);
+    let client = mock.client().unwrap();
+    let account = account_id(&Wallet::new_account(&mut rand::rngs::OsRng));
+
+    client.create_account(&account, 1_000).unwrap();
+    assert!(client.create_account(&account, 1_000).is_err());
+    client.advance_time(1).unwrap();
+    assert!(client.create_account(&account, 1_000).is_err());
+    client.top_up_account(&account, 1_000).unwrap();
+}
+
+#[test]
+pub fn drained_account_reports_lowered_balance() {
+    let testing_directory = TempDir::new().unwrap();
+    let mock = start_mock(&testing_directory);
+    let client = mock.client().unwrap();
+    let wallet = funded_account(&mock, 1_000);
+    let account = account_id(&wallet);
+
+    let reported_value = || {
+        mock.context()
+            .lock()
+            .unwrap()
+            .state()
+            .ledger()
+            .account_state(&wallet.identifier())
+            .unwrap()
+            .value
+            .0
+    };
+
+    client.drain_account(&account, Some(400)).unwrap();
+    assert_eq!(reported_value(), 600);
+
+    client.reset_account(&account).unwrap();
+    assert_eq!(reported_value(), 1_000);
+    assert!(client.top_up_account("not-an-account", 1).is_err());
+}
+
+#[test]
+pub fn invalid_account_id_is_rejected_with_bad_request() {
+    let testing_directory = TempDir::new().unwrap();
+    let mock = start_mock(&testing_directory);
+
+    assert_eq!(get_status(&mock, "api/v1/votes/account/not-hex"), 400);
+    assert_eq!(get_status(&mock, "api/v0/account/not-hex"), 400);
+    // mock is still usable after invalid request
+    assert_eq!(get_status(&mock, "api/v0/settings"), 200);
+}
@@ -0,0 +1,39 @@
+use super::start_mock;
+use assert_fs::TempDir;
+
+#[test]
+pub fn frozen_time_moves_only_when_advanced() {
+    let testing_directory = TempDir::new().unwrap();
+    let mock = start_mock(&testing_directory);
+    let client = mock.client().unwrap();
+    let context = mock.context();
+
+    let before = context.lock().unwrap().state().ledger().time_status();
+    assert!(before.frozen);
+    std::thread::sleep(std::time::Duration::from_secs(2));
+    let after = context.lock().unwrap().state().ledger().time_status();
+    assert_eq!(before.block_date, after.block_date);
+
+    client.advance_time(5).unwrap();
+    let advanced = context.lock().unwrap().state().ledger().time_status();
+    assert_eq!(
+        advanced.block_date.slot(),
+        before.block_date.slot() + 5,
+        "clock should be moved by exactly 5 slots"
+    );
+}
+
+#[test]
+pub fn jump_to_tally_start_moves_time_to_tally_epoch() {
+    let testing_directory = TempDir::new().unwrap();
+    let mock = start_mock(&testing_directory);
+    let client = mock.client().unwrap();
+
+    client.jump_to_tally_start().unwrap();
+    let context = mock.context();
+    let context = context.lock().unwrap();
+    assert_eq!(
+        context.state().ledger().time_status().block_date.epoch() as u64,
+        context.state().parameters().vote_tally
+    );
+}
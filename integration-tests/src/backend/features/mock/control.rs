@@ -0,0 +1,14 @@
+use super::{get_status, start_mock_with};
+use assert_fs::TempDir;
+use vitup::mock::Configuration;
+
+#[test]
+pub fn client_sends_api_token_to_control_api() {
+    let testing_directory = TempDir::new().unwrap();
+    let mut configuration = Configuration::new(testing_directory.path());
+    configuration.token = Some("YWJjZGVm".to_string());
+    let mock = start_mock_with(configuration, Default::default());
+
+    assert_ne!(get_status(&mock, "api/control/logs/get"), 200);
+    assert!(!mock.client().unwrap().get_logs().unwrap().is_empty());
+}
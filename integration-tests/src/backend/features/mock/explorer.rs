@@ -0,0 +1,72 @@
+use super::{funded_account, send_fragment, start_mock, transfer};
+use assert_fs::TempDir;
+use chain_core::property::Fragment as _;
+use vitup::mock::MockController;
+
+#[test]
+pub fn explorer_serves_transactions_from_blocks() {
+    let testing_directory = TempDir::new().unwrap();
+    let mock = start_mock(&testing_directory);
+    let mut sender = funded_account(&mock, 1_000);
+
+    let fragment = transfer(&mock, &mut sender, 10);
+    send_fragment(&mock, &fragment);
+    mock.client().unwrap().advance_time(1).unwrap();
+
+    let id = fragment.id().to_string();
+    let response = explorer_query(
+        &mock,
+        &format!(
+            "{{ transaction(id: \"{}\") {{ id blocks {{ id }} inputs {{ address {{ id }} }} }} }}",
+            id
+        ),
+    );
+
+    assert_eq!(response["data"]["transaction"]["id"], id);
+    assert_eq!(
+        response["data"]["transaction"]["blocks"]
+            .as_array()
+            .unwrap()
+            .len(),
+        1
+    );
+    let input = response["data"]["transaction"]["inputs"][0]["address"]["id"]
+        .as_str()
+        .unwrap();
+    assert!(input.starts_with("ca1"), "input should be bech32 address");
+}
+
+fn explorer_query(mock: &MockController, query: &str) -> serde_json::Value {
+    reqwest::blocking::Client::new()
+        .post(&format!("{}/explorer/graphql", mock.base_url()))
+        .json(&serde_json::json!({ "query": query }))
+        .send()
+        .unwrap()
+        .json()
+        .unwrap()
+}
+
+#[test]
+pub fn explorer_serves_block_on_every_slot() {
+    let testing_directory = TempDir::new().unwrap();
+    let mock = start_mock(&testing_directory);
+    let tip_query = "{ tip { block { id chainLength date { epoch { id } slot } } } }";
+    let chain_length =
+        |tip: &serde_json::Value| -> u32 { tip["chainLength"].as_str().unwrap().parse().unwrap() };
+
+    let before = explorer_query(&mock, tip_query)["data"]["tip"]["block"].clone();
+    mock.client().unwrap().advance_time(3).unwrap();
+    let tip = explorer_query(&mock, tip_query)["data"]["tip"]["block"].clone();
+    assert_eq!(chain_length(&tip), chain_length(&before) + 3);
+
+    let epoch = tip["date"]["epoch"]["id"].as_str().unwrap();
+    let response = explorer_query(
+        &mock,
+        &format!(
+            "{{ epoch(id: \"{}\") {{ lastBlock {{ id }} }} block(id: {}) {{ chainLength }} }}",
+            epoch, tip["id"]
+        ),
+    );
+    assert_eq!(response["data"]["epoch"]["lastBlock"]["id"], tip["id"]);
+    assert_eq!(response["data"]["block"]["chainLength"], tip["chainLength"]);
+}
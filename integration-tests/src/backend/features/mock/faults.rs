@@ -0,0 +1,73 @@
+use super::{get_status, start_mock};
+use assert_fs::TempDir;
+use vitup::mock::{Fault, FaultRule};
+
+fn status_code_fault(path: &str, code: u16) -> FaultRule {
+    FaultRule {
+        path: path.to_string(),
+        method: None,
+        account: None,
+        proposal: None,
+        fault: Fault::StatusCode(code),
+        probability: None,
+        count: None,
+    }
+}
+
+#[test]
+pub fn fault_overrides_response_until_removed() {
+    let testing_directory = TempDir::new().unwrap();
+    let mock = start_mock(&testing_directory);
+    let client = mock.client().unwrap();
+
+    let id = client
+        .add_fault(&status_code_fault("/v0/settings", 503))
+        .unwrap();
+    assert_eq!(get_status(&mock, "api/v0/settings"), 503);
+    assert_eq!(get_status(&mock, "api/v0/fund"), 200);
+    assert_eq!(client.list_faults().unwrap()[0].triggered, 1);
+
+    client.remove_fault(id).unwrap();
+    assert_eq!(get_status(&mock, "api/v0/settings"), 200);
+}
+
+#[test]
+pub fn fault_expires_after_count() {
+    let testing_directory = TempDir::new().unwrap();
+    let mock = start_mock(&testing_directory);
+    let client = mock.client().unwrap();
+
+    let mut rule = status_code_fault("/v0/*", 500);
+    rule.count = Some(1);
+    client.add_fault(&rule).unwrap();
+
+    assert_eq!(get_status(&mock, "api/v0/settings"), 500);
+    assert_eq!(get_status(&mock, "api/v0/settings"), 200);
+}
+
+#[test]
+pub fn fault_with_invalid_probability_is_rejected() {
+    let testing_directory = TempDir::new().unwrap();
+    let mock = start_mock(&testing_directory);
+    let client = mock.client().unwrap();
+
+    let mut rule = status_code_fault("/v0/settings", 500);
+    rule.probability = Some(1.5);
+    assert!(client.add_fault(&rule).is_err());
+    assert!(client.list_faults().unwrap().is_empty());
+}
+
+#[test]
+pub fn account_filter_on_path_without_parameter_is_rejected() {
+    let testing_directory = TempDir::new().unwrap();
+    let mock = start_mock(&testing_directory);
+    let client = mock.client().unwrap();
+
+    let mut rule = status_code_fault("/v0/message", 500);
+    rule.account = Some("ca1q5".to_string());
+    assert!(client.add_fault(&rule).is_err());
+
+    rule.path = "/v0/account/*".to_string();
+    client.add_fault(&rule).unwrap();
+    assert_eq!(client.list_faults().unwrap().len(), 1);
+}
@@ -0,0 +1,62 @@
+use super::{fragment_log, funded_account, send_fragment, start_mock, transfer};
+use assert_fs::TempDir;
+use vitup::mock::{FragmentRecieveStrategy, FragmentRule};
+
+fn reject_rule(sender: Option<String>, reason: &str) -> FragmentRule {
+    FragmentRule {
+        fragment_type: None,
+        sender,
+        vote_plan: None,
+        proposal_index: None,
+        every_nth: None,
+        strategy: FragmentRecieveStrategy::Reject,
+        reason: Some(reason.to_string()),
+        delay_slots: 0,
+    }
+}
+
+#[test]
+pub fn fragment_rule_rejects_fragments_of_sender() {
+    let testing_directory = TempDir::new().unwrap();
+    let mock = start_mock(&testing_directory);
+    let client = mock.client().unwrap();
+    let mut blocked = funded_account(&mock, 1_000);
+    let mut allowed = funded_account(&mock, 1_000);
+
+    client
+        .add_fragment_rule(&reject_rule(
+            Some(super::account_id(&blocked)),
+            "sender blocked",
+        ))
+        .unwrap();
+
+    let rejected = transfer(&mock, &mut blocked, 10);
+    let accepted = transfer(&mock, &mut allowed, 10);
+    assert_eq!(send_fragment(&mock, &rejected), 200);
+    assert_eq!(send_fragment(&mock, &accepted), 200);
+    client.advance_time(1).unwrap();
+
+    assert!(fragment_log(&mock, &rejected).is_rejected());
+    assert!(fragment_log(&mock, &accepted).is_in_a_block());
+    assert_eq!(client.list_fragment_rules().unwrap()[0].matched, 1);
+}
+
+#[test]
+pub fn reject_all_fragments_overrides_ledger() {
+    let testing_directory = TempDir::new().unwrap();
+    let mock = start_mock(&testing_directory);
+    let client = mock.client().unwrap();
+    let mut sender = funded_account(&mock, 1_000);
+
+    client.reject_all_fragments().unwrap();
+    let fragment = transfer(&mock, &mut sender, 10);
+    send_fragment(&mock, &fragment);
+    client.advance_time(1).unwrap();
+    assert!(fragment_log(&mock, &fragment).is_rejected());
+
+    client.reset_fragments_behavior().unwrap();
+    let fragment = transfer(&mock, &mut funded_account(&mock, 1_000), 10);
+    send_fragment(&mock, &fragment);
+    client.advance_time(1).unwrap();
+    assert!(fragment_log(&mock, &fragment).is_in_a_block());
+}
@@ -0,0 +1,96 @@
+mod accounts;
+mod clock;
+mod control;
+mod explorer;
+mod faults;
+mod fragment_rules;
+mod persistence;
+mod tally;
+mod tls;
+mod vit;
+
+use assert_fs::TempDir;
+use chain_core::property::{Fragment as _, Serialize};
+use chain_impl_mockchain::fragment::Fragment;
+use chain_impl_mockchain::value::Value;
+use jormungandr_lib::interfaces::FragmentLog;
+use jormungandr_testing_utils::testing::FragmentBuilder;
+use jormungandr_testing_utils::wallet::Wallet;
+use vitup::config::VitStartParameters;
+use vitup::mock::{Configuration, MockController};
+
+pub fn start_mock(testing_directory: &TempDir) -> MockController {
+    start_mock_with(
+        Configuration::new(testing_directory.path()),
+        Default::default(),
+    )
+}
+
+pub fn start_mock_with(
+    configuration: Configuration,
+    parameters: VitStartParameters,
+) -> MockController {
+    let mock = MockController::start(configuration, parameters).unwrap();
+    mock.client().unwrap().freeze_time().unwrap();
+    mock
+}
+
+/// Account id (public key in hex) as accepted by mock control api
+pub fn account_id(wallet: &Wallet) -> String {
+    hex::encode(wallet.identifier().as_ref().as_ref())
+}
+
+/// Creates account funded by mock faucet and waits for transfer to be applied
+pub fn funded_account(mock: &MockController, value: u64) -> Wallet {
+    let wallet = Wallet::new_account(&mut rand::rngs::OsRng);
+    let client = mock.client().unwrap();
+    client.create_account(&account_id(&wallet), value).unwrap();
+    client.advance_time(1).unwrap();
+    wallet
+}
+
+pub fn transfer(mock: &MockController, sender: &mut Wallet, value: u64) -> Fragment {
+    let receiver = Wallet::new_account(&mut rand::rngs::OsRng);
+    let (block0_hash, fees) = {
+        let context = mock.context();
+        let context = context.lock().unwrap();
+        let ledger = context.state().ledger();
+        (ledger.block0_hash(), ledger.fees())
+    };
+    let fragment = FragmentBuilder::new(&block0_hash.into(), &fees)
+        .transaction(sender, receiver.address(), Value(value))
+        .unwrap();
+    sender.confirm_transaction();
+    fragment
+}
+
+/// Sends fragment through jormungandr api served by mock and returns http status
+pub fn send_fragment(mock: &MockController, fragment: &Fragment) -> u16 {
+    reqwest::blocking::Client::new()
+        .post(&format!("{}/api/v0/message", mock.base_url()))
+        .body(fragment.serialize_as_vec().unwrap())
+        .send()
+        .unwrap()
+        .status()
+        .as_u16()
+}
+
+pub fn fragment_log(mock: &MockController, fragment: &Fragment) -> FragmentLog {
+    let id = fragment.id().to_string();
+    mock.context()
+        .lock()
+        .unwrap()
+        .state()
+        .ledger()
+        .fragment_logs()
+        .into_iter()
+        .find(|log| log.fragment_id().to_string() == id)
+        .unwrap()
+}
+
+pub fn get_status(mock: &MockController, path: &str) -> u16 {
+    reqwest::blocking::get(&format!("{}/{}", mock.base_url(), path))
+        .unwrap()
+        .status()
+        .as_u16()
+}
@@ -0,0 +1,33 @@
+use super::{funded_account, start_mock};
+use assert_fs::TempDir;
+
+#[test]
+pub fn saved_state_is_restored() {
+    let testing_directory = TempDir::new().unwrap();
+    let mock = start_mock(&testing_directory);
+    let client = mock.client().unwrap();
+    let account = funded_account(&mock, 1_000);
+    let fund_id = mock
+        .context()
+        .lock()
+        .unwrap()
+        .state()
+        .vit()
+        .funds()
+        .last()
+        .unwrap()
+        .id;
+
+    client.save_state().unwrap();
+    client.set_fund_id(fund_id as u32 + 10).unwrap();
+    client.load_state().unwrap();
+
+    let context = mock.context();
+    let context = context.lock().unwrap();
+    assert_eq!(context.state().vit().funds().last().unwrap().id, fund_id);
+    assert!(context
+        .state()
+        .ledger()
+        .account_state(&account.identifier())
+        .is_some());
+}
@@ -0,0 +1,84 @@
+use super::start_mock_with;
+use assert_fs::TempDir;
+use vitup::config::VitStartParameters;
+use vitup::mock::{Configuration, MockController};
+
+fn tally_and_assert_results(mock: &MockController) {
+    let client = mock.client().unwrap();
+    client.jump_to_tally_start().unwrap();
+
+    let fragment_ids = client.tally().unwrap();
+    assert!(!fragment_ids.is_empty());
+    client.advance_time(1).unwrap();
+
+    let context = mock.context();
+    let context = context.lock().unwrap();
+    let ledger = context.state().ledger();
+    for log in ledger.fragment_logs().iter().filter(|log| {
+        fragment_ids
+            .iter()
+            .any(|id| *id == log.fragment_id().to_string())
+    }) {
+        assert!(log.is_in_a_block(), "tally fragment not applied: {:?}", log);
+    }
+    for vote_plan in ledger.active_vote_plans() {
+        for proposal in vote_plan.proposals.iter() {
+            assert!(
+                proposal
+                    .tally
+                    .as_ref()
+                    .and_then(|tally| tally.result())
+                    .is_some(),
+                "no tally result for proposal {} of vote plan {}",
+                proposal.index,
+                vote_plan.id
+            );
+        }
+    }
+}
+
+#[test]
+pub fn public_tally_produces_results() {
+    let testing_directory = TempDir::new().unwrap();
+    let parameters = VitStartParameters {
+        proposals: 10,
+        private: false,
+        ..Default::default()
+    };
+    let mock = start_mock_with(Configuration::new(testing_directory.path()), parameters);
+    tally_and_assert_results(&mock);
+}
+
+#[test]
+pub fn private_tally_is_decrypted_by_committee() {
+    let testing_directory = TempDir::new().unwrap();
+    let parameters = VitStartParameters {
+        proposals: 10,
+        private: true,
+        ..Default::default()
+    };
+    let mock = start_mock_with(Configuration::new(testing_directory.path()), parameters);
+    tally_and_assert_results(&mock);
+}
+
+#[test]
+pub fn tally_before_vote_end_is_rejected() {
+    let testing_directory = TempDir::new().unwrap();
+    let mock = start_mock_with(
+        Configuration::new(testing_directory.path()),
+        Default::default(),
+    );
+    let client = mock.client().unwrap();
+
+    assert!(client.tally().is_err());
+    assert!(mock
+        .context()
+        .lock()
+        .unwrap()
+        .state()
+        .ledger()
+        .active_vote_plans()
+        .iter()
+        .flat_map(|vote_plan| vote_plan.proposals.iter())
+        .all(|proposal| proposal.tally.is_none()));
+}
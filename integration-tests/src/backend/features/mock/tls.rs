@@ -0,0 +1,21 @@
+use super::start_mock_with;
+use assert_fs::TempDir;
+use vitup::mock::{Configuration, Tls};
+
+#[test]
+pub fn generated_ca_is_reused_on_restart() {
+    let testing_directory = TempDir::new().unwrap();
+    let mut configuration = Configuration::new(testing_directory.path());
+    configuration.tls = Some(Tls::default());
+
+    let mock = start_mock_with(configuration.clone(), Default::default());
+    assert!(mock.base_url().starts_with("https"));
+    assert!(mock.client().unwrap().is_up());
+    let ca_path = mock.context().lock().unwrap().tls_dir().join("ca.crt");
+    let ca = std::fs::read_to_string(&ca_path).unwrap();
+    mock.shutdown();
+
+    let mock = start_mock_with(configuration, Default::default());
+    assert!(mock.client().unwrap().is_up());
+    assert_eq!(std::fs::read_to_string(&ca_path).unwrap(), ca);
+}
@@ -0,0 +1,11 @@
+use super::{get_status, start_mock};
+use assert_fs::TempDir;
+
+#[test]
+pub fn unknown_fund_and_proposal_are_not_found() {
+    let testing_directory = TempDir::new().unwrap();
+    let mock = start_mock(&testing_directory);
+
+    assert_eq!(get_status(&mock, "api/v0/fund/999999"), 404);
+    assert_eq!(get_status(&mock, "api/v0/proposals/999999"), 404);
+}
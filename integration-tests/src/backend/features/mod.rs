@@ -1 +1,2 @@
+mod mock;
 //mod persistent_log;
@@ -11,6 +11,7 @@ use std::path::Path;
 use std::str::FromStr;
 use vit_servicing_station_tests::common::data::ArbitraryValidVotingTemplateGenerator;
 use vitup::config::{InitialEntry, Initials};
+use vitup::scenario::committee::{PrivateCommittee, COMMITTEE_DIRECTORY};
 use vitup::scenario::network::setup_network;
 use vitup::setup::start::quick::QuickVitBackendSettingsBuilder;
 
@@ -127,12 +128,15 @@ pub fn private_vote_e2e_flow() -> std::result::Result<(), Error> {
         .find(|c_vote_plan| c_vote_plan.id == Hash::from_str(&fund1_vote_plan.id()).unwrap().into())
         .unwrap();
 
-    let shares = controller
-        .settings()
-        .private_vote_plans
-        .get(&fund_name)
-        .unwrap()
-        .decrypt_tally(&vote_plan_status.clone().into());
+    let (shares, _) = PrivateCommittee::read_from(
+        controller
+            .working_directory()
+            .path()
+            .join(COMMITTEE_DIRECTORY),
+    )
+    .unwrap()
+    .decrypt_vote_plan(&mut rand::rngs::OsRng, vote_plan_status)
+    .unwrap();
 
     fragment_sender
         .send_private_vote_tally(
@@ -14,6 +14,7 @@ use std::path::PathBuf;
 use std::str::FromStr;
 use vit_servicing_station_tests::common::data::ArbitraryValidVotingTemplateGenerator;
 use vitup::config::VitStartParameters;
+use vitup::scenario::committee::{PrivateCommittee, COMMITTEE_DIRECTORY};
 use vitup::scenario::network::setup_network;
 use vitup::setup::start::quick::QuickVitBackendSettingsBuilder;
 
@@ -102,12 +103,15 @@ pub fn private_vote_test_scenario(
         .find(|c_vote_plan| c_vote_plan.id == Hash::from_str(&vote_plan.id()).unwrap().into())
         .unwrap();
 
-    let shares = controller
-        .settings()
-        .private_vote_plans
-        .get(&fund_name)
-        .unwrap()
-        .decrypt_tally(&vote_plan_status.clone().into());
+    let (shares, _) = PrivateCommittee::read_from(
+        controller
+            .working_directory()
+            .path()
+            .join(COMMITTEE_DIRECTORY),
+    )
+    .unwrap()
+    .decrypt_vote_plan(&mut rand::rngs::OsRng, vote_plan_status)
+    .unwrap();
 
     match controller.fragment_sender().send_private_vote_tally(
         &mut committee,
@@ -1,8 +1,10 @@
+use crate::client::replay::{read_recording, Replayer};
 use crate::client::rest::VitupAdminRestClient;
 use crate::client::rest::VitupDisruptionRestClient;
 use crate::client::rest::VitupRest;
 use crate::config::VitStartParameters;
 use crate::error::Result;
+use crate::mock::{Fault, FaultRule, FragmentRecieveStrategy, FragmentRule};
 use jormungandr_testing_utils::testing::fragments::PersistentLogViewer;
 use std::path::PathBuf;
 use structopt::StructOpt;
@@ -15,6 +17,10 @@ pub struct VitupClientCommand {
     #[structopt(short, long, env = "VIT_ENDPOINT")]
     endpoint: Option<String>,
 
+    /// CA certificate (pem) to trust when endpoint uses https
+    #[structopt(long, env = "VIT_CA")]
+    ca: Option<PathBuf>,
+
     #[structopt(subcommand)]
     command: Command,
 }
@@ -25,10 +31,13 @@ impl VitupClientCommand {
             return command.exec();
         }
         let endpoint = self.endpoint.expect("no 'endpoint' arg defined");
-        let rest = match self.token {
+        let mut rest = match self.token {
             Some(token) => VitupRest::new_with_token(token, endpoint),
             None => VitupRest::new(endpoint),
         };
+        if let Some(ca) = self.ca {
+            rest = rest.with_ca_certificate(ca)?;
+        }
 
         match self.command {
             Command::Disruption(disruption_command) => disruption_command.exec(rest.into()),
@@ -56,6 +65,8 @@ pub enum DisruptionCommand {
     Files(FilesCommand),
     // start mock env
     Control(ControlCommand),
+    /// per-endpoint fault injection rules
+    Faults(FaultsCommand),
 }
 
 impl DisruptionCommand {
@@ -64,6 +75,122 @@ impl DisruptionCommand {
             Self::Logs(logs_command) => logs_command.exec(rest),
             Self::Files(files_command) => files_command.exec(rest),
             Self::Control(control_command) => control_command.exec(rest),
+            Self::Faults(faults_command) => faults_command.exec(rest),
+        }
+    }
+}
+
+#[derive(StructOpt, Debug)]
+pub enum FaultsCommand {
+    /// lists active rules with number of times they were triggered
+    List,
+    /// adds new rule
+    Add(AddFaultCommand),
+    /// removes rule with given id
+    Remove(RemoveFaultCommand),
+    /// removes all rules
+    Clear,
+}
+
+impl FaultsCommand {
+    pub fn exec(self, rest: VitupDisruptionRestClient) -> Result<()> {
+        match self {
+            Self::List => {
+                println!("{}", serde_json::to_string_pretty(&rest.list_faults()?)?);
+                Ok(())
+            }
+            Self::Add(add_command) => add_command.exec(rest),
+            Self::Remove(remove_command) => {
+                rest.remove_fault(remove_command.id).map_err(Into::into)
+            }
+            Self::Clear => rest.clear_faults().map_err(Into::into),
+        }
+    }
+}
+
+#[derive(StructOpt, Debug)]
+pub struct AddFaultCommand {
+    /// endpoint path, e.g. /v0/message. '*' matches any single segment
+    #[structopt(long = "path")]
+    pub path: String,
+
+    #[structopt(long = "method")]
+    pub method: Option<String>,
+
+    #[structopt(long = "account")]
+    pub account: Option<String>,
+
+    #[structopt(long = "proposal")]
+    pub proposal: Option<i32>,
+
+    /// probability in range [0.0, 1.0] that matching request is affected
+    #[structopt(long = "probability")]
+    pub probability: Option<f64>,
+
+    /// how many times rule can be triggered
+    #[structopt(long = "count")]
+    pub count: Option<u32>,
+
+    #[structopt(subcommand)]
+    pub fault: FaultKind,
+}
+
+impl AddFaultCommand {
+    pub fn exec(self, rest: VitupDisruptionRestClient) -> Result<()> {
+        let rule = FaultRule {
+            path: self.path,
+            method: self.method,
+            account: self.account,
+            proposal: self.proposal,
+            fault: self.fault.into(),
+            probability: self.probability,
+            count: self.count,
+        };
+        println!("{}", rest.add_fault(&rule)?);
+        Ok(())
+    }
+}
+
+#[derive(StructOpt, Debug)]
+pub struct RemoveFaultCommand {
+    #[structopt(long = "id")]
+    pub id: u32,
+}
+
+#[derive(StructOpt, Debug)]
+pub enum FaultKind {
+    /// responds with given status code
+    StatusCode {
+        #[structopt(long = "code")]
+        code: u16,
+    },
+    /// delays request
+    Latency {
+        #[structopt(long = "millis")]
+        millis: u64,
+    },
+    /// holds request and responds with 504. Without secs request hangs forever
+    Timeout {
+        #[structopt(long = "secs")]
+        secs: Option<u64>,
+    },
+    /// responds with body which cannot be parsed
+    MalformedBody,
+    /// responds with 429
+    TooManyRequests {
+        #[structopt(long = "retry-after")]
+        retry_after: Option<u64>,
+    },
+}
+
+impl From<FaultKind> for Fault {
+    fn from(kind: FaultKind) -> Self {
+        match kind {
+            FaultKind::StatusCode { code } => Fault::StatusCode(code),
+            FaultKind::Latency { millis } => Fault::Latency(millis),
+            FaultKind::Timeout { secs } => Fault::Timeout(secs),
+            FaultKind::MalformedBody => Fault::MalformedBody,
+            FaultKind::TooManyRequests { retry_after } => Fault::TooManyRequests(retry_after),
         }
     }
 }
@@ -74,6 +201,8 @@ pub enum LogsCommand {
     Clear,
     /// start advanced backend from scratch
     Get,
+    /// prints recorded requests and responses as json lines
+    Requests,
 }
 
 impl LogsCommand {
@@ -84,6 +213,10 @@ impl LogsCommand {
                 println!("{:?}", rest.get_logs());
                 Ok(())
             }
+            Self::Requests => {
+                println!("{}", rest.get_recorded_requests()?);
+                Ok(())
+            }
         }
     }
 }
@@ -112,6 +245,15 @@ pub enum ControlCommand {
     SetAvailable,
     SetFundId(SetFundIdCommand),
     Fragments(FragmentsCommand),
+    Time(TimeCommand),
+    /// tallies all vote plans using committee wallet
+    Tally,
+    /// saves or restores mock state
+    State(StateCommand),
+    /// modifies funds, challenges and proposals
+    Data(DataCommand),
+    /// creates accounts and modifies their state
+    Accounts(AccountsCommand),
     Health,
 }
 
@@ -128,6 +270,14 @@ impl ControlCommand {
                 rest.set_fund_id(set_fund_id.fund_id).map_err(Into::into)
             }
             Self::Fragments(fragments_command) => fragments_command.exec(rest).map_err(Into::into),
+            Self::Time(time_command) => time_command.exec(rest),
+            Self::State(state_command) => state_command.exec(rest),
+            Self::Data(data_command) => data_command.exec(rest),
+            Self::Accounts(accounts_command) => accounts_command.exec(rest),
+            Self::Tally => {
+                println!("{:?}", rest.tally()?);
+                Ok(())
+            }
             Self::Health => {
                 match rest.is_up() {
                     true => {
@@ -161,6 +311,8 @@ pub enum FragmentsCommand {
     Hold,
     Accept,
     Reset,
+    /// content based rules for received fragments
+    Rules(FragmentRulesCommand),
 }
 
 impl FragmentsCommand {
@@ -170,6 +322,291 @@ impl FragmentsCommand {
             Self::Hold => rest.hold_all_fragments().map_err(Into::into),
             Self::Accept => rest.accept_all_fragments().map_err(Into::into),
             Self::Reset => rest.reset_fragments_behavior().map_err(Into::into),
+            Self::Rules(rules_command) => rules_command.exec(rest),
+        }
+    }
+}
+
+#[derive(StructOpt, Debug)]
+pub enum FragmentRulesCommand {
+    /// lists rules with number of fragments they matched
+    List,
+    /// adds new rule
+    Add(AddFragmentRuleCommand),
+    /// removes rule with given id
+    Remove(RemoveFragmentRuleCommand),
+    /// removes all rules
+    Clear,
+}
+
+impl FragmentRulesCommand {
+    pub fn exec(self, rest: VitupDisruptionRestClient) -> Result<()> {
+        match self {
+            Self::List => {
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&rest.list_fragment_rules()?)?
+                );
+                Ok(())
+            }
+            Self::Add(add_command) => add_command.exec(rest),
+            Self::Remove(remove_command) => rest
+                .remove_fragment_rule(remove_command.id)
+                .map_err(Into::into),
+            Self::Clear => rest.clear_fragment_rules().map_err(Into::into),
+        }
+    }
+}
+
+#[derive(StructOpt, Debug)]
+pub struct AddFragmentRuleCommand {
+    /// transaction, vote_plan, vote_cast, vote_tally, encrypted_vote_tally or other
+    #[structopt(long = "type")]
+    pub fragment_type: Option<String>,
+
+    /// sender account id in hex
+    #[structopt(long = "sender")]
+    pub sender: Option<String>,
+
+    #[structopt(long = "vote-plan")]
+    pub vote_plan: Option<String>,
+
+    #[structopt(long = "proposal-index")]
+    pub proposal_index: Option<u8>,
+
+    /// applies rule only to every n-th matching fragment
+    #[structopt(long = "every-nth")]
+    pub every_nth: Option<u32>,
+
+    /// accept, reject, pending or none (ledger decides)
+    #[structopt(long = "strategy")]
+    pub strategy: String,
+
+    /// custom rejection reason
+    #[structopt(long = "reason")]
+    pub reason: Option<String>,
+
+    /// number of slots fragment stays pending before strategy is applied
+    #[structopt(long = "delay-slots", default_value = "0")]
+    pub delay_slots: u32,
+}
+
+impl AddFragmentRuleCommand {
+    pub fn exec(self, rest: VitupDisruptionRestClient) -> Result<()> {
+        let strategy = match self.strategy.to_lowercase().as_str() {
+            "accept" => FragmentRecieveStrategy::Accept,
+            "reject" => FragmentRecieveStrategy::Reject,
+            "pending" => FragmentRecieveStrategy::Pending,
+            "none" => FragmentRecieveStrategy::None,
+            other => return Err(format!("unknown fragment strategy: {}", other).into()),
+        };
+        let fragment_type = self
+            .fragment_type
+            .map(|fragment_type| serde_json::from_value(serde_json::Value::String(fragment_type)))
+            .transpose()?;
+
+        let rule = FragmentRule {
+            fragment_type,
+            sender: self.sender,
+            vote_plan: self.vote_plan,
+            proposal_index: self.proposal_index,
+            every_nth: self.every_nth,
+            strategy,
+            reason: self.reason,
+            delay_slots: self.delay_slots,
+        };
+        println!("{}", rest.add_fragment_rule(&rule)?);
+        Ok(())
+    }
+}
+
+#[derive(StructOpt, Debug)]
+pub struct RemoveFragmentRuleCommand {
+    #[structopt(long = "id")]
+    pub id: u32,
+}
+
+#[derive(StructOpt, Debug)]
+pub enum TimeCommand {
+    /// prints current mock time and block date
+    Status,
+    /// stops mock clock
+    Freeze,
+    /// resumes mock clock
+    Resume,
+    /// moves mock clock forward by given number of slots
+    Advance(AdvanceTimeCommand),
+    /// moves mock clock to the beginning of given voting phase
+    Jump(JumpTimeCommand),
+}
+
+impl TimeCommand {
+    pub fn exec(self, rest: VitupDisruptionRestClient) -> Result<()> {
+        match self {
+            Self::Status => {
+                println!("{}", rest.time_status()?);
+                Ok(())
+            }
+            Self::Freeze => rest.freeze_time().map_err(Into::into),
+            Self::Resume => rest.resume_time().map_err(Into::into),
+            Self::Advance(advance_command) => {
+                rest.advance_time(advance_command.slots).map_err(Into::into)
+            }
+            Self::Jump(jump_command) => jump_command.exec(rest),
+        }
+    }
+}
+
+#[derive(StructOpt, Debug)]
+pub struct AdvanceTimeCommand {
+    #[structopt(long = "slots")]
+    slots: u32,
+}
+
+#[derive(StructOpt, Debug)]
+pub enum JumpTimeCommand {
+    VoteStart,
+    TallyStart,
+    TallyEnd,
+}
+
+impl JumpTimeCommand {
+    pub fn exec(self, rest: VitupDisruptionRestClient) -> Result<()> {
+        match self {
+            Self::VoteStart => rest.jump_to_vote_start().map_err(Into::into),
+            Self::TallyStart => rest.jump_to_tally_start().map_err(Into::into),
+            Self::TallyEnd => rest.jump_to_tally_end().map_err(Into::into),
+        }
+    }
+}
+
+#[derive(StructOpt, Debug)]
+pub enum DataCommand {
+    Funds(DataEntityCommand),
+    Challenges(DataEntityCommand),
+    Proposals(DataEntityCommand),
+}
+
+impl DataCommand {
+    pub fn exec(self, rest: VitupDisruptionRestClient) -> Result<()> {
+        match self {
+            Self::Funds(command) => command.exec(rest, "funds"),
+            Self::Challenges(command) => command.exec(rest, "challenges"),
+            Self::Proposals(command) => command.exec(rest, "proposals"),
+        }
+    }
+}
+
+#[derive(StructOpt, Debug)]
+pub enum DataEntityCommand {
+    /// adds entity defined in json file
+    Add {
+        #[structopt(long = "file")]
+        file: PathBuf,
+    },
+    /// replaces entity with given id with the one defined in json file
+    Update {
+        #[structopt(long = "id")]
+        id: i32,
+        #[structopt(long = "file")]
+        file: PathBuf,
+    },
+    /// removes entity together with entities which depend on it
+    Remove {
+        #[structopt(long = "id")]
+        id: i32,
+    },
+}
+
+impl DataEntityCommand {
+    pub fn exec(self, rest: VitupDisruptionRestClient, entity: &str) -> Result<()> {
+        match self {
+            Self::Add { file } => {
+                let body = serde_json::from_str(&jortestkit::prelude::read_file(file))?;
+                rest.add_data(entity, &body).map_err(Into::into)
+            }
+            Self::Update { id, file } => {
+                let body = serde_json::from_str(&jortestkit::prelude::read_file(file))?;
+                rest.update_data(entity, id, &body).map_err(Into::into)
+            }
+            Self::Remove { id } => rest.remove_data(entity, id).map_err(Into::into),
+        }
+    }
+}
+
+#[derive(StructOpt, Debug)]
+pub enum AccountsCommand {
+    /// creates account with given funds transferred from mock faucet
+    Create {
+        /// account public key in hex
+        #[structopt(long = "account")]
+        account: String,
+        #[structopt(long = "value")]
+        value: u64,
+    },
+    /// transfers funds from mock faucet to existing account
+    TopUp {
+        #[structopt(long = "account")]
+        account: String,
+        #[structopt(long = "value")]
+        value: u64,
+    },
+    /// lowers reported account balance by given value or to zero
+    Drain {
+        #[structopt(long = "account")]
+        account: String,
+        #[structopt(long = "value")]
+        value: Option<u64>,
+    },
+    /// moves reported spending counter forward
+    BumpCounter {
+        #[structopt(long = "account")]
+        account: String,
+        #[structopt(long = "count", default_value = "1")]
+        count: u32,
+    },
+    /// removes balance and spending counter modifications
+    Reset {
+        #[structopt(long = "account")]
+        account: String,
+    },
+}
+
+impl AccountsCommand {
+    pub fn exec(self, rest: VitupDisruptionRestClient) -> Result<()> {
+        match self {
+            Self::Create { account, value } => {
+                println!("{}", rest.create_account(&account, value)?);
+                Ok(())
+            }
+            Self::TopUp { account, value } => {
+                println!("{}", rest.top_up_account(&account, value)?);
+                Ok(())
+            }
+            Self::Drain { account, value } => {
+                rest.drain_account(&account, value).map_err(Into::into)
+            }
+            Self::BumpCounter { account, count } => rest
+                .bump_spending_counter(&account, count)
+                .map_err(Into::into),
+            Self::Reset { account } => rest.reset_account(&account).map_err(Into::into),
+        }
+    }
+}
+
+#[derive(StructOpt, Debug)]
+pub enum StateCommand {
+    /// saves mock state into state directory
+    Save,
+    /// replaces mock state with the one from state directory
+    Load,
+}
+
+impl StateCommand {
+    pub fn exec(self, rest: VitupDisruptionRestClient) -> Result<()> {
+        match self {
+            Self::Save => rest.save_state().map_err(Into::into),
+            Self::Load => rest.load_state().map_err(Into::into),
         }
     }
 }
@@ -264,13 +701,63 @@ impl MockCommand {
 pub enum UtilsCommand {
     /// persistent log comamnds
     PersistentLog(PersistentLogCommand),
+    /// replays recorded mock traffic against target and diffs responses
+    Replay(ReplayCommand),
 }
 
 impl UtilsCommand {
     pub fn exec(self) -> Result<()> {
         match self {
             Self::PersistentLog(persistent_logs_command) => persistent_logs_command.exec(),
+            Self::Replay(replay_command) => replay_command.exec(),
+        }
+    }
+}
+
+#[derive(StructOpt, Debug)]
+pub struct ReplayCommand {
+    /// recording in json lines format (downloaded from /api/control/logs/requests)
+    #[structopt(long = "recording")]
+    pub recording: PathBuf,
+
+    /// address of mock or real backend, e.g. http://127.0.0.1:8080
+    #[structopt(long = "target")]
+    pub target: String,
+
+    /// replays also requests to control endpoints
+    #[structopt(long = "include-control")]
+    pub include_control: bool,
+}
+
+impl ReplayCommand {
+    pub fn exec(self) -> Result<()> {
+        let records = read_recording(&self.recording)?;
+        let mismatches = Replayer::new(self.target, self.include_control).replay(&records)?;
+
+        for mismatch in mismatches.iter() {
+            println!(
+                "[{}] {} {}: status {} -> {}",
+                mismatch.record_id,
+                mismatch.method,
+                mismatch.path,
+                mismatch.expected_status,
+                mismatch.actual_status
+            );
+            if let Some(body_diff) = &mismatch.body_diff {
+                println!("{}", body_diff);
+            }
         }
+
+        if !mismatches.is_empty() {
+            return Err(format!(
+                "{} out of {} responses differ from recording",
+                mismatches.len(),
+                records.len()
+            )
+            .into());
+        }
+        println!("all {} responses match recording", records.len());
+        Ok(())
     }
 }
 
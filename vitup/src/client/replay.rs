@@ -0,0 +1,115 @@
+use crate::mock::{Record, RecordedBody, REDACTED_HEADER_VALUE};
+use diffy::create_patch;
+use reqwest::blocking::Client;
+use reqwest::Method;
+use std::path::Path;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Headers which are specific to original connection and should not be resent
+const SKIPPED_HEADERS: [&str; 3] = ["host", "content-length", "connection"];
+
+pub fn read_recording<P: AsRef<Path>>(recording: P) -> Result<Vec<Record>, Error> {
+    std::fs::read_to_string(recording)?
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| serde_json::from_str(line).map_err(Into::into))
+        .collect()
+}
+
+#[derive(Debug)]
+pub struct ReplayMismatch {
+    pub record_id: u64,
+    pub method: String,
+    pub path: String,
+    pub expected_status: u16,
+    pub actual_status: u16,
+    pub body_diff: Option<String>,
+}
+
+/// Sends recorded requests to target in original order and compares responses
+/// with recorded ones
+pub struct Replayer {
+    target: String,
+    include_control: bool,
+    client: Client,
+}
+
+impl Replayer {
+    pub fn new<S: Into<String>>(target: S, include_control: bool) -> Self {
+        Self {
+            target: target.into(),
+            include_control,
+            client: Client::new(),
+        }
+    }
+
+    pub fn replay(&self, records: &[Record]) -> Result<Vec<ReplayMismatch>, Error> {
+        let mut mismatches = Vec::new();
+
+        for record in records {
+            if !self.include_control && record.request.path.starts_with("/api/control") {
+                continue;
+            }
+
+            let method = Method::from_str(&record.request.method)
+                .map_err(|_| Error::InvalidMethod(record.request.method.clone()))?;
+            let mut request = self
+                .client
+                .request(method, &format!("{}{}", self.target, record.request.path));
+            for (name, value) in record.request.headers.iter() {
+                if !SKIPPED_HEADERS.contains(&name.as_str()) && value != REDACTED_HEADER_VALUE {
+                    request = request.header(name.as_str(), value.as_str());
+                }
+            }
+
+            let response = request.body(record.request.body.to_bytes()).send()?;
+            let actual_status = response.status().as_u16();
+            let actual_body = RecordedBody::from(response.bytes()?.as_ref());
+
+            let body_diff = if actual_body != record.response.body {
+                Some(
+                    create_patch(
+                        &body_as_text(&record.response.body),
+                        &body_as_text(&actual_body),
+                    )
+                    .to_string(),
+                )
+            } else {
+                None
+            };
+
+            if actual_status != record.response.status || body_diff.is_some() {
+                mismatches.push(ReplayMismatch {
+                    record_id: record.id,
+                    method: record.request.method.clone(),
+                    path: record.request.path.clone(),
+                    expected_status: record.response.status,
+                    actual_status,
+                    body_diff,
+                });
+            }
+        }
+        Ok(mismatches)
+    }
+}
+
+fn body_as_text(body: &RecordedBody) -> String {
+    match body {
+        RecordedBody::Empty => String::new(),
+        RecordedBody::Text(text) => format!("{}\n", text),
+        RecordedBody::Binary(content) => format!("{}\n", content),
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("cannot read recording")]
+    IoError(#[from] std::io::Error),
+    #[error("cannot parse recording")]
+    SerdeError(#[from] serde_json::Error),
+    #[error("request error")]
+    ReqwestError(#[from] reqwest::Error),
+    #[error("invalid http method in recording: {0}")]
+    InvalidMethod(String),
+}
@@ -1,12 +1,18 @@
 use crate::config::VitStartParameters;
 use crate::manager::file_lister::FolderDump;
 use crate::manager::State;
-use reqwest::blocking::Response;
+use crate::mock::{FaultRule, FaultRuleEntry, FragmentRule, FragmentRuleEntry};
+use jortestkit::web::api_token::API_TOKEN_HEADER;
+use reqwest::blocking::{Client, RequestBuilder, Response};
+use reqwest::Certificate;
+use serde::Serialize;
+use std::path::Path;
 use thiserror::Error;
 
 pub struct VitupRest {
     token: Option<String>,
     address: String,
+    client: Client,
 }
 
 impl VitupRest {
@@ -14,6 +20,7 @@ impl VitupRest {
         Self {
             token: original.token().clone(),
             address: format!("{}:{}", original.address, port.into()),
+            client: original.client,
         }
     }
 
@@ -21,6 +28,7 @@ impl VitupRest {
         Self {
             token: Some(token),
             address,
+            client: Client::new(),
         }
     }
 
@@ -28,9 +36,20 @@ impl VitupRest {
         Self {
             token: None,
             address,
+            client: Client::new(),
         }
     }
 
+    /// Trusts given CA certificate (pem) in addition to system ones. Required for https
+    /// endpoints with self-signed certificates, like the one generated by mock
+    pub fn with_ca_certificate<P: AsRef<Path>>(mut self, ca: P) -> Result<Self, Error> {
+        let certificate = Certificate::from_pem(&std::fs::read(ca)?)?;
+        self.client = Client::builder()
+            .add_root_certificate(certificate)
+            .build()?;
+        Ok(self)
+    }
+
     pub fn token(&self) -> &Option<String> {
         &self.token
     }
@@ -43,21 +62,44 @@ impl VitupRest {
         format!("{}/{}", self.address, path.into())
     }
 
+    /// Adds api token header, if token is defined
+    fn with_token(&self, request: RequestBuilder) -> RequestBuilder {
+        match &self.token {
+            Some(token) => request.header(API_TOKEN_HEADER, token),
+            None => request,
+        }
+    }
+
     pub fn post_skip_response<S: Into<String>>(&self, local_path: S) -> Result<(), Error> {
-        self.post(local_path).map(|_| ())
+        self.post(local_path)?.error_for_status()?;
+        Ok(())
     }
 
     pub fn post<S: Into<String>>(&self, local_path: S) -> Result<Response, Error> {
         let path = self.path(local_path);
         println!("Calling: {}", path);
-        let client = reqwest::blocking::Client::new();
-        client.post(&path).send().map_err(Into::into)
+        self.with_token(self.client.post(&path))
+            .send()
+            .map_err(Into::into)
+    }
+
+    pub fn post_json<S: Into<String>, T: Serialize>(
+        &self,
+        local_path: S,
+        body: &T,
+    ) -> Result<Response, Error> {
+        let path = self.path(local_path);
+        println!("Calling: {}", path);
+        self.with_token(self.client.post(&path))
+            .json(body)
+            .send()
+            .map_err(Into::into)
     }
 
     pub fn get<S: Into<String>>(&self, local_path: S) -> Result<String, Error> {
         let path = self.path(local_path);
         println!("Calling: {}", path);
-        Ok(reqwest::blocking::get(&path)?.text()?)
+        Ok(self.with_token(self.client.get(&path)).send()?.text()?)
     }
 }
 
@@ -76,10 +118,19 @@ impl VitupDisruptionRestClient {
         serde_json::from_str(&self.inner.get("api/control/logs/get")?).map_err(Into::into)
     }
 
+    pub fn get_recorded_requests(&self) -> Result<String, Error> {
+        self.inner.get("api/control/logs/requests")
+    }
+
     pub fn clear_logs(&self) -> Result<(), Error> {
         self.inner.post_skip_response("api/control/logs/clear")
     }
 
+    pub fn clear_recorded_requests(&self) -> Result<(), Error> {
+        self.inner
+            .post_skip_response("api/control/logs/requests/clear")
+    }
+
     pub fn list_files(&self) -> Result<FolderDump, Error> {
         serde_json::from_str(&self.inner.get("api/control/files/list")?).map_err(Into::into)
     }
@@ -126,11 +177,180 @@ impl VitupDisruptionRestClient {
             .post_skip_response("api/control/command/fragments/reset")
     }
 
+    pub fn list_fragment_rules(&self) -> Result<Vec<FragmentRuleEntry>, Error> {
+        serde_json::from_str(&self.inner.get("api/control/command/fragments/rules/list")?)
+            .map_err(Into::into)
+    }
+
+    pub fn add_fragment_rule(&self, rule: &FragmentRule) -> Result<u32, Error> {
+        serde_json::from_str(
+            &self
+                .inner
+                .post_json("api/control/command/fragments/rules/add", rule)?
+                .text()?,
+        )
+        .map_err(Into::into)
+    }
+
+    pub fn remove_fragment_rule(&self, id: u32) -> Result<(), Error> {
+        self.inner
+            .post_skip_response(format!("api/control/command/fragments/rules/remove/{}", id))
+    }
+
+    pub fn clear_fragment_rules(&self) -> Result<(), Error> {
+        self.inner
+            .post_skip_response("api/control/command/fragments/rules/clear")
+    }
+
+    pub fn tally(&self) -> Result<Vec<String>, Error> {
+        serde_json::from_str(&self.inner.post("api/control/command/tally")?.text()?)
+            .map_err(Into::into)
+    }
+
+    pub fn time_status(&self) -> Result<String, Error> {
+        self.inner.get("api/control/time/status")
+    }
+
+    pub fn freeze_time(&self) -> Result<(), Error> {
+        self.inner.post_skip_response("api/control/time/freeze")
+    }
+
+    pub fn resume_time(&self) -> Result<(), Error> {
+        self.inner.post_skip_response("api/control/time/resume")
+    }
+
+    pub fn advance_time(&self, slots: u32) -> Result<(), Error> {
+        self.inner
+            .post_skip_response(format!("api/control/time/advance/{}", slots))
+    }
+
+    pub fn jump_to_vote_start(&self) -> Result<(), Error> {
+        self.inner
+            .post_skip_response("api/control/time/jump/vote-start")
+    }
+
+    pub fn jump_to_tally_start(&self) -> Result<(), Error> {
+        self.inner
+            .post_skip_response("api/control/time/jump/tally-start")
+    }
+
+    pub fn jump_to_tally_end(&self) -> Result<(), Error> {
+        self.inner
+            .post_skip_response("api/control/time/jump/tally-end")
+    }
+
+    /// Adds fund, challenge or proposal. Entity is one of: funds, challenges, proposals
+    pub fn add_data(&self, entity: &str, body: &serde_json::Value) -> Result<(), Error> {
+        self.inner
+            .post_json(format!("api/control/data/{}/add", entity), body)?
+            .error_for_status()?;
+        Ok(())
+    }
+
+    pub fn update_data(
+        &self,
+        entity: &str,
+        id: i32,
+        body: &serde_json::Value,
+    ) -> Result<(), Error> {
+        self.inner
+            .post_json(format!("api/control/data/{}/update/{}", entity, id), body)?
+            .error_for_status()?;
+        Ok(())
+    }
+
+    pub fn remove_data(&self, entity: &str, id: i32) -> Result<(), Error> {
+        self.inner
+            .post(format!("api/control/data/{}/remove/{}", entity, id))?
+            .error_for_status()?;
+        Ok(())
+    }
+
+    /// Creates account by transferring funds from mock faucet. Returns transfer fragment id
+    pub fn create_account(&self, account: &str, value: u64) -> Result<String, Error> {
+        serde_json::from_str(
+            &self
+                .inner
+                .post(format!("api/control/accounts/create/{}/{}", account, value))?
+                .error_for_status()?
+                .text()?,
+        )
+        .map_err(Into::into)
+    }
+
+    /// Transfers funds from mock faucet to existing account. Returns transfer fragment id
+    pub fn top_up_account(&self, account: &str, value: u64) -> Result<String, Error> {
+        serde_json::from_str(
+            &self
+                .inner
+                .post(format!("api/control/accounts/top-up/{}/{}", account, value))?
+                .error_for_status()?
+                .text()?,
+        )
+        .map_err(Into::into)
+    }
+
+    pub fn drain_account(&self, account: &str, value: Option<u64>) -> Result<(), Error> {
+        let path = match value {
+            Some(value) => format!("api/control/accounts/drain/{}/{}", account, value),
+            None => format!("api/control/accounts/drain/{}", account),
+        };
+        self.inner.post(path)?.error_for_status()?;
+        Ok(())
+    }
+
+    pub fn bump_spending_counter(&self, account: &str, count: u32) -> Result<(), Error> {
+        self.inner
+            .post(format!(
+                "api/control/accounts/bump-counter/{}/{}",
+                account, count
+            ))?
+            .error_for_status()?;
+        Ok(())
+    }
+
+    pub fn reset_account(&self, account: &str) -> Result<(), Error> {
+        self.inner
+            .post(format!("api/control/accounts/reset/{}", account))?
+            .error_for_status()?;
+        Ok(())
+    }
+
+    pub fn list_faults(&self) -> Result<Vec<FaultRuleEntry>, Error> {
+        serde_json::from_str(&self.inner.get("api/control/faults/list")?).map_err(Into::into)
+    }
+
+    pub fn add_fault(&self, rule: &FaultRule) -> Result<u32, Error> {
+        serde_json::from_str(
+            &self
+                .inner
+                .post_json("api/control/faults/add", rule)?
+                .text()?,
+        )
+        .map_err(Into::into)
+    }
+
+    pub fn remove_fault(&self, id: u32) -> Result<(), Error> {
+        self.inner
+            .post_skip_response(format!("api/control/faults/remove/{}", id))
+    }
+
+    pub fn clear_faults(&self) -> Result<(), Error> {
+        self.inner.post_skip_response("api/control/faults/clear")
+    }
+
+    pub fn save_state(&self) -> Result<(), Error> {
+        self.inner.post_skip_response("api/control/state/save")
+    }
+
+    pub fn load_state(&self) -> Result<(), Error> {
+        self.inner.post_skip_response("api/control/state/load")
+    }
+
     pub fn is_up(&self) -> bool {
-        if let Ok(path) = self.inner.get("api/health") {
-            if let Ok(response) = reqwest::blocking::get(&path) {
-                return response.status() == reqwest::StatusCode::OK;
-            }
+        let path = self.inner.path("api/health");
+        if let Ok(response) = self.inner.client.get(&path).send() {
+            return response.status() == reqwest::StatusCode::OK;
         }
         false
     }
@@ -155,10 +375,10 @@ impl VitupAdminRestClient {
     }
 
     pub fn start_custom(&self, params: VitStartParameters) -> Result<String, Error> {
-        let path = self.inner.path("control/start/custom");
-        let client = reqwest::blocking::Client::new();
-        let response = client.post(&path).json(&params).send()?;
-        Ok(response.text()?)
+        Ok(self
+            .inner
+            .post_json("control/start/custom", &params)?
+            .text()?)
     }
 
     pub fn start_default(&self) -> Result<String, Error> {
@@ -184,4 +404,6 @@ pub enum Error {
     ReqwestError(#[from] reqwest::Error),
     #[error("response serialization error")]
     SerdeError(#[from] serde_json::Error),
+    #[error("cannot read certificate")]
+    IoError(#[from] std::io::Error),
 }
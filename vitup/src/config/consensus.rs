@@ -0,0 +1,77 @@
+use jormungandr_scenario_tests::scenario::ConsensusVersion;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Prefix of accounts which delegate stake to leaders stake pools in genesis praos
+pub const DELEGATOR_PREFIX: &str = "delegator_";
+
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Consensus {
+    Bft,
+    GenesisPraos,
+}
+
+pub fn parse_consensus_from_str(consensus: &str) -> Result<Consensus, String> {
+    let consensus_lowercase: &str = &consensus.to_lowercase().replace('-', "_");
+    match consensus_lowercase {
+        "bft" => Ok(Consensus::Bft),
+        "genesis_praos" | "praos" => Ok(Consensus::GenesisPraos),
+        _ => Err(format!(
+            "unknown consensus '{}', expected one of: bft, genesis-praos",
+            consensus
+        )),
+    }
+}
+
+/// Consensus of generated block0. In genesis praos every leader operates stake pool
+/// registered in block0, with stake delegated by separate account (`delegator_{leader}`).
+/// Stake of particular pools can be defined in `stake_distribution` by leader alias,
+/// other pools receive `default_pool_stake`
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ConsensusConfig {
+    pub version: Consensus,
+    /// in millis, used only in genesis praos
+    pub active_slot_coefficient: u64,
+    /// in seconds
+    pub kes_update_speed: u32,
+    #[serde(default)]
+    pub stake_distribution: HashMap<String, u64>,
+    pub default_pool_stake: u64,
+}
+
+impl Default for ConsensusConfig {
+    fn default() -> Self {
+        Self {
+            version: Consensus::Bft,
+            active_slot_coefficient: 700,
+            kes_update_speed: 46800,
+            stake_distribution: HashMap::new(),
+            default_pool_stake: 1_000_000_000,
+        }
+    }
+}
+
+impl ConsensusConfig {
+    pub fn consensus_version(&self) -> ConsensusVersion {
+        match self.version {
+            Consensus::Bft => ConsensusVersion::Bft,
+            Consensus::GenesisPraos => ConsensusVersion::GenesisPraos,
+        }
+    }
+
+    pub fn is_genesis_praos(&self) -> bool {
+        self.version == Consensus::GenesisPraos
+    }
+
+    pub fn pool_stake(&self, leader: &str) -> u64 {
+        self.stake_distribution
+            .get(leader)
+            .copied()
+            .unwrap_or(self.default_pool_stake)
+    }
+
+    pub fn delegator_alias(leader: &str) -> String {
+        format!("{}{}", DELEGATOR_PREFIX, leader)
+    }
+}
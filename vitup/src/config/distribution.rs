@@ -0,0 +1,233 @@
+use rand::Rng;
+use rand_distr::{Distribution, LogNormal, Pareto};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Wallets count with values in range `start..end` (in ADA). Ranges are the same
+/// as levels printed by `iapyx stats block0 wallets`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistogramBucket {
+    pub start: u64,
+    pub end: u64,
+    pub count: usize,
+}
+
+/// Values drawn from log-normal distribution with given sigma, scaled so they sum up to total
+pub fn log_normal<R: Rng + ?Sized>(
+    count: usize,
+    sigma: f64,
+    total: u64,
+    rng: &mut R,
+) -> Result<Vec<u64>, Error> {
+    let distribution = LogNormal::new(0.0, sigma).map_err(|_| Error::InvalidSigma(sigma))?;
+    scale_to_total(distribution.sample_iter(rng).take(count).collect(), total)
+}
+
+/// Values drawn from Pareto distribution with given shape, scaled so they sum up to total
+pub fn pareto<R: Rng + ?Sized>(
+    count: usize,
+    shape: f64,
+    total: u64,
+    rng: &mut R,
+) -> Result<Vec<u64>, Error> {
+    let distribution = Pareto::new(1.0, shape).map_err(|_| Error::InvalidShape(shape))?;
+    scale_to_total(distribution.sample_iter(rng).take(count).collect(), total)
+}
+
+/// Values drawn uniformly within buckets. When total is defined, values are moved towards
+/// bucket bounds, so they sum up to total as close as buckets allow
+pub fn histogram<R: Rng + ?Sized>(
+    buckets: &[HistogramBucket],
+    total: Option<u64>,
+    rng: &mut R,
+) -> Vec<u64> {
+    let bounds: Vec<(u64, u64)> = buckets
+        .iter()
+        .flat_map(|bucket| {
+            let end = bucket.end.max(bucket.start + 1);
+            std::iter::repeat((bucket.start.max(1), end - 1)).take(bucket.count)
+        })
+        .map(|(low, high)| (low, high.max(low)))
+        .collect();
+    let values: Vec<u64> = bounds
+        .iter()
+        .map(|(low, high)| rng.gen_range(*low..=*high))
+        .collect();
+
+    let total = match total {
+        Some(total) => total as f64,
+        None => return values,
+    };
+    let sum = values.iter().sum::<u64>() as f64;
+    let lowest = bounds.iter().map(|(low, _)| low).sum::<u64>() as f64;
+    let highest = bounds.iter().map(|(_, high)| high).sum::<u64>() as f64;
+
+    values
+        .iter()
+        .zip(bounds.iter())
+        .map(|(value, (low, high))| {
+            let value = *value as f64;
+            let moved = if total > sum && highest > sum {
+                let factor = ((total - sum) / (highest - sum)).min(1.0);
+                value + (*high as f64 - value) * factor
+            } else if total < sum && sum > lowest {
+                let factor = ((sum - total) / (sum - lowest)).min(1.0);
+                value - (value - *low as f64) * factor
+            } else {
+                value
+            };
+            moved.round() as u64
+        })
+        .collect()
+}
+
+/// Every wallet gets non zero value, so total cannot be lower than wallets count
+fn scale_to_total(samples: Vec<f64>, total: u64) -> Result<Vec<u64>, Error> {
+    if (samples.len() as u64) > total {
+        return Err(Error::TotalBelowCount {
+            total,
+            count: samples.len(),
+        });
+    }
+    let sum: f64 = samples.iter().sum();
+    let mut values: Vec<u64> = samples
+        .iter()
+        .map(|sample| ((sample / sum * total as f64).floor() as u64).max(1))
+        .collect();
+
+    // rounding remainder goes to the richest wallet. Wallets raised to minimal value
+    // can overshoot total, excess is taken from the richest wallets down to minimal value
+    let assigned: u64 = values.iter().sum();
+    if total > assigned {
+        if let Some(richest) = values.iter_mut().max() {
+            *richest += total - assigned;
+        }
+    } else {
+        let mut excess = assigned - total;
+        let mut by_value: Vec<&mut u64> = values.iter_mut().collect();
+        by_value.sort_by(|left, right| right.cmp(left));
+        for value in by_value {
+            if excess == 0 {
+                break;
+            }
+            let taken = excess.min(*value - 1);
+            *value -= taken;
+            excess -= taken;
+        }
+    }
+    Ok(values)
+}
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("log-normal sigma ({0}) should be positive")]
+    InvalidSigma(f64),
+    #[error("pareto shape ({0}) should be positive")]
+    InvalidShape(f64),
+    #[error("total ({total}) should be at least wallets count ({count})")]
+    TotalBelowCount { total: u64, count: usize },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{histogram, log_normal, pareto, scale_to_total, Error, HistogramBucket};
+    use rand_chacha::ChaChaRng;
+    use rand_core::SeedableRng;
+
+    fn rng() -> ChaChaRng {
+        ChaChaRng::from_seed([7u8; 32])
+    }
+
+    #[test]
+    pub fn log_normal_values_sum_up_to_total() {
+        let values = log_normal(100, 1.5, 1_000_000, &mut rng()).unwrap();
+        assert_eq!(values.len(), 100);
+        assert_eq!(values.iter().sum::<u64>(), 1_000_000);
+        assert!(values.iter().all(|value| *value >= 1));
+    }
+
+    #[test]
+    pub fn pareto_values_sum_up_to_total() {
+        let values = pareto(100, 1.16, 1_000_000, &mut rng()).unwrap();
+        assert_eq!(values.len(), 100);
+        assert_eq!(values.iter().sum::<u64>(), 1_000_000);
+        assert!(values.iter().all(|value| *value >= 1));
+    }
+
+    #[test]
+    pub fn every_wallet_gets_value_when_total_equals_count() {
+        let values = pareto(50, 0.5, 50, &mut rng()).unwrap();
+        assert_eq!(values, vec![1; 50]);
+    }
+
+    #[test]
+    pub fn excess_of_minimal_values_is_taken_from_richest_wallets() {
+        let values =
+            scale_to_total(vec![45.0, 45.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0], 10).unwrap();
+        assert_eq!(values.iter().sum::<u64>(), 10);
+        assert!(values.iter().all(|value| *value >= 1));
+    }
+
+    #[test]
+    pub fn total_below_count_is_rejected() {
+        assert!(matches!(
+            log_normal(10, 1.0, 9, &mut rng()),
+            Err(Error::TotalBelowCount {
+                total: 9,
+                count: 10
+            })
+        ));
+    }
+
+    #[test]
+    pub fn invalid_parameters_are_rejected() {
+        assert!(matches!(
+            log_normal(10, -1.0, 100, &mut rng()),
+            Err(Error::InvalidSigma(_))
+        ));
+        assert!(matches!(
+            pareto(10, 0.0, 100, &mut rng()),
+            Err(Error::InvalidShape(_))
+        ));
+    }
+
+    #[test]
+    pub fn histogram_values_stay_within_buckets() {
+        let buckets = vec![
+            HistogramBucket {
+                start: 0,
+                end: 450,
+                count: 20,
+            },
+            HistogramBucket {
+                start: 450,
+                end: 1_000,
+                count: 10,
+            },
+        ];
+        for total in &[None, Some(1_000), Some(10_000), Some(100_000)] {
+            let values = histogram(&buckets, *total, &mut rng());
+            assert_eq!(values.len(), 30);
+            assert!(values[..20].iter().all(|value| (1..450).contains(value)));
+            assert!(values[20..]
+                .iter()
+                .all(|value| (450..1_000).contains(value)));
+        }
+    }
+
+    #[test]
+    pub fn histogram_values_are_moved_towards_total() {
+        let buckets = vec![HistogramBucket {
+            start: 0,
+            end: 1_000,
+            count: 10,
+        }];
+        let values = histogram(&buckets, Some(5_000), &mut rng());
+        let sum = values.iter().sum::<u64>();
+        assert!(
+            (4_990..=5_010).contains(&sum),
+            "sum {} is far from total",
+            sum
+        );
+    }
+}
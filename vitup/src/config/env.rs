@@ -1,7 +1,13 @@
+use super::consensus::ConsensusConfig;
 use super::initials::Initials;
+use super::private_committee::PrivateCommitteeSettings;
+use super::topology::TopologyConfig;
+use super::vote_options::VoteOptionsConfig;
+use super::vote_plans::VotePlanSettings;
 use chrono::NaiveDateTime;
 use iapyx::Protocol;
 use serde::{Deserialize, Serialize};
+use std::iter;
 use std::time::Duration;
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -12,6 +18,9 @@ pub struct VitStartParameters {
     pub vote_start: u64,
     pub vote_tally: u64,
     pub tally_end: u64,
+    /// fixed block0 date, so the same seed gives the same genesis
+    #[serde(default)]
+    pub block0_time: Option<NaiveDateTime>,
     pub vote_start_timestamp: Option<NaiveDateTime>,
     pub tally_start_timestamp: Option<NaiveDateTime>,
     pub tally_end_timestamp: Option<NaiveDateTime>,
@@ -26,6 +35,16 @@ pub struct VitStartParameters {
     pub fund_id: i32,
     pub private: bool,
     pub version: String,
+    #[serde(default)]
+    pub topology: TopologyConfig,
+    #[serde(default)]
+    pub consensus: ConsensusConfig,
+    #[serde(default)]
+    pub vote_options: VoteOptionsConfig,
+    #[serde(default)]
+    pub vote_plans: Vec<VotePlanSettings>,
+    #[serde(default)]
+    pub private_committee: PrivateCommitteeSettings,
 }
 
 impl VitStartParameters {
@@ -36,6 +55,52 @@ impl VitStartParameters {
 
         Duration::from_secs(duration_as_secs)
     }
+
+    /// Indexes of generated proposals grouped by vote plan settings. Proposals of challenges
+    /// which are not assigned to any vote plan come first and use global settings
+    pub fn proposals_by_vote_plan(&self) -> Vec<(Option<VotePlanSettings>, Vec<usize>)> {
+        let mut groups: Vec<(Option<VotePlanSettings>, Vec<usize>)> = iter::once(None)
+            .chain(self.vote_plans.iter().cloned().map(Some))
+            .map(|settings| (settings, Vec::new()))
+            .collect();
+
+        for index in 0..self.proposals as usize {
+            let challenge_id = self.vote_options.challenge_id(index, self.challenges);
+            let group = self
+                .vote_plans
+                .iter()
+                .position(|settings| {
+                    challenge_id
+                        .as_ref()
+                        .map(|id| settings.contains(id))
+                        .unwrap_or(false)
+                })
+                .map(|position| position + 1)
+                .unwrap_or(0);
+            groups[group].1.push(index);
+        }
+
+        groups
+            .into_iter()
+            .filter(|(_, indexes)| !indexes.is_empty())
+            .collect()
+    }
+
+    pub fn has_private_vote_plans(&self) -> bool {
+        self.private
+            || self
+                .vote_plans
+                .iter()
+                .any(|settings| settings.private == Some(true))
+    }
+
+    /// Indexes of generated proposals in order in which they are put into vote plans
+    pub fn proposals_order(&self) -> Vec<usize> {
+        self.proposals_by_vote_plan()
+            .into_iter()
+            .flat_map(|(_, indexes)| indexes)
+            .collect()
+    }
 }
 
 impl Default for VitStartParameters {
@@ -51,6 +116,7 @@ impl Default for VitStartParameters {
             slot_duration: 20,
             slots_per_epoch: 30,
             voting_power: 8000,
+            block0_time: None,
             vote_start_timestamp: None,
             tally_start_timestamp: None,
             tally_end_timestamp: None,
@@ -60,6 +126,77 @@ impl Default for VitStartParameters {
             private: false,
             fund_id: 1,
             version: "2.0".to_string(),
+            topology: Default::default(),
+            consensus: Default::default(),
+            vote_options: Default::default(),
+            vote_plans: Vec::new(),
+            private_committee: Default::default(),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{VitStartParameters, VotePlanSettings};
+
+    fn vote_plan(challenges: &[&str]) -> VotePlanSettings {
+        VotePlanSettings {
+            challenges: challenges.iter().map(|id| id.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    fn parameters(vote_plans: Vec<VotePlanSettings>) -> VitStartParameters {
+        VitStartParameters {
+            proposals: 6,
+            challenges: 3,
+            vote_plans,
+            ..Default::default()
+        }
+    }
+
+    fn groups(parameters: &VitStartParameters) -> Vec<(Option<Vec<String>>, Vec<usize>)> {
+        parameters
+            .proposals_by_vote_plan()
+            .into_iter()
+            .map(|(settings, indexes)| (settings.map(|settings| settings.challenges), indexes))
+            .collect()
+    }
+
+    #[test]
+    pub fn all_proposals_share_global_settings_without_vote_plans() {
+        let parameters = parameters(Vec::new());
+        assert_eq!(groups(&parameters), vec![(None, vec![0, 1, 2, 3, 4, 5])]);
+        assert_eq!(parameters.proposals_order(), vec![0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    pub fn proposals_are_grouped_by_challenges_of_vote_plans() {
+        let parameters = parameters(vec![vote_plan(&["2"]), vote_plan(&["3"])]);
+        assert_eq!(
+            groups(&parameters),
+            vec![
+                (None, vec![0, 3]),
+                (Some(vec!["2".to_string()]), vec![1, 4]),
+                (Some(vec!["3".to_string()]), vec![2, 5]),
+            ]
+        );
+        assert_eq!(parameters.proposals_order(), vec![0, 3, 1, 4, 2, 5]);
+    }
+
+    #[test]
+    pub fn challenge_belongs_to_first_vote_plan_and_empty_groups_are_skipped() {
+        let parameters = parameters(vec![
+            vote_plan(&["1", "2", "3"]),
+            vote_plan(&["2"]),
+            vote_plan(&["4"]),
+        ]);
+        assert_eq!(
+            groups(&parameters),
+            vec![(
+                Some(vec!["1".to_string(), "2".to_string(), "3".to_string()]),
+                vec![0, 1, 2, 3, 4, 5]
+            )]
+        );
+    }
+}
@@ -1,7 +1,9 @@
+use super::distribution::{self, Error as DistributionError, HistogramBucket};
 use chain_addr::Discrimination;
 use chain_impl_mockchain::value::Value;
 use jormungandr_testing_utils::testing::network_builder::{ExternalWalletTemplate, WalletTemplate};
-use rand::Rng;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaChaRng;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -31,6 +33,30 @@ pub enum Initial {
         address: String,
         funds: u64,
     },
+    LogNormal {
+        log_normal: usize,
+        sigma: f64,
+        total: u64,
+        #[serde(default)]
+        seed: Option<u64>,
+        pin: String,
+    },
+    Pareto {
+        pareto: usize,
+        shape: f64,
+        total: u64,
+        #[serde(default)]
+        seed: Option<u64>,
+        pin: String,
+    },
+    Histogram {
+        histogram: Vec<HistogramBucket>,
+        #[serde(default)]
+        total: Option<u64>,
+        #[serde(default)]
+        seed: Option<u64>,
+        pin: String,
+    },
 }
 
 pub const GRACE_VALUE: u64 = 1;
@@ -49,6 +75,21 @@ impl Default for Initials {
     }
 }
 
+impl Initial {
+    pub fn pin(&self) -> Option<&str> {
+        match self {
+            Initial::AboveThreshold { pin, .. }
+            | Initial::BelowThreshold { pin, .. }
+            | Initial::ZeroFunds { pin, .. }
+            | Initial::Wallet { pin, .. }
+            | Initial::LogNormal { pin, .. }
+            | Initial::Pareto { pin, .. }
+            | Initial::Histogram { pin, .. } => Some(pin),
+            Initial::External { .. } => None,
+        }
+    }
+}
+
 impl Initials {
     pub fn zero_funds_count(&self) -> usize {
         for initial in self.0.iter() {
@@ -73,6 +114,11 @@ impl Initials {
                     pin: _,
                 } => sum += above_threshold,
                 Initial::Wallet { .. } => sum += 1,
+                Initial::LogNormal { log_normal, .. } => sum += log_normal,
+                Initial::Pareto { pareto, .. } => sum += pareto,
+                Initial::Histogram { histogram, .. } => {
+                    sum += histogram.iter().map(|bucket| bucket.count).sum::<usize>()
+                }
                 _ => {}
             }
         }
@@ -124,14 +170,17 @@ impl Initials {
         templates
     }
 
-    pub fn templates(
+    /// Wallet values above and below threshold and values of distributions without own seed
+    /// are drawn from given rng, so the same seed gives the same wallets
+    pub fn templates<R: Rng>(
         &self,
         threshold: u64,
         discrimination: Discrimination,
-    ) -> HashMap<WalletTemplate, String> {
-        let mut rand = rand::thread_rng();
+        rand: &mut R,
+    ) -> Result<HashMap<WalletTemplate, String>, DistributionError> {
         let mut above_threshold_index = 0;
         let mut below_threshold_index = 0;
+        let mut distribution_index = 0;
 
         let mut templates = HashMap::new();
 
@@ -186,12 +235,68 @@ impl Initials {
                         pin.to_string(),
                     );
                 }
+                Initial::LogNormal {
+                    log_normal,
+                    sigma,
+                    total,
+                    seed,
+                    pin,
+                } => {
+                    let values = with_seed(*seed, rand, |rng| {
+                        distribution::log_normal(*log_normal, *sigma, *total, rng)
+                    })?;
+                    distribution_index = insert_distribution(
+                        &mut templates,
+                        distribution_index,
+                        "log_normal",
+                        values,
+                        pin,
+                        discrimination,
+                    );
+                }
+                Initial::Pareto {
+                    pareto,
+                    shape,
+                    total,
+                    seed,
+                    pin,
+                } => {
+                    let values = with_seed(*seed, rand, |rng| {
+                        distribution::pareto(*pareto, *shape, *total, rng)
+                    })?;
+                    distribution_index = insert_distribution(
+                        &mut templates,
+                        distribution_index,
+                        "pareto",
+                        values,
+                        pin,
+                        discrimination,
+                    );
+                }
+                Initial::Histogram {
+                    histogram,
+                    total,
+                    seed,
+                    pin,
+                } => {
+                    let values = with_seed(*seed, rand, |rng| {
+                        Ok(distribution::histogram(histogram, *total, rng))
+                    })?;
+                    distribution_index = insert_distribution(
+                        &mut templates,
+                        distribution_index,
+                        "histogram",
+                        values,
+                        pin,
+                        discrimination,
+                    );
+                }
                 _ => {
                     //skip
                 }
             }
         }
-        templates
+        Ok(templates)
     }
 
     pub fn extend(&mut self, initials: &Initials) {
@@ -200,3 +305,37 @@ impl Initials {
         }
     }
 }
+
+/// Distribution with own seed is independent from other initials
+fn with_seed<R, F>(seed: Option<u64>, rand: &mut R, f: F) -> Result<Vec<u64>, DistributionError>
+where
+    R: Rng,
+    F: FnOnce(&mut dyn rand::RngCore) -> Result<Vec<u64>, DistributionError>,
+{
+    match seed {
+        Some(seed) => f(&mut ChaChaRng::seed_from_u64(seed)),
+        None => f(rand),
+    }
+}
+
+fn insert_distribution(
+    templates: &mut HashMap<WalletTemplate, String>,
+    mut index: usize,
+    name: &str,
+    values: Vec<u64>,
+    pin: &str,
+    discrimination: Discrimination,
+) -> usize {
+    for value in values {
+        index += 1;
+        templates.insert(
+            WalletTemplate::new_account(
+                format!("wallet_{}_{}", index, name),
+                Value(value),
+                discrimination,
+            ),
+            pin.to_string(),
+        );
+    }
+    index
+}
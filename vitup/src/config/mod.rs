@@ -1,8 +1,30 @@
+mod consensus;
+mod distribution;
 mod env;
 mod initials;
+mod private_committee;
+mod topology;
+mod validation;
+mod vote_options;
+mod vote_plans;
 
+pub use consensus::{parse_consensus_from_str, Consensus, ConsensusConfig, DELEGATOR_PREFIX};
+pub use distribution::{Error as DistributionError, HistogramBucket};
 pub use env::VitStartParameters;
 pub use initials::{Initial as InitialEntry, Initials};
+pub use private_committee::PrivateCommitteeSettings;
+pub use topology::{
+    parse_layout_from_str, NodeSettings, TopologyConfig, TrustedPeersLayout, LEADER_PREFIX,
+    WALLET_NODE,
+};
+pub use validation::{
+    imported_fund_problems, parse_timestamp, timestamps_problems, ValidationError, TIMESTAMP_FORMAT,
+};
+pub use vote_options::{
+    parse_options, Error as VoteOptionsError, ImportedProposal, ProposalAction, ProposalSettings,
+    VoteOptions, VoteOptionsConfig,
+};
+pub use vote_plans::VotePlanSettings;
 
 use chain_impl_mockchain::fee::LinearFee;
 use jormungandr_lib::interfaces::{CommitteeIdDef, ConsensusLeaderId, LinearFeeDef};
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+/// Committee which decrypts tally of private vote plans. Every member gets communication
+/// and member keys, election key of private vote plans is derived from members public keys.
+/// Decryption requires shares of all members, so there is no separate threshold
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PrivateCommitteeSettings {
+    pub members: usize,
+}
+
+impl Default for PrivateCommitteeSettings {
+    fn default() -> Self {
+        Self { members: 1 }
+    }
+}
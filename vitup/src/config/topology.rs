@@ -0,0 +1,255 @@
+use super::validation::ValidationError;
+use jormungandr_testing_utils::testing::network_builder::{Node, Topology, TopologyBuilder};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+pub const LEADER_PREFIX: &str = "Leader";
+pub const WALLET_NODE: &str = "Wallet_Node";
+
+/// How nodes are connected with each other. Nodes are ordered: leaders first,
+/// then wallet nodes
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TrustedPeersLayout {
+    /// every node trusts all leaders defined before it
+    FullMesh,
+    /// every node trusts only the first leader
+    Star,
+    /// every node trusts only the node defined directly before it
+    Chain,
+}
+
+pub fn parse_layout_from_str(layout: &str) -> Result<TrustedPeersLayout, String> {
+    let layout_lowercase: &str = &layout.to_lowercase().replace('-', "_");
+    match layout_lowercase {
+        "full_mesh" => Ok(TrustedPeersLayout::FullMesh),
+        "star" => Ok(TrustedPeersLayout::Star),
+        "chain" => Ok(TrustedPeersLayout::Chain),
+        _ => Err(format!(
+            "unknown topology '{}', expected one of: full-mesh, star, chain",
+            layout
+        )),
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct NodeSettings {
+    #[serde(default = "default_persistent")]
+    pub persistent: bool,
+    #[serde(default)]
+    pub explorer: bool,
+}
+
+fn default_persistent() -> bool {
+    true
+}
+
+/// Description of network spawned by quick and advanced start. Leaders are named
+/// `Leader1..LeaderN`, wallet nodes `Wallet_Node`, `Wallet_Node2..Wallet_NodeN`.
+/// Wallet proxy is attached to the first wallet node or to the first leader if there is
+/// no wallet node. Settings of particular nodes can be overridden by node alias
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TopologyConfig {
+    pub leaders: usize,
+    pub wallet_nodes: usize,
+    pub layout: TrustedPeersLayout,
+    #[serde(default)]
+    pub nodes: HashMap<String, NodeSettings>,
+}
+
+impl Default for TopologyConfig {
+    fn default() -> Self {
+        Self {
+            leaders: 4,
+            wallet_nodes: 1,
+            layout: TrustedPeersLayout::FullMesh,
+            nodes: HashMap::new(),
+        }
+    }
+}
+
+impl TopologyConfig {
+    pub fn leader_aliases(&self) -> Vec<String> {
+        (1..=self.leaders)
+            .map(|i| format!("{}{}", LEADER_PREFIX, i))
+            .collect()
+    }
+
+    pub fn wallet_node_aliases(&self) -> Vec<String> {
+        (1..=self.wallet_nodes)
+            .map(|i| match i {
+                1 => WALLET_NODE.to_string(),
+                i => format!("{}{}", WALLET_NODE, i),
+            })
+            .collect()
+    }
+
+    /// Node to which wallet proxy is attached
+    pub fn proxy_node(&self) -> Result<String, ValidationError> {
+        self.wallet_node_aliases()
+            .into_iter()
+            .chain(self.leader_aliases())
+            .next()
+            .ok_or_else(|| {
+                ValidationError(vec![
+                    "topology should define at least one node to attach wallet proxy to"
+                        .to_string(),
+                ])
+            })
+    }
+
+    /// By default all nodes are persistent and explorer is enabled on wallet nodes
+    /// and on the first leader
+    pub fn node_settings(&self, alias: &str) -> NodeSettings {
+        if let Some(settings) = self.nodes.get(alias) {
+            return settings.clone();
+        }
+        let leaders = self.leader_aliases();
+        let is_leader = leaders.iter().any(|leader| leader == alias);
+        let is_first_leader = leaders.first().map(String::as_str) == Some(alias);
+        NodeSettings {
+            persistent: true,
+            explorer: !is_leader || is_first_leader,
+        }
+    }
+
+    /// Aliases of all nodes in order, each with aliases of nodes it trusts
+    pub fn trusted_peers(&self) -> Vec<(String, Vec<String>)> {
+        let leaders = self.leader_aliases();
+        let aliases: Vec<String> = leaders
+            .iter()
+            .cloned()
+            .chain(self.wallet_node_aliases())
+            .collect();
+
+        aliases
+            .iter()
+            .enumerate()
+            .map(|(i, alias)| {
+                let trusted_peers: Vec<String> = match self.layout {
+                    TrustedPeersLayout::FullMesh => leaders.iter().take(i).cloned().collect(),
+                    TrustedPeersLayout::Star => leaders.iter().take(i.min(1)).cloned().collect(),
+                    TrustedPeersLayout::Chain => i
+                        .checked_sub(1)
+                        .map(|prev| aliases[prev].clone())
+                        .into_iter()
+                        .collect(),
+                };
+                (alias.clone(), trusted_peers)
+            })
+            .collect()
+    }
+
+    pub fn build(&self) -> Topology {
+        let mut topology_builder = TopologyBuilder::new();
+        for (alias, trusted_peers) in self.trusted_peers() {
+            let mut node = Node::new(&alias);
+            for peer in trusted_peers.iter() {
+                node.add_trusted_peer(peer);
+            }
+            topology_builder.register_node(node);
+        }
+        topology_builder.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_layout_from_str, TopologyConfig, TrustedPeersLayout, WALLET_NODE};
+    use std::collections::HashMap;
+
+    fn topology(leaders: usize, wallet_nodes: usize, layout: TrustedPeersLayout) -> TopologyConfig {
+        TopologyConfig {
+            leaders,
+            wallet_nodes,
+            layout,
+            nodes: HashMap::new(),
+        }
+    }
+
+    fn peers(trusted_peers: &[&str]) -> Vec<String> {
+        trusted_peers.iter().map(|peer| peer.to_string()).collect()
+    }
+
+    #[test]
+    pub fn full_mesh_nodes_trust_all_previous_leaders() {
+        let trusted_peers = topology(3, 2, TrustedPeersLayout::FullMesh).trusted_peers();
+        assert_eq!(
+            trusted_peers,
+            vec![
+                ("Leader1".to_string(), peers(&[])),
+                ("Leader2".to_string(), peers(&["Leader1"])),
+                ("Leader3".to_string(), peers(&["Leader1", "Leader2"])),
+                (
+                    "Wallet_Node".to_string(),
+                    peers(&["Leader1", "Leader2", "Leader3"])
+                ),
+                (
+                    "Wallet_Node2".to_string(),
+                    peers(&["Leader1", "Leader2", "Leader3"])
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn star_nodes_trust_first_leader() {
+        let trusted_peers = topology(3, 1, TrustedPeersLayout::Star).trusted_peers();
+        assert_eq!(
+            trusted_peers,
+            vec![
+                ("Leader1".to_string(), peers(&[])),
+                ("Leader2".to_string(), peers(&["Leader1"])),
+                ("Leader3".to_string(), peers(&["Leader1"])),
+                ("Wallet_Node".to_string(), peers(&["Leader1"])),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn chain_nodes_trust_previous_node() {
+        let trusted_peers = topology(2, 2, TrustedPeersLayout::Chain).trusted_peers();
+        assert_eq!(
+            trusted_peers,
+            vec![
+                ("Leader1".to_string(), peers(&[])),
+                ("Leader2".to_string(), peers(&["Leader1"])),
+                ("Wallet_Node".to_string(), peers(&["Leader2"])),
+                ("Wallet_Node2".to_string(), peers(&["Wallet_Node"])),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn layout_is_parsed_case_insensitive() {
+        assert_eq!(
+            parse_layout_from_str("Full-Mesh"),
+            Ok(TrustedPeersLayout::FullMesh)
+        );
+        assert_eq!(parse_layout_from_str("star"), Ok(TrustedPeersLayout::Star));
+        assert_eq!(
+            parse_layout_from_str("CHAIN"),
+            Ok(TrustedPeersLayout::Chain)
+        );
+        assert!(parse_layout_from_str("ring").is_err());
+    }
+
+    #[test]
+    pub fn proxy_is_attached_to_first_wallet_node_or_leader() {
+        assert_eq!(
+            topology(2, 1, TrustedPeersLayout::FullMesh)
+                .proxy_node()
+                .unwrap(),
+            WALLET_NODE
+        );
+        assert_eq!(
+            topology(2, 0, TrustedPeersLayout::FullMesh)
+                .proxy_node()
+                .unwrap(),
+            "Leader1"
+        );
+        assert!(topology(0, 0, TrustedPeersLayout::FullMesh)
+            .proxy_node()
+            .is_err());
+    }
+}
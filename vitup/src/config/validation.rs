@@ -0,0 +1,570 @@
+use super::env::VitStartParameters;
+use super::initials::{Initial, Initials};
+use super::vote_options::{read_imported, value_to_string, Error as ImportError};
+use super::DataGenerationConfig;
+use chrono::NaiveDateTime;
+use std::path::Path;
+use thiserror::Error;
+
+/// Format of timestamps accepted by command line parameters
+pub const TIMESTAMP_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+const MAX_SLOTS_PER_EPOCH: u32 = 1_000_000;
+const MAX_ACTIVE_SLOT_COEFFICIENT: u64 = 1_000;
+const MIN_KES_UPDATE_SPEED: u32 = 60;
+const MAX_KES_UPDATE_SPEED: u32 = 365 * 24 * 3600;
+const MAX_VOTE_OPTIONS: usize = u8::MAX as usize;
+
+/// All problems found in configuration
+#[derive(Debug, Error)]
+#[error("invalid configuration:\n{}", format_problems(.0))]
+pub struct ValidationError(pub Vec<String>);
+
+fn format_problems(problems: &[String]) -> String {
+    problems
+        .iter()
+        .map(|problem| format!(" - {}", problem))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+pub fn into_result(problems: Vec<String>) -> Result<(), ValidationError> {
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(ValidationError(problems))
+    }
+}
+
+/// Parses timestamp passed as command line parameter with [`TIMESTAMP_FORMAT`]
+pub fn parse_timestamp(name: &str, timestamp: &str) -> Result<NaiveDateTime, String> {
+    NaiveDateTime::parse_from_str(timestamp, TIMESTAMP_FORMAT).map_err(|_| {
+        format!(
+            "{} '{}' does not match format '{}'",
+            name, timestamp, TIMESTAMP_FORMAT
+        )
+    })
+}
+
+/// Checks format of timestamps passed as command line parameters, which are parsed later
+/// with [`parse_timestamp`]
+pub fn timestamps_problems(timestamps: &[(&str, &Option<String>)]) -> Vec<String> {
+    timestamps
+        .iter()
+        .filter_map(|(name, timestamp)| parse_timestamp(name, timestamp.as_ref()?).err())
+        .collect()
+}
+
+/// Checks that fund id of parameters is defined in imported funds file and that all
+/// imported challenges belong to this fund
+pub fn imported_fund_problems<P: AsRef<Path>, Q: AsRef<Path>>(
+    fund_id: i32,
+    funds: P,
+    challenges: Q,
+) -> Result<Vec<String>, ImportError> {
+    let fund_id = fund_id.to_string();
+    let mut problems = Vec::new();
+
+    let funds_ids: Vec<String> = read_imported(funds)?
+        .iter()
+        .filter_map(|fund| fund.get("id").and_then(value_to_string))
+        .collect();
+    if !funds_ids.contains(&fund_id) {
+        problems.push(format!(
+            "fund id ({}) is not defined in imported funds ({})",
+            fund_id,
+            funds_ids.join(", ")
+        ));
+    }
+
+    for challenge in read_imported(challenges)? {
+        let challenge_fund_id = challenge.get("fund_id").and_then(value_to_string);
+        if challenge_fund_id.as_ref() != Some(&fund_id) {
+            problems.push(format!(
+                "imported challenge '{}' belongs to fund '{}', expected fund id ({})",
+                challenge
+                    .get("id")
+                    .and_then(value_to_string)
+                    .unwrap_or_default(),
+                challenge_fund_id.unwrap_or_default(),
+                fund_id
+            ));
+        }
+    }
+    Ok(problems)
+}
+
+impl VitStartParameters {
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        into_result(self.problems())
+    }
+
+    pub fn problems(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+
+        if !(self.vote_start < self.vote_tally && self.vote_tally < self.tally_end) {
+            problems.push(format!(
+                "vote phases epochs should be ordered: vote_start ({}) < vote_tally ({}) < tally_end ({})",
+                self.vote_start, self.vote_tally, self.tally_end
+            ));
+        }
+        problems.extend(self.timestamps_problems());
+
+        if self.voting_power == 0 {
+            problems.push("voting power threshold should be greater than 0".to_string());
+        }
+        if self.slot_duration == 0 {
+            problems.push("slot duration should be greater than 0".to_string());
+        }
+        if self.slots_per_epoch == 0 || self.slots_per_epoch > MAX_SLOTS_PER_EPOCH {
+            problems.push(format!(
+                "slots per epoch ({}) should be in range 1..={}",
+                self.slots_per_epoch, MAX_SLOTS_PER_EPOCH
+            ));
+        }
+        if self.fund_id < 1 {
+            problems.push(format!("fund id ({}) should be positive", self.fund_id));
+        }
+        if self.fund_name.is_empty() {
+            problems.push("fund name should not be empty".to_string());
+        }
+        if self.proposals > 0 && self.challenges == 0 {
+            problems.push("proposals are defined, but there are no challenges".to_string());
+        }
+        if self.topology.leaders == 0 {
+            problems.push("topology should define at least one leader".to_string());
+        }
+        // both are part of block0 regardless of consensus version
+        if self.consensus.active_slot_coefficient == 0
+            || self.consensus.active_slot_coefficient > MAX_ACTIVE_SLOT_COEFFICIENT
+        {
+            problems.push(format!(
+                "active slot coefficient ({}) should be in range 1..={} millis",
+                self.consensus.active_slot_coefficient, MAX_ACTIVE_SLOT_COEFFICIENT
+            ));
+        }
+        if self.consensus.kes_update_speed < MIN_KES_UPDATE_SPEED
+            || self.consensus.kes_update_speed > MAX_KES_UPDATE_SPEED
+        {
+            problems.push(format!(
+                "kes update speed ({}) should be in range {}..={} seconds",
+                self.consensus.kes_update_speed, MIN_KES_UPDATE_SPEED, MAX_KES_UPDATE_SPEED
+            ));
+        }
+        problems.extend(self.vote_plans_problems());
+
+        let committee = &self.private_committee;
+        if committee.members == 0 {
+            problems.push("private committee should have at least one member".to_string());
+        }
+
+        if let Some(initials) = &self.initials {
+            problems.extend(initials.problems());
+        }
+        problems
+    }
+
+    fn timestamps_problems(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        let phases = [
+            self.vote_start_timestamp,
+            self.tally_start_timestamp,
+            self.tally_end_timestamp,
+        ];
+        let defined = phases.iter().filter(|x| x.is_some()).count();
+        if defined > 0 && defined < phases.len() {
+            problems.push(
+                "either define all of vote_start_timestamp, tally_start_timestamp and tally_end_timestamp or none"
+                    .to_string(),
+            );
+        }
+
+        if let [Some(vote_start), Some(tally_start), Some(tally_end)] = phases {
+            if !(vote_start < tally_start && tally_start < tally_end) {
+                problems.push(format!(
+                    "vote phases timestamps should be ordered: vote start ({}) < tally start ({}) < tally end ({})",
+                    vote_start, tally_start, tally_end
+                ));
+            }
+            if let Some(block0_time) = self.block0_time {
+                if block0_time > vote_start {
+                    problems.push(format!(
+                        "block0 time ({}) should not be after vote start ({})",
+                        block0_time, vote_start
+                    ));
+                }
+            }
+            if let Some(refresh_time) = self.refresh_time {
+                if refresh_time > vote_start {
+                    problems.push(format!(
+                        "snapshot time ({}) should not be after vote start ({})",
+                        refresh_time, vote_start
+                    ));
+                }
+            }
+            if let Some(next_vote_start_time) = self.next_vote_start_time {
+                if next_vote_start_time < tally_end {
+                    problems.push(format!(
+                        "next fund start ({}) should not be before tally end ({})",
+                        next_vote_start_time, tally_end
+                    ));
+                }
+            }
+        }
+        problems
+    }
+
+    fn vote_plans_problems(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        for (index, settings) in self.vote_plans.iter().enumerate() {
+            let vote_start = settings.vote_start.unwrap_or(self.vote_start);
+            let vote_tally = settings.vote_tally.unwrap_or(self.vote_tally);
+            let tally_end = settings.tally_end.unwrap_or(self.tally_end);
+            if !(vote_start < vote_tally && vote_tally < tally_end) {
+                problems.push(format!(
+                    "vote plan settings #{}: phases epochs should be ordered: vote_start ({}) < vote_tally ({}) < tally_end ({})",
+                    index, vote_start, vote_tally, tally_end
+                ));
+            }
+        }
+        problems.extend(vote_options_problems("default", &self.vote_options.default));
+        for (id, options) in self.vote_options.challenges.iter() {
+            problems.extend(vote_options_problems(
+                &format!("challenge '{}'", id),
+                options,
+            ));
+        }
+        for (index, settings) in self.vote_options.proposals.iter() {
+            if let Some(options) = &settings.options {
+                problems.extend(vote_options_problems(
+                    &format!("proposal #{}", index),
+                    options,
+                ));
+            }
+        }
+        for (index, proposal) in self.vote_options.imported.iter().enumerate() {
+            if let Some(options) = &proposal.options {
+                problems.extend(vote_options_problems(
+                    &format!("imported proposal #{}", index),
+                    options,
+                ));
+            }
+        }
+        if !self.vote_options.is_imported() {
+            let challenge_ids = self
+                .vote_plans
+                .iter()
+                .flat_map(|settings| settings.challenges.iter())
+                .chain(self.vote_options.challenges.keys());
+            for id in challenge_ids {
+                if !matches!(id.parse::<usize>(), Ok(id) if id >= 1 && id <= self.challenges) {
+                    problems.push(format!(
+                        "challenge '{}' is not generated, challenges ids are in range 1..={}",
+                        id, self.challenges
+                    ));
+                }
+            }
+        }
+        problems
+    }
+}
+
+/// Proposal on chain has between 1 and 255 options
+fn vote_options_problems(owner: &str, options: &[String]) -> Vec<String> {
+    if options.is_empty() || options.len() > MAX_VOTE_OPTIONS {
+        vec![format!(
+            "{} vote options count ({}) should be in range 1..={}",
+            owner,
+            options.len(),
+            MAX_VOTE_OPTIONS
+        )]
+    } else {
+        Vec::new()
+    }
+}
+
+/// Every wallet of distribution gets non zero value
+fn total_problem(name: &str, count: usize, total: u64) -> Option<String> {
+    if count as u64 > total {
+        Some(format!(
+            "{} total ({}) should be at least wallets count ({})",
+            name, total, count
+        ))
+    } else {
+        None
+    }
+}
+
+impl Initials {
+    pub fn problems(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        for initial in self.0.iter() {
+            if let Some(pin) = initial.pin() {
+                if pin.len() != 4 || !pin.chars().all(|c| c.is_ascii_digit()) {
+                    problems.push(format!("pin '{}' should consist of 4 digits", pin));
+                }
+            }
+            match initial {
+                Initial::LogNormal {
+                    log_normal,
+                    sigma,
+                    total,
+                    ..
+                } => {
+                    if sigma.is_nan() || *sigma <= 0.0 {
+                        problems.push(format!("log-normal sigma ({}) should be positive", sigma));
+                    }
+                    problems.extend(total_problem("log-normal", *log_normal, *total));
+                }
+                Initial::Pareto {
+                    pareto,
+                    shape,
+                    total,
+                    ..
+                } => {
+                    if shape.is_nan() || *shape <= 0.0 {
+                        problems.push(format!("pareto shape ({}) should be positive", shape));
+                    }
+                    problems.extend(total_problem("pareto", *pareto, *total));
+                }
+                Initial::Histogram { histogram, .. } => {
+                    for bucket in histogram.iter().filter(|bucket| bucket.start >= bucket.end) {
+                        problems.push(format!(
+                            "histogram range {}..{} is empty",
+                            bucket.start, bucket.end
+                        ));
+                    }
+                }
+                _ => {}
+            }
+        }
+        problems
+    }
+}
+
+impl DataGenerationConfig {
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        into_result(self.problems())
+    }
+
+    pub fn problems(&self) -> Vec<String> {
+        let mut problems = self.params.problems();
+        if self.params.has_private_vote_plans() && !self.committees.is_empty() {
+            problems.push(
+                "private voting requires committee generated by vitup, external committees cannot decrypt tally"
+                    .to_string(),
+            );
+        }
+        problems
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{imported_fund_problems, parse_timestamp, timestamps_problems};
+    use crate::config::{
+        HistogramBucket, InitialEntry, Initials, VitStartParameters, VotePlanSettings,
+    };
+
+    fn timestamp(timestamp: &str) -> chrono::NaiveDateTime {
+        parse_timestamp("timestamp", timestamp).unwrap()
+    }
+
+    fn has_problem(parameters: &VitStartParameters, problem: &str) -> bool {
+        parameters
+            .problems()
+            .iter()
+            .any(|found| found.contains(problem))
+    }
+
+    #[test]
+    pub fn default_parameters_are_valid() {
+        assert!(VitStartParameters::default().validate().is_ok());
+    }
+
+    #[test]
+    pub fn all_problems_are_reported_at_once() {
+        let mut parameters = VitStartParameters::default();
+        parameters.vote_start = 3;
+        parameters.voting_power = 0;
+        parameters.fund_name = String::new();
+        let problems = parameters.validate().unwrap_err().0;
+        assert_eq!(problems.len(), 3, "{:?}", problems);
+    }
+
+    #[test]
+    pub fn unordered_vote_phases_are_rejected() {
+        let mut parameters = VitStartParameters::default();
+        parameters.vote_tally = parameters.tally_end;
+        assert!(has_problem(
+            &parameters,
+            "vote phases epochs should be ordered"
+        ));
+
+        let mut parameters = VitStartParameters::default();
+        parameters.vote_plans = vec![VotePlanSettings {
+            challenges: vec!["1".to_string()],
+            vote_start: Some(5),
+            ..Default::default()
+        }];
+        assert!(has_problem(&parameters, "vote plan settings #0"));
+    }
+
+    #[test]
+    pub fn timestamps_should_be_all_defined_and_ordered() {
+        let mut parameters = VitStartParameters::default();
+        parameters.vote_start_timestamp = Some(timestamp("2021-10-01 10:00:00"));
+        assert!(has_problem(&parameters, "either define all"));
+
+        parameters.tally_start_timestamp = Some(timestamp("2021-10-03 10:00:00"));
+        parameters.tally_end_timestamp = Some(timestamp("2021-10-02 10:00:00"));
+        assert!(has_problem(
+            &parameters,
+            "vote phases timestamps should be ordered"
+        ));
+
+        parameters.tally_end_timestamp = Some(timestamp("2021-10-04 10:00:00"));
+        parameters.block0_time = Some(timestamp("2021-10-01 11:00:00"));
+        assert!(has_problem(&parameters, "block0 time"));
+
+        parameters.block0_time = Some(timestamp("2021-10-01 09:00:00"));
+        assert!(parameters.validate().is_ok());
+    }
+
+    #[test]
+    pub fn timestamps_format_is_checked() {
+        let valid = Some("2021-10-01 10:00:00".to_string());
+        let invalid = Some("01.10.2021".to_string());
+        let problems = timestamps_problems(&[
+            ("vote-start-timestamp", &valid),
+            ("tally-start-timestamp", &invalid),
+            ("tally-end-timestamp", &None),
+        ]);
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].starts_with("tally-start-timestamp '01.10.2021'"));
+    }
+
+    #[test]
+    pub fn consensus_settings_are_checked() {
+        let mut parameters = VitStartParameters::default();
+        parameters.consensus.kes_update_speed = 59;
+        assert!(has_problem(&parameters, "kes update speed (59)"));
+
+        parameters.consensus.kes_update_speed = 365 * 24 * 3600 + 1;
+        assert!(has_problem(&parameters, "kes update speed"));
+
+        parameters.consensus.kes_update_speed = 43200;
+        parameters.consensus.active_slot_coefficient = 0;
+        assert!(has_problem(&parameters, "active slot coefficient (0)"));
+
+        parameters.consensus.active_slot_coefficient = 1_001;
+        assert!(has_problem(&parameters, "active slot coefficient (1001)"));
+
+        parameters.slots_per_epoch = 0;
+        assert!(has_problem(&parameters, "slots per epoch (0)"));
+    }
+
+    #[test]
+    pub fn vote_options_count_is_checked() {
+        let mut parameters = VitStartParameters::default();
+        parameters.vote_options.default = Vec::new();
+        assert!(has_problem(&parameters, "default vote options count (0)"));
+
+        let mut parameters = VitStartParameters::default();
+        parameters.vote_options.challenges.insert(
+            "1".to_string(),
+            (0..256).map(|option| option.to_string()).collect(),
+        );
+        assert!(has_problem(
+            &parameters,
+            "challenge '1' vote options count (256)"
+        ));
+    }
+
+    #[test]
+    pub fn challenges_should_be_generated() {
+        let mut parameters = VitStartParameters::default();
+        parameters.vote_plans = vec![VotePlanSettings {
+            challenges: vec!["5".to_string()],
+            ..Default::default()
+        }];
+        assert!(has_problem(&parameters, "challenge '5' is not generated"));
+
+        parameters.challenges = 5;
+        assert!(parameters.validate().is_ok());
+    }
+
+    #[test]
+    pub fn private_committee_should_have_members() {
+        let mut parameters = VitStartParameters::default();
+        parameters.private_committee.members = 0;
+        assert!(has_problem(
+            &parameters,
+            "private committee should have at least one member"
+        ));
+
+        parameters.private_committee.members = 3;
+        assert!(parameters.validate().is_ok());
+    }
+
+    #[test]
+    pub fn initials_are_checked() {
+        let initials = Initials(vec![
+            InitialEntry::AboveThreshold {
+                above_threshold: 1,
+                pin: "12345".to_string(),
+            },
+            InitialEntry::LogNormal {
+                log_normal: 10,
+                sigma: 0.0,
+                total: 9,
+                seed: None,
+                pin: "1234".to_string(),
+            },
+            InitialEntry::Pareto {
+                pareto: 10,
+                shape: f64::NAN,
+                total: 100,
+                seed: None,
+                pin: "1234".to_string(),
+            },
+            InitialEntry::Histogram {
+                histogram: vec![HistogramBucket {
+                    start: 10,
+                    end: 10,
+                    count: 1,
+                }],
+                total: None,
+                seed: None,
+                pin: "1234".to_string(),
+            },
+        ]);
+        assert_eq!(
+            initials.problems(),
+            vec![
+                "pin '12345' should consist of 4 digits".to_string(),
+                "log-normal sigma (0) should be positive".to_string(),
+                "log-normal total (9) should be at least wallets count (10)".to_string(),
+                "pareto shape (NaN) should be positive".to_string(),
+                "histogram range 10..10 is empty".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn fund_id_should_match_imported_funds_and_challenges() {
+        let funds = "../resources/tests/example/funds.json";
+        let challenges = "../resources/tests/example/challenges.json";
+
+        assert!(imported_fund_problems(4, funds, challenges)
+            .unwrap()
+            .is_empty());
+
+        let problems = imported_fund_problems(1, funds, challenges).unwrap();
+        assert!(problems[0].starts_with("fund id (1) is not defined in imported funds (4)"));
+        assert!(problems[1..]
+            .iter()
+            .all(|problem| problem.contains("belongs to fund '4', expected fund id (1)")));
+        assert!(problems.len() > 1);
+    }
+}
@@ -0,0 +1,258 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+use thiserror::Error;
+
+pub type VoteOptions = Vec<String>;
+
+/// Action executed on chain when proposal is accepted
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case", tag = "type")]
+pub enum ProposalAction {
+    OffChain,
+    /// moves value from treasury to rewards
+    Treasury {
+        value: u64,
+    },
+    /// increases rewards by value
+    Parameters {
+        value: u64,
+    },
+}
+
+impl Default for ProposalAction {
+    fn default() -> Self {
+        ProposalAction::OffChain
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ProposalSettings {
+    #[serde(default)]
+    pub options: Option<VoteOptions>,
+    #[serde(default)]
+    pub action: ProposalAction,
+}
+
+/// Proposal data taken from imported proposals file, in the same order as proposals
+/// are put into vote plans
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ImportedProposal {
+    pub challenge_id: Option<String>,
+    pub options: Option<VoteOptions>,
+}
+
+/// Vote options and actions of generated proposals. Options are resolved in order:
+/// per proposal (by index in vote plans), imported proposal data, per challenge (by id)
+/// and default. Unless proposals are imported, proposal with index `i` belongs
+/// to challenge `i % challenges + 1`
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct VoteOptionsConfig {
+    #[serde(default = "default_options")]
+    pub default: VoteOptions,
+    #[serde(default)]
+    pub challenges: HashMap<String, VoteOptions>,
+    #[serde(default)]
+    pub proposals: HashMap<usize, ProposalSettings>,
+    #[serde(default)]
+    pub imported: Vec<ImportedProposal>,
+}
+
+fn default_options() -> VoteOptions {
+    vec!["blank".to_string(), "yes".to_string(), "no".to_string()]
+}
+
+impl Default for VoteOptionsConfig {
+    fn default() -> Self {
+        Self {
+            default: default_options(),
+            challenges: HashMap::new(),
+            proposals: HashMap::new(),
+            imported: Vec::new(),
+        }
+    }
+}
+
+impl VoteOptionsConfig {
+    /// Reads challenge ids and vote options (`chain_vote_options` csv) of proposals
+    /// from ideascale-like proposals file
+    pub fn import_proposals<P: AsRef<Path>>(&mut self, proposals: P) -> Result<(), Error> {
+        self.imported = read_imported(proposals)?
+            .iter()
+            .map(|proposal| ImportedProposal {
+                challenge_id: proposal.get("challenge_id").and_then(value_to_string),
+                options: proposal
+                    .get("chain_vote_options")
+                    .and_then(|options| options.as_str())
+                    .map(parse_options),
+            })
+            .collect();
+        Ok(())
+    }
+
+    pub fn is_imported(&self) -> bool {
+        !self.imported.is_empty()
+    }
+
+    pub fn challenge_id(&self, index: usize, challenges: usize) -> Option<String> {
+        if self.is_imported() {
+            return self
+                .imported
+                .get(index)
+                .and_then(|proposal| proposal.challenge_id.clone());
+        }
+        Some((index % challenges.max(1) + 1).to_string())
+    }
+
+    pub fn options(&self, index: usize, challenges: usize) -> VoteOptions {
+        if let Some(options) = self
+            .proposals
+            .get(&index)
+            .and_then(|proposal| proposal.options.clone())
+        {
+            return options;
+        }
+        if let Some(options) = self
+            .imported
+            .get(index)
+            .and_then(|proposal| proposal.options.clone())
+        {
+            return options;
+        }
+        self.challenge_id(index, challenges)
+            .and_then(|id| self.challenges.get(&id).cloned())
+            .unwrap_or_else(|| self.default.clone())
+    }
+
+    pub fn action(&self, index: usize) -> ProposalAction {
+        self.proposals
+            .get(&index)
+            .map(|proposal| proposal.action.clone())
+            .unwrap_or_default()
+    }
+}
+
+pub fn parse_options(options: &str) -> VoteOptions {
+    options
+        .split(',')
+        .map(|option| option.trim().to_string())
+        .filter(|option| !option.is_empty())
+        .collect()
+}
+
+/// Entries of ideascale-like json file (proposals, challenges or funds)
+pub(super) fn read_imported<P: AsRef<Path>>(path: P) -> Result<Vec<serde_json::Value>, Error> {
+    let content = std::fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&content)?)
+}
+
+pub(super) fn value_to_string(value: &serde_json::Value) -> Option<String> {
+    match value {
+        serde_json::Value::String(value) => Some(value.clone()),
+        serde_json::Value::Number(value) => Some(value.to_string()),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("cannot read imported file")]
+    IoError(#[from] std::io::Error),
+    #[error("cannot parse imported file")]
+    SerdeError(#[from] serde_json::Error),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        parse_options, ImportedProposal, ProposalAction, ProposalSettings, VoteOptionsConfig,
+    };
+
+    fn options(options: &[&str]) -> Vec<String> {
+        options.iter().map(|option| option.to_string()).collect()
+    }
+
+    fn config() -> VoteOptionsConfig {
+        let mut config = VoteOptionsConfig::default();
+        config
+            .challenges
+            .insert("2".to_string(), options(&["challenge"]));
+        config.proposals.insert(
+            0,
+            ProposalSettings {
+                options: Some(options(&["proposal"])),
+                action: ProposalAction::Treasury { value: 100 },
+            },
+        );
+        config
+    }
+
+    #[test]
+    pub fn generated_proposals_belong_to_challenges_in_turn() {
+        let config = VoteOptionsConfig::default();
+        let ids: Vec<Option<String>> = (0..5).map(|index| config.challenge_id(index, 3)).collect();
+        assert_eq!(
+            ids,
+            vec![
+                Some("1".to_string()),
+                Some("2".to_string()),
+                Some("3".to_string()),
+                Some("1".to_string()),
+                Some("2".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn options_are_resolved_per_proposal_then_challenge_then_default() {
+        let config = config();
+        // proposal 0 belongs to challenge 1, but has own options
+        assert_eq!(config.options(0, 2), options(&["proposal"]));
+        assert_eq!(config.options(1, 2), options(&["challenge"]));
+        assert_eq!(config.options(2, 2), options(&["blank", "yes", "no"]));
+    }
+
+    #[test]
+    pub fn imported_options_take_precedence_over_challenge_options() {
+        let mut config = config();
+        config.imported = vec![
+            ImportedProposal {
+                challenge_id: Some("2".to_string()),
+                options: Some(options(&["imported"])),
+            },
+            ImportedProposal {
+                challenge_id: Some("2".to_string()),
+                options: Some(options(&["imported"])),
+            },
+            ImportedProposal {
+                challenge_id: Some("2".to_string()),
+                options: None,
+            },
+            ImportedProposal {
+                challenge_id: None,
+                options: None,
+            },
+        ];
+        assert_eq!(config.options(0, 2), options(&["proposal"]));
+        assert_eq!(config.options(1, 2), options(&["imported"]));
+        assert_eq!(config.options(2, 2), options(&["challenge"]));
+        assert_eq!(config.options(3, 2), options(&["blank", "yes", "no"]));
+        assert_eq!(config.challenge_id(3, 2), None);
+    }
+
+    #[test]
+    pub fn action_is_off_chain_unless_defined() {
+        let config = config();
+        assert_eq!(config.action(0), ProposalAction::Treasury { value: 100 });
+        assert_eq!(config.action(1), ProposalAction::OffChain);
+    }
+
+    #[test]
+    pub fn options_are_trimmed_and_empty_ones_skipped() {
+        assert_eq!(
+            parse_options(" yes, no,,blank "),
+            options(&["yes", "no", "blank"])
+        );
+        assert!(parse_options("").is_empty());
+    }
+}
@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+/// Vote plan grouping proposals of given challenges. Undefined phases, payload type and
+/// committee are taken from global parameters
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct VotePlanSettings {
+    pub challenges: Vec<String>,
+    #[serde(default)]
+    pub vote_start: Option<u64>,
+    #[serde(default)]
+    pub vote_tally: Option<u64>,
+    #[serde(default)]
+    pub tally_end: Option<u64>,
+    #[serde(default)]
+    pub private: Option<bool>,
+    /// alias of committee wallet which owns vote plan
+    #[serde(default)]
+    pub committee: Option<String>,
+}
+
+impl VotePlanSettings {
+    pub fn contains(&self, challenge_id: &str) -> bool {
+        self.challenges.iter().any(|id| id == challenge_id)
+    }
+}
@@ -35,6 +35,13 @@ error_chain! {
         ChainAddressError(chain_addr::Error);
         ChainBech32Error(chain_crypto::bech32::Error);
         GlobError(glob::GlobError);
+        ReplayError(crate::client::replay::Error);
+        VoteOptionsError(crate::config::VoteOptionsError);
+        DistributionError(crate::config::DistributionError);
+        ValidationError(crate::config::ValidationError);
+        ManifestError(crate::scenario::manifest::Error);
+        CommitteeError(crate::scenario::committee::Error);
+        PrivateTallyError(crate::setup::tally::Error);
     }
 
     errors {
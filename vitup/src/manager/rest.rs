@@ -1,9 +1,10 @@
 use super::file_lister;
 use super::State;
-use crate::config::VitStartParameters;
+use crate::config::{ValidationError, VitStartParameters};
 use crate::manager::{
     APIToken, APITokenManager, ControlContext, ControlContextLock, API_TOKEN_HEADER,
 };
+use crate::scenario::manifest::{self, Manifest};
 use futures::FutureExt;
 use futures::{channel::mpsc, StreamExt};
 use jortestkit::web::api_token::TokenError;
@@ -13,6 +14,8 @@ use warp::http::StatusCode;
 use warp::reject::Reject;
 use warp::{Filter, Rejection, Reply};
 impl Reject for file_lister::Error {}
+impl Reject for manifest::Error {}
+impl Reject for ValidationError {}
 
 #[derive(Clone)]
 pub struct ServerStopper(mpsc::Sender<()>);
@@ -89,7 +92,18 @@ pub async fn start_rest_server(context: ControlContextLock) {
         .and_then(status_handler)
         .boxed();
 
-    let api = files.or(control).or(status).recover(report_invalid).boxed();
+    let manifest = warp::path!("manifest")
+        .and(warp::get())
+        .and(with_context.clone())
+        .and_then(manifest_handler)
+        .boxed();
+
+    let api = files
+        .or(control)
+        .or(status)
+        .or(manifest)
+        .recover(report_invalid)
+        .boxed();
 
     let server = warp::serve(api);
     let (_, server_fut) = server.bind_with_graceful_shutdown(([0, 0, 0, 0], 3030), stopper_rx);
@@ -101,10 +115,16 @@ pub async fn file_lister_handler(context: ControlContextLock) -> Result<impl Rep
     Ok(file_lister::dump_json(context_lock.working_directory())?).map(|r| warp::reply::json(&r))
 }
 
+pub async fn manifest_handler(context: ControlContextLock) -> Result<impl Reply, Rejection> {
+    let context_lock = context.lock().unwrap();
+    Ok(Manifest::read(context_lock.working_directory())?).map(|r| warp::reply::json(&r))
+}
+
 pub async fn start_handler(
     context: ControlContextLock,
     parameters: VitStartParameters,
 ) -> Result<impl Reply, Rejection> {
+    parameters.validate().map_err(warp::reject::custom)?;
     let mut context_lock = context.lock().unwrap();
     context_lock.set_parameters(parameters);
     let state = context_lock.state();
@@ -163,6 +183,16 @@ async fn report_invalid(r: Rejection) -> Result<impl Reply, Infallible> {
             e.to_string(),
             StatusCode::BAD_REQUEST,
         ))
+    } else if let Some(e) = r.find::<ValidationError>() {
+        Ok(warp::reply::with_status(
+            e.to_string(),
+            StatusCode::BAD_REQUEST,
+        ))
+    } else if let Some(e) = r.find::<manifest::Error>() {
+        Ok(warp::reply::with_status(
+            format!("{}. try to start backend", e),
+            StatusCode::NOT_FOUND,
+        ))
     } else {
         // Do prettier error reporting for the default error here.
         Ok(warp::reply::with_status(
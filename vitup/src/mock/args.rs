@@ -4,11 +4,14 @@ use crate::mock::{
     config::{read_config, Configuration},
     context::Context,
 };
+use jormungandr_testing_utils::testing::network_builder::Seed;
+use std::fs::File;
 use std::path::Path;
 use std::sync::Mutex;
 use std::{path::PathBuf, sync::Arc};
 use structopt::StructOpt;
 use thiserror::Error;
+use tracing_subscriber::fmt::format::FmtSpan;
 
 #[derive(StructOpt, Debug)]
 pub struct MockStartCommandArgs {
@@ -20,22 +23,75 @@ pub struct MockStartCommandArgs {
 
     #[structopt(long = "params")]
     pub params: Option<PathBuf>,
+
+    /// directory with mock state saved previously. Mock restores state from it
+    /// on start and saves state into it on demand
+    #[structopt(long = "state")]
+    pub state: Option<PathBuf>,
+
+    /// serves mock over https. Unless certificate and key are provided,
+    /// CA and server certificate are generated into working dir
+    #[structopt(long = "https")]
+    pub https: bool,
+
+    #[structopt(long = "cert", requires = "key")]
+    pub cert: Option<PathBuf>,
+
+    #[structopt(long = "key", requires = "cert")]
+    pub key: Option<PathBuf>,
+
+    /// additional host name for generated certificate (e.g. machine name in local network)
+    #[structopt(long = "host")]
+    pub hosts: Vec<String>,
+
+    /// seed of generated backend. The same seed generates the same wallets and block0
+    #[structopt(long = "seed")]
+    pub seed: Option<Seed>,
 }
 
 impl MockStartCommandArgs {
     #[tokio::main]
     pub async fn exec(self) -> Result<(), Error> {
         let mut configuration: Configuration = read_config(&self.config)?;
-        let start_params = self.params.as_ref().map(|x| read_params(x).unwrap());
+        let start_params = self.params.as_ref().map(read_params).transpose()?;
+        if let Some(start_params) = &start_params {
+            start_params.validate()?;
+        }
 
         if self.token.is_some() {
             configuration.token = self.token;
         }
 
+        if self.state.is_some() {
+            configuration.state = self.state;
+        }
+
+        if self.seed.is_some() {
+            configuration.seed = self.seed;
+        }
+
+        if self.https || self.cert.is_some() {
+            let tls = configuration.tls.get_or_insert_with(Default::default);
+            if self.cert.is_some() {
+                tls.cert = self.cert;
+                tls.key = self.key;
+            }
+            tls.hosts.extend(self.hosts);
+        }
+
         let control_context = Arc::new(Mutex::new(Context::new(
             configuration.clone(),
             start_params,
-        )));
+        )?));
+
+        let (non_block, _guard) = tracing_appender::non_blocking(File::create("vole.trace")?);
+        let filter =
+            std::env::var("RUST_LOG").unwrap_or_else(|_| "tracing=info,warp=debug".to_owned());
+        tracing_subscriber::fmt()
+            .with_env_filter(filter)
+            .with_writer(non_block)
+            .with_span_events(FmtSpan::CLOSE)
+            .init();
 
         tokio::spawn(async move {
             start_rest_server(control_context.clone()).await;
@@ -63,4 +119,6 @@ pub enum Error {
     Context(#[from] crate::mock::context::Error),
     #[error("join error")]
     JoinError(#[from] tokio::task::JoinError),
+    #[error(transparent)]
+    InvalidParameters(#[from] crate::config::ValidationError),
 }
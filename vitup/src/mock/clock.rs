@@ -0,0 +1,87 @@
+use jormungandr_lib::interfaces::BlockDate;
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant, SystemTime};
+use thiserror::Error;
+
+/// Virtual clock of the mock. It follows wall-clock by default,
+/// but it can be frozen or moved forward, so all time dependent mock aspects
+/// (block production, voting phases) can be exercised without waiting
+pub struct MockClock {
+    base: SystemTime,
+    started: Instant,
+    frozen: bool,
+}
+
+impl Default for MockClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MockClock {
+    pub fn new() -> Self {
+        Self {
+            base: SystemTime::now(),
+            started: Instant::now(),
+            frozen: false,
+        }
+    }
+
+    pub fn starting_at(time: SystemTime, frozen: bool) -> Self {
+        Self {
+            base: time,
+            started: Instant::now(),
+            frozen,
+        }
+    }
+
+    pub fn now(&self) -> SystemTime {
+        if self.frozen {
+            self.base
+        } else {
+            self.base + self.started.elapsed()
+        }
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        self.frozen
+    }
+
+    pub fn freeze(&mut self) {
+        self.base = self.now();
+        self.frozen = true;
+    }
+
+    pub fn resume(&mut self) {
+        if self.frozen {
+            self.started = Instant::now();
+            self.frozen = false;
+        }
+    }
+
+    pub fn advance(&mut self, duration: Duration) {
+        self.base += duration;
+    }
+
+    pub fn set(&mut self, time: SystemTime) -> Result<(), Error> {
+        let now = self.now();
+        let duration = time
+            .duration_since(now)
+            .map_err(|_| Error::CannotMoveBackInTime)?;
+        self.advance(duration);
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeStatus {
+    pub now: jormungandr_lib::time::SystemTime,
+    pub frozen: bool,
+    pub block_date: BlockDate,
+}
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("clock cannot be moved back in time")]
+    CannotMoveBackInTime,
+}
@@ -1,3 +1,4 @@
+use jormungandr_testing_utils::testing::network_builder::Seed;
 use serde::{Deserialize, Serialize};
 use std::path::Path;
 use std::path::PathBuf;
@@ -8,8 +9,111 @@ pub struct Configuration {
     pub port: u16,
     pub token: Option<String>,
     pub ideascale: bool,
+    /// explicit paths to ideascale import files. Defining them implies `ideascale`,
+    /// otherwise files from `../resources/external` are used when `ideascale` is set
+    #[serde(default, alias = "ideascale-data")]
+    pub ideascale_data: Option<IdeascaleData>,
+    /// existing deployment artifacts to serve instead of generating new backend
+    #[serde(default)]
+    pub artifacts: Option<Artifacts>,
     #[serde(alias = "working-dir")]
     pub working_dir: PathBuf,
+    #[serde(default)]
+    pub state: Option<PathBuf>,
+    /// serves mock over https when defined
+    #[serde(default)]
+    pub tls: Option<Tls>,
+    /// seed of generated backend (hex). The same seed generates the same wallets and block0
+    #[serde(default, with = "hex_seed")]
+    pub seed: Option<Seed>,
+}
+
+impl Configuration {
+    /// Ideascale data is used when requested explicitly or when import files are defined
+    pub fn use_ideascale(&self) -> bool {
+        self.ideascale || self.ideascale_data.is_some()
+    }
+
+    /// Configuration with generated backend served on ephemeral port
+    pub fn new<P: AsRef<Path>>(working_dir: P) -> Self {
+        Self {
+            port: 0,
+            token: None,
+            ideascale: false,
+            ideascale_data: None,
+            artifacts: None,
+            working_dir: working_dir.as_ref().to_path_buf(),
+            state: None,
+            tls: None,
+            seed: None,
+        }
+    }
+}
+
+/// Server certificate and key. When both are not defined, CA and server certificate
+/// are generated into `{working_dir}/tls`
+#[derive(Debug, Default, PartialEq, Eq, Clone, Deserialize, Serialize)]
+pub struct Tls {
+    #[serde(default)]
+    pub cert: Option<PathBuf>,
+    #[serde(default)]
+    pub key: Option<PathBuf>,
+    /// host names (besides localhost) for which generated certificate is valid
+    #[serde(default)]
+    pub hosts: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+pub struct IdeascaleData {
+    pub proposals: PathBuf,
+    pub challenges: PathBuf,
+    pub funds: PathBuf,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+pub struct Artifacts {
+    pub block0: PathBuf,
+    pub genesis: PathBuf,
+    #[serde(alias = "vit-data")]
+    pub vit_data: VitData,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VitData {
+    /// vit-servicing-station database file (storage.db)
+    Storage(PathBuf),
+    /// json files with lists of funds, challenges and full proposals
+    /// in the same format as returned by vit-servicing-station rest api
+    Json {
+        funds: PathBuf,
+        challenges: PathBuf,
+        proposals: PathBuf,
+    },
+}
+
+/// Seed is kept in configuration as hex string, the same as accepted by `--seed`
+mod hex_seed {
+    use jormungandr_testing_utils::testing::network_builder::Seed;
+    use serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
+    use std::str::FromStr;
+
+    pub fn serialize<S: Serializer>(seed: &Option<Seed>, serializer: S) -> Result<S::Ok, S::Error> {
+        seed.as_ref()
+            .map(|seed| hex::encode(seed.as_ref()))
+            .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Seed>, D::Error> {
+        Option::<String>::deserialize(deserializer)?
+            .map(|seed| {
+                Seed::from_str(&seed)
+                    .map_err(|_| D::Error::custom(format!("invalid seed: {}", seed)))
+            })
+            .transpose()
+    }
 }
 
 pub fn read_config<P: AsRef<Path>>(config: P) -> Result<Configuration, Error> {
@@ -1,31 +1,65 @@
 pub type ContextLock = Arc<Mutex<Context>>;
 use crate::config::VitStartParameters;
 use crate::mock::config::Configuration;
-use crate::mock::mock_state::MockState;
+use crate::mock::fault::FaultInjector;
+use crate::mock::metrics::Metrics;
+use crate::mock::mock_state::{Error as MockStateError, MockState};
+use crate::mock::recorder::Recorder;
+use crate::mock::tls::{Certificates, Error as TlsError};
 use crate::mock::Logger;
 use iapyx::VitVersion;
-use serde::{Deserialize, Serialize};
 use std::net::SocketAddr;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::Mutex;
 use thiserror::Error;
+use tokio_rustls::rustls::ServerConfig;
+
+const TLS_DIR: &str = "tls";
 
 pub struct Context {
     config: Configuration,
     address: SocketAddr,
     state: MockState,
     logger: Logger,
+    faults: FaultInjector,
+    recorder: Recorder,
+    metrics: Metrics,
+    certificates: Option<Certificates>,
+    tls_config: Option<ServerConfig>,
 }
 
 impl Context {
-    pub fn new(config: Configuration, params: Option<VitStartParameters>) -> Self {
-        Self {
+    pub fn new(config: Configuration, params: Option<VitStartParameters>) -> Result<Self, Error> {
+        let state = match &config.state {
+            Some(state_dir) if MockState::is_saved_in(state_dir) => MockState::load(state_dir)?,
+            _ => MockState::new(params.unwrap_or_default(), config.clone())?,
+        };
+
+        let tls_dir = config.working_dir.join(TLS_DIR);
+        let certificates = config
+            .tls
+            .as_ref()
+            .map(|tls| Certificates::from_config(tls, &tls_dir))
+            .transpose()?;
+        let tls_config = certificates
+            .as_ref()
+            .map(Certificates::server_config)
+            .transpose()?;
+
+        let context = Self {
             address: ([0, 0, 0, 0], config.port).into(),
-            state: MockState::new(params.unwrap_or_default(), config.clone()).unwrap(),
+            state,
             config,
             logger: Logger::new(),
-        }
+            faults: FaultInjector::default(),
+            recorder: Recorder::new(),
+            metrics: Metrics::default(),
+            certificates,
+            tls_config,
+        };
+        context.write_certificates()?;
+        Ok(context)
     }
 
     pub fn log<S: Into<String>>(&mut self, message: S) {
@@ -40,12 +74,57 @@ impl Context {
         self.logger.clear()
     }
 
+    pub fn faults(&self) -> &FaultInjector {
+        &self.faults
+    }
+
+    pub fn faults_mut(&mut self) -> &mut FaultInjector {
+        &mut self.faults
+    }
+
+    pub fn recorder(&self) -> &Recorder {
+        &self.recorder
+    }
+
+    pub fn recorder_mut(&mut self) -> &mut Recorder {
+        &mut self.recorder
+    }
+
+    pub fn metrics(&self) -> &Metrics {
+        &self.metrics
+    }
+
     pub fn version(&self) -> VitVersion {
         self.state.version()
     }
 
-    pub fn reset(&mut self, params: VitStartParameters) {
-        self.state = MockState::new(params, self.config.clone()).unwrap();
+    pub fn reset(&mut self, params: VitStartParameters) -> Result<(), Error> {
+        self.state = MockState::new(params, self.config.clone())?;
+        self.write_certificates()
+    }
+
+    pub fn certificates(&self) -> Option<&Certificates> {
+        self.certificates.as_ref()
+    }
+
+    /// Server tls configuration, validated when context is created
+    pub fn tls_config(&self) -> Option<ServerConfig> {
+        self.tls_config.clone()
+    }
+
+    pub fn tls_dir(&self) -> PathBuf {
+        self.working_dir().join(TLS_DIR)
+    }
+
+    /// Generated certificates are kept in working dir, so clients can download CA
+    /// certificate. Working dir is cleaned on reset, therefore they need to be rewritten
+    fn write_certificates(&self) -> Result<(), Error> {
+        if let Some(certificates) = &self.certificates {
+            if certificates.is_generated() {
+                certificates.write(self.tls_dir())?;
+            }
+        }
+        Ok(())
     }
 
     pub fn block0_bin(&self) -> Vec<u8> {
@@ -56,6 +135,25 @@ impl Context {
         self.config.working_dir.clone()
     }
 
+    pub fn state_dir(&self) -> PathBuf {
+        self.config
+            .state
+            .clone()
+            .unwrap_or_else(|| self.working_dir().join("state"))
+    }
+
+    pub fn save_state(&self) -> Result<PathBuf, MockStateError> {
+        let state_dir = self.state_dir();
+        self.state.save(&state_dir)?;
+        Ok(state_dir)
+    }
+
+    pub fn load_state(&mut self) -> Result<PathBuf, MockStateError> {
+        let state_dir = self.state_dir();
+        self.state = MockState::load(&state_dir)?;
+        Ok(state_dir)
+    }
+
     pub fn available(&self) -> bool {
         self.state.available
     }
@@ -82,8 +180,12 @@ impl Context {
     }
 }
 
-#[derive(Debug, Error, Deserialize, Serialize)]
+#[derive(Debug, Error)]
 pub enum Error {
     #[error("account does not exists")]
     AccountDoesNotExist,
+    #[error("cannot create or restore mock state")]
+    MockState(#[from] MockStateError),
+    #[error("cannot prepare tls certificates")]
+    Tls(#[from] TlsError),
 }
@@ -0,0 +1,113 @@
+use super::config::Configuration;
+use super::context::{Context, ContextLock};
+use super::rest::serve;
+use super::tls::CA_CERT;
+use crate::client::rest::{Error as RestError, VitupDisruptionRestClient, VitupRest};
+use crate::config::VitStartParameters;
+use std::net::{SocketAddr, TcpListener};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use thiserror::Error;
+use tokio::sync::oneshot;
+
+/// Mock running in current process on a separate thread with its own runtime, so it can
+/// be used from synchronous code. Port 0 in configuration binds mock to ephemeral port.
+/// Many mocks can run at the same time as long as they use different working dirs.
+/// Mock is stopped on [`MockController::shutdown`] or when controller is dropped
+pub struct MockController {
+    address: SocketAddr,
+    context: ContextLock,
+    shutdown: Option<oneshot::Sender<()>>,
+    server: Option<JoinHandle<()>>,
+}
+
+impl MockController {
+    pub fn start(
+        configuration: Configuration,
+        parameters: VitStartParameters,
+    ) -> Result<Self, Error> {
+        let context = Arc::new(Mutex::new(Context::new(configuration, Some(parameters))?));
+        let listener = TcpListener::bind(*context.lock().unwrap().address())?;
+        let address = listener.local_addr()?;
+        let (shutdown_sender, shutdown_receiver) = oneshot::channel::<()>();
+
+        let server_context = context.clone();
+        let runtime = tokio::runtime::Runtime::new()?;
+        let server = std::thread::spawn(move || {
+            runtime.block_on(serve(server_context, listener, async {
+                let _ = shutdown_receiver.await;
+            }));
+        });
+
+        Ok(Self {
+            address,
+            context,
+            shutdown: Some(shutdown_sender),
+            server: Some(server),
+        })
+    }
+
+    pub fn address(&self) -> SocketAddr {
+        self.address
+    }
+
+    /// Base url of mock rest api, e.g. `http://localhost:8080`
+    pub fn base_url(&self) -> String {
+        let scheme = match self.context.lock().unwrap().certificates() {
+            Some(_) => "https",
+            None => "http",
+        };
+        format!("{}://localhost:{}", scheme, self.address.port())
+    }
+
+    pub fn context(&self) -> ContextLock {
+        self.context.clone()
+    }
+
+    /// Client for control api. When mock generated its own certificates,
+    /// client trusts generated CA
+    pub fn client(&self) -> Result<VitupDisruptionRestClient, Error> {
+        let base_url = self.base_url();
+        let context = self.context.lock().unwrap();
+        let mut rest = match context.api_token() {
+            Some(token) => VitupRest::new_with_token(token, base_url),
+            None => VitupRest::new(base_url),
+        };
+        if let Some(certificates) = context.certificates() {
+            if certificates.is_generated() {
+                rest = rest.with_ca_certificate(context.tls_dir().join(CA_CERT))?;
+            }
+        }
+        Ok(rest.into())
+    }
+
+    /// Stops accepting connections and waits until server and block production are stopped
+    pub fn shutdown(mut self) {
+        self.stop();
+    }
+
+    fn stop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+        if let Some(server) = self.server.take() {
+            let _ = server.join();
+        }
+    }
+}
+
+impl Drop for MockController {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("cannot bind mock address")]
+    IoError(#[from] std::io::Error),
+    #[error("cannot create client")]
+    RestError(#[from] RestError),
+    #[error("cannot create mock context")]
+    ContextError(#[from] super::context::Error),
+}
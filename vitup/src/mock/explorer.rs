@@ -0,0 +1,438 @@
+use super::context::ContextLock;
+use super::ledger_state::{LedgerState, MockBlock};
+use async_graphql::{
+    Context as GraphQLContext, EmptyMutation, EmptySubscription, Enum, FieldError, FieldResult,
+    Object, Schema, SimpleObject, Union,
+};
+use chain_addr::{Address as ChainAddress, AddressReadable, Discrimination, Kind};
+use chain_core::property::Fragment as _;
+use chain_impl_mockchain::block::BlockDate as ChainBlockDate;
+use chain_impl_mockchain::fragment::Fragment;
+use chain_impl_mockchain::key::Hash;
+use chain_impl_mockchain::transaction::{InputEnum, Transaction as ChainTransaction};
+use chain_impl_mockchain::vote::{
+    PayloadType as ChainPayloadType, PrivateTallyState, Tally, TallyResult,
+    VotePlanStatus as ChainVotePlanStatus, VoteProposalStatus as ChainVoteProposalStatus,
+};
+use jormungandr_lib::interfaces::BlockDate as LibBlockDate;
+
+/// Prefix of bech32 addresses returned by explorer
+const ADDRESS_PREFIX: &str = "ca";
+
+pub type ExplorerSchema = Schema<Query, EmptyMutation, EmptySubscription>;
+
+/// Subset of jormungandr explorer schema used by wallet backends. Names and types of
+/// served fields follow jormungandr, fields which mock cannot provide are not defined.
+/// Data is read from mock ledger, so only fragments which were put into mock blocks are visible
+pub fn schema(context: ContextLock) -> ExplorerSchema {
+    Schema::build(Query, EmptyMutation, EmptySubscription)
+        .data(context)
+        .finish()
+}
+
+fn with_ledger<T, F: FnOnce(&LedgerState) -> T>(ctx: &GraphQLContext<'_>, f: F) -> T {
+    let context = ctx.data_unchecked::<ContextLock>().lock().unwrap();
+    f(context.state().ledger())
+}
+
+fn not_found(entity: &str, id: &str) -> FieldError {
+    FieldError::from(format!("{} '{}' not found", entity, id))
+}
+
+pub struct Query;
+
+#[Object]
+impl Query {
+    async fn block(&self, ctx: &GraphQLContext<'_>, id: String) -> FieldResult<Block> {
+        with_ledger(ctx, |ledger| {
+            id.parse::<Hash>()
+                .ok()
+                .and_then(|hash| ledger.block(&hash))
+                .cloned()
+                .map(Block)
+        })
+        .ok_or_else(|| not_found("block", &id))
+    }
+
+    async fn blocks_by_chain_length(
+        &self,
+        ctx: &GraphQLContext<'_>,
+        length: String,
+    ) -> FieldResult<Vec<Block>> {
+        let length: u32 = length.parse()?;
+        Ok(with_ledger(ctx, |ledger| {
+            ledger
+                .block_with_chain_length(length)
+                .cloned()
+                .map(Block)
+                .into_iter()
+                .collect()
+        }))
+    }
+
+    /// Blocks of given epoch. Together with `Block.date` it allows to look up
+    /// blocks by date, since mock mints block on every slot
+    async fn epoch(&self, ctx: &GraphQLContext<'_>, id: String) -> FieldResult<Epoch> {
+        let epoch: u32 = id.parse()?;
+        let tip_epoch = with_ledger(ctx, |ledger| {
+            ChainBlockDate::from(ledger.tip().date()).epoch
+        });
+        if epoch > tip_epoch {
+            return Err(not_found("epoch", &id));
+        }
+        Ok(Epoch(epoch))
+    }
+
+    async fn tip(&self, ctx: &GraphQLContext<'_>) -> Branch {
+        Branch(with_ledger(ctx, |ledger| ledger.tip().clone()))
+    }
+
+    async fn transaction(&self, ctx: &GraphQLContext<'_>, id: String) -> FieldResult<Transaction> {
+        with_ledger(ctx, |ledger| {
+            ledger
+                .received_fragments()
+                .into_iter()
+                .find(|fragment| fragment.id().to_string() == id)
+                .and_then(|fragment| {
+                    let block = ledger.block_containing(&fragment.id())?.clone();
+                    let discrimination = ledger
+                        .block0_configuration()
+                        .blockchain_configuration
+                        .discrimination;
+                    Some(Transaction {
+                        fragment,
+                        block,
+                        discrimination,
+                    })
+                })
+        })
+        .ok_or_else(|| not_found("transaction", &id))
+    }
+
+    async fn vote_plan(&self, ctx: &GraphQLContext<'_>, id: String) -> FieldResult<VotePlanStatus> {
+        with_ledger(ctx, |ledger| {
+            ledger
+                .active_vote_plans()
+                .iter()
+                .find(|vote_plan| vote_plan.id.to_string() == id)
+                .map(VotePlanStatus::from)
+        })
+        .ok_or_else(|| not_found("vote plan", &id))
+    }
+}
+
+/// Mock has a single branch, which ends at the tip
+pub struct Branch(MockBlock);
+
+#[Object]
+impl Branch {
+    async fn id(&self) -> String {
+        self.0.id().to_string()
+    }
+
+    async fn block(&self) -> Block {
+        Block(self.0.clone())
+    }
+}
+
+pub struct Block(MockBlock);
+
+#[Object]
+impl Block {
+    async fn id(&self) -> String {
+        self.0.id().to_string()
+    }
+
+    async fn date(&self) -> BlockDate {
+        self.0.date().into()
+    }
+
+    async fn chain_length(&self) -> String {
+        self.0.chain_length().to_string()
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct BlockDate {
+    epoch: Epoch,
+    slot: String,
+}
+
+/// Epoch which already started. Its blocks are minted on every slot up to the tip
+pub struct Epoch(u32);
+
+impl Epoch {
+    fn date(&self, slot: u32) -> ChainBlockDate {
+        ChainBlockDate {
+            epoch: self.0,
+            slot_id: slot,
+        }
+    }
+}
+
+#[Object]
+impl Epoch {
+    async fn id(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Not defined when block was already forgotten, see mock blocks history
+    async fn first_block(&self, ctx: &GraphQLContext<'_>) -> Option<Block> {
+        with_ledger(ctx, |ledger| {
+            ledger.block_at(&self.date(0).into()).cloned().map(Block)
+        })
+    }
+
+    async fn last_block(&self, ctx: &GraphQLContext<'_>) -> Option<Block> {
+        with_ledger(ctx, |ledger| {
+            let tip = ledger.tip();
+            if ChainBlockDate::from(tip.date()).epoch == self.0 {
+                return Some(Block(tip.clone()));
+            }
+            let last_slot = ledger.slots_per_epoch() - 1;
+            ledger
+                .block_at(&self.date(last_slot).into())
+                .cloned()
+                .map(Block)
+        })
+    }
+
+    async fn total_blocks(&self, ctx: &GraphQLContext<'_>) -> i32 {
+        with_ledger(ctx, |ledger| {
+            let tip = ChainBlockDate::from(ledger.tip().date());
+            if tip.epoch == self.0 {
+                tip.slot_id as i32 + 1
+            } else {
+                ledger.slots_per_epoch() as i32
+            }
+        })
+    }
+}
+
+impl From<ChainBlockDate> for BlockDate {
+    fn from(date: ChainBlockDate) -> Self {
+        Self {
+            epoch: Epoch(date.epoch),
+            slot: date.slot_id.to_string(),
+        }
+    }
+}
+
+impl From<LibBlockDate> for BlockDate {
+    fn from(date: LibBlockDate) -> Self {
+        ChainBlockDate::from(date).into()
+    }
+}
+
+pub struct Transaction {
+    fragment: Fragment,
+    block: MockBlock,
+    discrimination: Discrimination,
+}
+
+#[Object]
+impl Transaction {
+    async fn id(&self) -> String {
+        self.fragment.id().to_string()
+    }
+
+    async fn blocks(&self) -> Vec<Block> {
+        vec![Block(self.block.clone())]
+    }
+
+    async fn inputs(&self) -> Vec<TransactionInput> {
+        let discrimination = self.discrimination;
+        match &self.fragment {
+            Fragment::Transaction(tx) => inputs(tx, discrimination),
+            Fragment::VotePlan(tx) => inputs(tx, discrimination),
+            Fragment::VoteCast(tx) => inputs(tx, discrimination),
+            Fragment::VoteTally(tx) => inputs(tx, discrimination),
+            Fragment::EncryptedVoteTally(tx) => inputs(tx, discrimination),
+            _ => Vec::new(),
+        }
+    }
+
+    async fn outputs(&self) -> Vec<TransactionOutput> {
+        match &self.fragment {
+            Fragment::Transaction(tx) => outputs(tx),
+            Fragment::VotePlan(tx) => outputs(tx),
+            Fragment::VoteCast(tx) => outputs(tx),
+            Fragment::VoteTally(tx) => outputs(tx),
+            Fragment::EncryptedVoteTally(tx) => outputs(tx),
+            _ => Vec::new(),
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct TransactionInput {
+    amount: String,
+    address: Address,
+}
+
+#[derive(SimpleObject)]
+pub struct TransactionOutput {
+    amount: String,
+    address: Address,
+}
+
+#[derive(SimpleObject)]
+pub struct Address {
+    id: String,
+}
+
+/// Account inputs are rendered as addresses in given discrimination, the same way
+/// as outputs. Mock does not keep utxo, so utxo inputs are rendered as transaction id
+fn inputs<P>(tx: &ChainTransaction<P>, discrimination: Discrimination) -> Vec<TransactionInput> {
+    tx.as_slice()
+        .inputs()
+        .iter()
+        .map(|input| TransactionInput {
+            amount: input.value().0.to_string(),
+            address: Address {
+                id: match input.to_enum() {
+                    InputEnum::AccountInput(account, _) => {
+                        let kind = match account.to_single_account() {
+                            Some(id) => Kind::Account(id.into()),
+                            None => {
+                                let mut multisig = [0u8; 32];
+                                multisig.copy_from_slice(account.as_ref());
+                                Kind::Multisig(multisig)
+                            }
+                        };
+                        let address = ChainAddress(discrimination, kind);
+                        AddressReadable::from_address(ADDRESS_PREFIX, &address).to_string()
+                    }
+                    InputEnum::UtxoInput(pointer) => pointer.transaction_id.to_string(),
+                },
+            },
+        })
+        .collect()
+}
+
+fn outputs<P>(tx: &ChainTransaction<P>) -> Vec<TransactionOutput> {
+    tx.as_slice()
+        .outputs()
+        .iter()
+        .map(|output| TransactionOutput {
+            amount: output.value.0.to_string(),
+            address: Address {
+                id: AddressReadable::from_address(ADDRESS_PREFIX, &output.address).to_string(),
+            },
+        })
+        .collect()
+}
+
+#[derive(SimpleObject)]
+pub struct VotePlanStatus {
+    id: String,
+    vote_start: BlockDate,
+    vote_end: BlockDate,
+    committee_end: BlockDate,
+    payload_type: PayloadType,
+    proposals: Vec<VoteProposalStatus>,
+}
+
+impl From<&ChainVotePlanStatus> for VotePlanStatus {
+    fn from(status: &ChainVotePlanStatus) -> Self {
+        Self {
+            id: status.id.to_string(),
+            vote_start: status.vote_start.into(),
+            vote_end: status.vote_end.into(),
+            committee_end: status.committee_end.into(),
+            payload_type: status.payload.into(),
+            proposals: status
+                .proposals
+                .iter()
+                .map(VoteProposalStatus::from)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum PayloadType {
+    Public,
+    Private,
+}
+
+impl From<ChainPayloadType> for PayloadType {
+    fn from(payload_type: ChainPayloadType) -> Self {
+        match payload_type {
+            ChainPayloadType::Public => Self::Public,
+            ChainPayloadType::Private => Self::Private,
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct VoteProposalStatus {
+    proposal_id: String,
+    options: VoteOptionRange,
+    tally: Option<TallyStatus>,
+}
+
+impl From<&ChainVoteProposalStatus> for VoteProposalStatus {
+    fn from(status: &ChainVoteProposalStatus) -> Self {
+        let options = VoteOptionRange::from(&status.options.choice_range());
+        Self {
+            proposal_id: status.proposal_id.to_string(),
+            tally: status.tally.as_ref().map(|tally| match tally {
+                Tally::Public { result } => TallyStatus::Public(TallyPublicStatus {
+                    results: weights(result),
+                }),
+                Tally::Private { state } => TallyStatus::Private(TallyPrivateStatus {
+                    results: match state {
+                        PrivateTallyState::Encrypted { .. } => None,
+                        PrivateTallyState::Decrypted { result } => Some(weights(result)),
+                    },
+                    options: options.clone(),
+                }),
+            }),
+            options,
+        }
+    }
+}
+
+#[derive(SimpleObject, Clone)]
+pub struct VoteOptionRange {
+    /// first option (inclusive)
+    start: i32,
+    /// last option (exclusive)
+    end: i32,
+}
+
+impl From<&std::ops::Range<u8>> for VoteOptionRange {
+    fn from(range: &std::ops::Range<u8>) -> Self {
+        Self {
+            start: range.start as i32,
+            end: range.end as i32,
+        }
+    }
+}
+
+/// Tally results are defined only after public tally or decrypted private tally
+#[derive(Union)]
+pub enum TallyStatus {
+    Public(TallyPublicStatus),
+    Private(TallyPrivateStatus),
+}
+
+#[derive(SimpleObject)]
+pub struct TallyPublicStatus {
+    results: Vec<String>,
+}
+
+#[derive(SimpleObject)]
+pub struct TallyPrivateStatus {
+    results: Option<Vec<String>>,
+    options: VoteOptionRange,
+}
+
+fn weights(result: &TallyResult) -> Vec<String> {
+    result
+        .results()
+        .iter()
+        .map(|weight| u64::from(*weight).to_string())
+        .collect()
+}
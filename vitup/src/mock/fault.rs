@@ -0,0 +1,202 @@
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use thiserror::Error;
+
+/// Rule which injects fault into matching requests. Path can contain `*` which matches
+/// any single segment. `/api` prefix is optional. Account and proposal filters match
+/// corresponding path parameter (e.g. `/v0/account/{id}` or `/v0/proposals/{id}`), so they
+/// are rejected for paths without parameter, like `/v0/message` which carries account in body
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct FaultRule {
+    pub path: String,
+    #[serde(default)]
+    pub method: Option<String>,
+    #[serde(default)]
+    pub account: Option<String>,
+    #[serde(default)]
+    pub proposal: Option<i32>,
+    pub fault: Fault,
+    /// probability in range [0.0, 1.0] that matching request is affected.
+    /// If not defined, every matching request is affected
+    #[serde(default)]
+    pub probability: Option<f64>,
+    /// how many times rule can be triggered. If not defined, rule never expires
+    #[serde(default)]
+    pub count: Option<u32>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Fault {
+    /// responds with given status code instead of calling endpoint
+    StatusCode(u16),
+    /// delays request by given number of milliseconds and then passes it to endpoint
+    Latency(u64),
+    /// holds request for given number of seconds (or forever) and then responds with 504
+    Timeout(Option<u64>),
+    /// responds with 200 and body which cannot be parsed
+    MalformedBody,
+    /// responds with 429 and optional `Retry-After` header in seconds
+    TooManyRequests(Option<u64>),
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct FaultRuleEntry {
+    pub id: u32,
+    pub rule: FaultRule,
+    pub triggered: u32,
+}
+
+/// Outcome of matching request against fault rules
+#[derive(Debug, Clone)]
+pub enum InjectedFault {
+    Delay(Duration),
+    Respond(Fault),
+}
+
+#[derive(Default)]
+pub struct FaultInjector {
+    rules: Vec<FaultRuleEntry>,
+    next_id: u32,
+}
+
+impl FaultInjector {
+    pub fn add(&mut self, rule: FaultRule) -> Result<u32, Error> {
+        rule.validate()?;
+        let id = self.next_id;
+        self.next_id += 1;
+        self.rules.push(FaultRuleEntry {
+            id,
+            rule,
+            triggered: 0,
+        });
+        Ok(id)
+    }
+
+    pub fn remove(&mut self, id: u32) -> bool {
+        let len = self.rules.len();
+        self.rules.retain(|entry| entry.id != id);
+        len != self.rules.len()
+    }
+
+    pub fn clear(&mut self) {
+        self.rules.clear();
+    }
+
+    pub fn rules(&self) -> Vec<FaultRuleEntry> {
+        self.rules.clone()
+    }
+
+    /// Returns fault for first matching and not expired rule which was triggered
+    /// according to its probability
+    pub fn check(&mut self, method: &str, path: &str) -> Option<(u32, InjectedFault)> {
+        for entry in self.rules.iter_mut() {
+            if !entry.rule.matches(method, path) {
+                continue;
+            }
+            if let Some(count) = entry.rule.count {
+                if entry.triggered >= count {
+                    continue;
+                }
+            }
+            if let Some(probability) = entry.rule.probability {
+                if rand::random::<f64>() >= probability {
+                    continue;
+                }
+            }
+            entry.triggered += 1;
+            let fault = match &entry.rule.fault {
+                Fault::Latency(millis) => InjectedFault::Delay(Duration::from_millis(*millis)),
+                fault => InjectedFault::Respond(fault.clone()),
+            };
+            return Some((entry.id, fault));
+        }
+        None
+    }
+}
+
+impl FaultRule {
+    pub fn validate(&self) -> Result<(), Error> {
+        if let Some(probability) = self.probability {
+            if !(0.0..=1.0).contains(&probability) {
+                return Err(Error::InvalidProbability(probability));
+            }
+        }
+
+        let rule_segments = segments(&self.path);
+        let can_match = |value: &str| {
+            rule_segments
+                .iter()
+                .any(|segment| *segment == "*" || *segment == value)
+        };
+        if let Some(account) = &self.account {
+            if !can_match(account) {
+                return Err(Error::FilterWithoutPathParameter {
+                    filter: "account".to_string(),
+                    path: self.path.clone(),
+                });
+            }
+        }
+        if let Some(proposal) = &self.proposal {
+            if !can_match(&proposal.to_string()) {
+                return Err(Error::FilterWithoutPathParameter {
+                    filter: "proposal".to_string(),
+                    path: self.path.clone(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn matches(&self, method: &str, path: &str) -> bool {
+        if let Some(rule_method) = &self.method {
+            if !rule_method.eq_ignore_ascii_case(method) {
+                return false;
+            }
+        }
+
+        let rule_segments = segments(&self.path);
+        let segments = segments(path);
+
+        if segments.len() != rule_segments.len()
+            || !rule_segments
+                .iter()
+                .zip(segments.iter())
+                .all(|(rule, segment)| *rule == "*" || rule == segment)
+        {
+            return false;
+        }
+
+        if let Some(account) = &self.account {
+            if !segments.iter().any(|segment| *segment == account.as_str()) {
+                return false;
+            }
+        }
+
+        if let Some(proposal) = &self.proposal {
+            if !segments
+                .iter()
+                .any(|segment| *segment == proposal.to_string())
+            {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+fn segments(path: &str) -> Vec<&str> {
+    let segments: Vec<&str> = path.split('/').filter(|x| !x.is_empty()).collect();
+    match segments.first() {
+        Some(&"api") => segments[1..].to_vec(),
+        _ => segments,
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("fault probability ({0}) should be in range [0.0, 1.0]")]
+    InvalidProbability(f64),
+    #[error("{filter} filter can only match path parameter, path '{path}' has none")]
+    FilterWithoutPathParameter { filter: String, path: String },
+}
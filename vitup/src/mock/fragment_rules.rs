@@ -0,0 +1,187 @@
+use super::ledger_state::FragmentRecieveStrategy;
+use chain_impl_mockchain::fragment::Fragment;
+use chain_impl_mockchain::transaction::{InputEnum, Transaction};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FragmentType {
+    Transaction,
+    VotePlan,
+    VoteCast,
+    VoteTally,
+    EncryptedVoteTally,
+    Other,
+}
+
+/// Rule which decides fate of received fragment based on its content. All defined filters
+/// need to match. When `every_nth` is defined, rule is applied only to every n-th fragment
+/// which passed other filters
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct FragmentRule {
+    #[serde(default)]
+    pub fragment_type: Option<FragmentType>,
+    /// account id (hex) of one of transaction inputs
+    #[serde(default)]
+    pub sender: Option<String>,
+    #[serde(default)]
+    pub vote_plan: Option<String>,
+    #[serde(default)]
+    pub proposal_index: Option<u8>,
+    #[serde(default)]
+    pub every_nth: Option<u32>,
+    pub strategy: FragmentRecieveStrategy,
+    /// custom rejection reason
+    #[serde(default)]
+    pub reason: Option<String>,
+    /// number of slots fragment stays pending before strategy is applied
+    #[serde(default)]
+    pub delay_slots: u32,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct FragmentRuleEntry {
+    pub id: u32,
+    pub rule: FragmentRule,
+    pub matched: u32,
+}
+
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+pub struct FragmentRules {
+    rules: Vec<FragmentRuleEntry>,
+    next_id: u32,
+}
+
+impl FragmentRules {
+    pub fn add(&mut self, rule: FragmentRule) -> u32 {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.rules.push(FragmentRuleEntry {
+            id,
+            rule,
+            matched: 0,
+        });
+        id
+    }
+
+    pub fn remove(&mut self, id: u32) -> bool {
+        let len = self.rules.len();
+        self.rules.retain(|entry| entry.id != id);
+        len != self.rules.len()
+    }
+
+    pub fn clear(&mut self) {
+        self.rules.clear();
+    }
+
+    pub fn rules(&self) -> Vec<FragmentRuleEntry> {
+        self.rules.clone()
+    }
+
+    /// Returns first rule which applies to given fragment
+    pub fn find(&mut self, fragment: &Fragment) -> Option<FragmentRule> {
+        let details = FragmentDetails::from(fragment);
+
+        for entry in self.rules.iter_mut() {
+            if !entry.rule.matches(&details) {
+                continue;
+            }
+            entry.matched += 1;
+            match entry.rule.every_nth {
+                Some(n) if n > 1 && entry.matched % n != 0 => continue,
+                _ => return Some(entry.rule.clone()),
+            }
+        }
+        None
+    }
+}
+
+impl FragmentRule {
+    fn matches(&self, details: &FragmentDetails) -> bool {
+        if let Some(fragment_type) = &self.fragment_type {
+            if *fragment_type != details.fragment_type {
+                return false;
+            }
+        }
+        if let Some(sender) = &self.sender {
+            if !details
+                .senders
+                .iter()
+                .any(|x| x.eq_ignore_ascii_case(sender))
+            {
+                return false;
+            }
+        }
+        if let Some(vote_plan) = &self.vote_plan {
+            if details.vote_plan.as_ref() != Some(vote_plan) {
+                return false;
+            }
+        }
+        if let Some(proposal_index) = &self.proposal_index {
+            if details.proposal_index.as_ref() != Some(proposal_index) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+struct FragmentDetails {
+    fragment_type: FragmentType,
+    senders: Vec<String>,
+    vote_plan: Option<String>,
+    proposal_index: Option<u8>,
+}
+
+impl From<&Fragment> for FragmentDetails {
+    fn from(fragment: &Fragment) -> Self {
+        match fragment {
+            Fragment::Transaction(tx) => Self::new(FragmentType::Transaction, tx),
+            Fragment::VotePlan(tx) => Self {
+                vote_plan: Some(tx.as_slice().payload().into_payload().to_id().to_string()),
+                ..Self::new(FragmentType::VotePlan, tx)
+            },
+            Fragment::VoteCast(tx) => {
+                let vote_cast = tx.as_slice().payload().into_payload();
+                Self {
+                    vote_plan: Some(vote_cast.vote_plan().to_string()),
+                    proposal_index: Some(vote_cast.proposal_index()),
+                    ..Self::new(FragmentType::VoteCast, tx)
+                }
+            }
+            Fragment::VoteTally(tx) => Self {
+                vote_plan: Some(tx.as_slice().payload().into_payload().id().to_string()),
+                ..Self::new(FragmentType::VoteTally, tx)
+            },
+            Fragment::EncryptedVoteTally(tx) => Self {
+                vote_plan: Some(tx.as_slice().payload().into_payload().id().to_string()),
+                ..Self::new(FragmentType::EncryptedVoteTally, tx)
+            },
+            _ => Self {
+                fragment_type: FragmentType::Other,
+                senders: Vec::new(),
+                vote_plan: None,
+                proposal_index: None,
+            },
+        }
+    }
+}
+
+impl FragmentDetails {
+    fn new<P>(fragment_type: FragmentType, tx: &Transaction<P>) -> Self {
+        Self {
+            fragment_type,
+            senders: tx
+                .as_slice()
+                .inputs()
+                .iter()
+                .filter_map(|input| match input.to_enum() {
+                    InputEnum::AccountInput(account, _) => Some(hex::encode(account.as_ref())),
+                    InputEnum::UtxoInput(_) => None,
+                })
+                .collect(),
+            vote_plan: None,
+            proposal_index: None,
+        }
+    }
+}
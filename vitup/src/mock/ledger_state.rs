@@ -1,22 +1,34 @@
+use super::clock::{Error as ClockError, MockClock, TimeStatus};
+use super::fragment_rules::FragmentRules;
 use chain_addr::Discrimination;
 use chain_core::property::Block;
+use chain_core::property::Deserialize as _;
 use chain_core::property::Fragment as _;
+use chain_core::property::Serialize as _;
+use chain_impl_mockchain::account::{AccountState, Identifier};
+use chain_impl_mockchain::certificate::VotePlan;
+use chain_impl_mockchain::fee::LinearFee;
 use chain_impl_mockchain::fragment::Fragment;
 use chain_impl_mockchain::fragment::FragmentId;
-use chain_impl_mockchain::ledger::{Error as LedgerError, Ledger};
-use chain_impl_mockchain::testing::TestGen;
-use chain_impl_mockchain::vote::VotePlanStatus;
+use chain_impl_mockchain::key::Hash;
+use chain_impl_mockchain::ledger::Ledger;
+use chain_impl_mockchain::transaction::{InputEnum, UnspecifiedAccountIdentifier};
+use chain_impl_mockchain::value::Value;
+use chain_impl_mockchain::vote::{Payload, VotePlanStatus};
 use chain_time::TimeEra;
-use jormungandr_lib::interfaces::Block0Configuration;
+use iapyx::AccountVote;
+use jormungandr_lib::interfaces::{Block0Configuration, CommitteeIdDef};
 use jormungandr_lib::interfaces::{BlockDate, SettingsDto};
 use jormungandr_lib::interfaces::{FragmentLog, FragmentOrigin, FragmentStatus};
 use jormungandr_lib::time::SystemTime;
-use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
 use std::ops::Add;
 use std::path::PathBuf;
+use std::time::{Duration, Instant};
 use thiserror::Error;
 
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Debug, Deserialize, Serialize)]
 pub enum FragmentRecieveStrategy {
     Reject,
     Accept,
@@ -24,14 +36,80 @@ pub enum FragmentRecieveStrategy {
     None,
 }
 
+/// Number of most recent blocks kept in memory, empty ones included. Blocks with
+/// fragments are always kept, since they are needed for explorer and state dump
+const MAX_RECENT_BLOCKS: usize = 10_000;
+
+/// Block minted by the mock at slot boundary. It does not carry any real
+/// header, but its id is calculated from parent id, date and content,
+/// so it is stable for given chain of fragments
+#[derive(Debug, Clone)]
+pub struct MockBlock {
+    id: Hash,
+    date: BlockDate,
+    chain_length: u32,
+    fragments: Vec<FragmentId>,
+}
+
+impl MockBlock {
+    fn genesis(id: Hash) -> Self {
+        Self {
+            id,
+            date: BlockDate::new(0, 0),
+            chain_length: 0,
+            fragments: Vec::new(),
+        }
+    }
+
+    fn mint(parent: &MockBlock, date: BlockDate, fragments: Vec<FragmentId>) -> Self {
+        let chain_date: chain_impl_mockchain::block::BlockDate = date.into();
+        let mut content = parent.id.as_ref().to_vec();
+        content.extend(&chain_date.epoch.to_be_bytes());
+        content.extend(&chain_date.slot_id.to_be_bytes());
+        for fragment in fragments.iter() {
+            content.extend(fragment.as_ref());
+        }
+
+        Self {
+            id: Hash::hash_bytes(&content),
+            date,
+            chain_length: parent.chain_length + 1,
+            fragments,
+        }
+    }
+
+    pub fn id(&self) -> Hash {
+        self.id
+    }
+
+    pub fn date(&self) -> BlockDate {
+        self.date
+    }
+
+    pub fn chain_length(&self) -> u32 {
+        self.chain_length
+    }
+
+    pub fn fragments(&self) -> &[FragmentId] {
+        &self.fragments
+    }
+}
+
 pub struct LedgerState {
     fragment_strategy: FragmentRecieveStrategy,
     fragment_logs: Vec<FragmentLog>,
     received_fragments: Vec<Fragment>,
+    mempool: Vec<(Fragment, FragmentRecieveStrategy)>,
+    fragment_rules: FragmentRules,
+    delayed: Vec<DelayedFragment>,
+    account_overrides: HashMap<String, AccountOverride>,
+    blocks: Vec<MockBlock>,
+    recent_blocks: VecDeque<MockBlock>,
     ledger: Ledger,
-    start_time: SystemTime,
+    clock: MockClock,
     block0_configuration: Block0Configuration,
     block0_bin: Vec<u8>,
+    started: Instant,
 }
 
 impl LedgerState {
@@ -45,27 +123,368 @@ impl LedgerState {
             fragment_strategy: FragmentRecieveStrategy::None,
             fragment_logs: Vec::new(),
             received_fragments: Vec::new(),
+            mempool: Vec::new(),
+            fragment_rules: FragmentRules::default(),
+            delayed: Vec::new(),
+            account_overrides: HashMap::new(),
+            blocks: vec![MockBlock::genesis(block.id())],
+            recent_blocks: vec![MockBlock::genesis(block.id())].into(),
+            clock: MockClock::new(),
             block0_configuration,
-            start_time: SystemTime::now(),
             block0_bin: jortestkit::file::get_file_as_byte_vec(&block0_path),
             ledger: Ledger::new(block.id(), block.fragments())?,
+            started: Instant::now(),
         })
     }
 
+    /// Recreates ledger state from dump. Ledger is rebuilt by replaying
+    /// received fragments on block0 in the same blocks they were originally minted in,
+    /// so blocks ids are the same as before dump
+    pub fn restore(
+        block0_configuration: Block0Configuration,
+        block0_path: PathBuf,
+        dump: LedgerStateDump,
+    ) -> Result<Self, Error> {
+        let mut ledger_state = Self::new(block0_configuration, block0_path)?;
+
+        let received_fragments = dump
+            .fragments
+            .iter()
+            .map(|fragment| {
+                let bytes = hex::decode(fragment)?;
+                Fragment::deserialize(bytes.as_slice())
+                    .map_err(|e| Error::FragmentDecodeError(e.to_string()))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        let find_fragment = |id: &str| {
+            received_fragments
+                .iter()
+                .find(|fragment| fragment.id().to_string() == id)
+                .cloned()
+                .ok_or_else(|| Error::FragmentNotFound(id.to_string()))
+        };
+
+        let mut blocks = dump.blocks.clone();
+        blocks.sort_by_key(|block| ledger_state.slot_index_of(&block.date));
+        for block in blocks {
+            let slot = ledger_state.slot_index_of(&block.date);
+            ledger_state.mint_blocks_until(slot.saturating_sub(1));
+            for id in block.fragments.iter() {
+                ledger_state
+                    .mempool
+                    .push((find_fragment(id)?, FragmentRecieveStrategy::Accept));
+            }
+            ledger_state.mint_blocks_until(slot);
+        }
+        ledger_state.mint_blocks_until(ledger_state.slot_index_of(&dump.tip));
+
+        ledger_state.mempool = dump
+            .mempool
+            .iter()
+            .map(|(id, strategy)| Ok((find_fragment(id)?, *strategy)))
+            .collect::<Result<Vec<_>, Error>>()?;
+        ledger_state.delayed = dump
+            .delayed
+            .into_iter()
+            .map(|delayed| {
+                Ok(DelayedFragment {
+                    fragment: find_fragment(&delayed.id)?,
+                    strategy: delayed.strategy,
+                    reason: delayed.reason,
+                    due_slot: delayed.due_slot,
+                })
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        ledger_state.fragment_rules = dump.fragment_rules;
+        ledger_state.account_overrides = dump.account_overrides;
+        ledger_state.received_fragments = received_fragments;
+        ledger_state.fragment_logs = dump.fragment_logs;
+        ledger_state.fragment_strategy = dump.fragment_strategy;
+        ledger_state.clock = MockClock::starting_at(*dump.time.as_ref(), dump.frozen);
+        Ok(ledger_state)
+    }
+
+    pub fn dump(&self) -> LedgerStateDump {
+        LedgerStateDump {
+            fragment_strategy: self.fragment_strategy,
+            fragments: self
+                .received_fragments
+                .iter()
+                .map(|fragment| hex::encode(fragment.serialize_as_vec().unwrap()))
+                .collect(),
+            fragment_logs: self.fragment_logs.clone(),
+            mempool: self
+                .mempool
+                .iter()
+                .map(|(fragment, strategy)| (fragment.id().to_string(), *strategy))
+                .collect(),
+            fragment_rules: self.fragment_rules.clone(),
+            delayed: self
+                .delayed
+                .iter()
+                .map(|delayed| DelayedFragmentDump {
+                    id: delayed.fragment.id().to_string(),
+                    strategy: delayed.strategy,
+                    reason: delayed.reason.clone(),
+                    due_slot: delayed.due_slot,
+                })
+                .collect(),
+            account_overrides: self.account_overrides.clone(),
+            blocks: self
+                .blocks
+                .iter()
+                .filter(|block| !block.fragments().is_empty())
+                .map(BlockDump::from)
+                .collect(),
+            tip: self.tip().date(),
+            time: self.clock.now().into(),
+            frozen: self.clock.is_frozen(),
+        }
+    }
+
+    pub fn block0_configuration(&self) -> &Block0Configuration {
+        &self.block0_configuration
+    }
+
+    /// Vote plans certificates included in block0
+    pub fn block0_vote_plans(&self) -> Vec<VotePlan> {
+        self.block0_configuration
+            .to_block()
+            .contents
+            .iter()
+            .filter_map(|fragment| match fragment {
+                Fragment::VotePlan(tx) => Some(tx.as_slice().payload().into_payload()),
+                _ => None,
+            })
+            .collect()
+    }
+
     pub fn message(&mut self, fragment: Fragment) -> FragmentId {
+        self.produce_blocks();
+
         self.received_fragments.push(fragment.clone());
         let fragment_id = fragment.id();
-        let parameters = self.ledger.get_ledger_parameters();
-        let date = self.curr_block_date();
-        let result = self
-            .ledger
-            .apply_fragment(&parameters, &fragment, date.into());
-        let mut fragment_log = FragmentLog::new(fragment.id(), FragmentOrigin::Rest);
-        self.set_fragment_status(&mut fragment_log, self.fragment_strategy, result);
-        self.fragment_logs.push(fragment_log);
+        self.fragment_logs
+            .push(FragmentLog::new(fragment.id(), FragmentOrigin::Rest));
+
+        let (strategy, reason, delay_slots) = match self.fragment_rules.find(&fragment) {
+            Some(rule) => (rule.strategy, rule.reason, rule.delay_slots),
+            None => (self.fragment_strategy, None, 0),
+        };
+
+        if delay_slots > 0 {
+            let due_slot = self.slot_index_of(&self.tip().date()) + delay_slots as u64;
+            self.delayed.push(DelayedFragment {
+                fragment,
+                strategy,
+                reason,
+                due_slot,
+            });
+        } else {
+            self.resolve(fragment, strategy, reason);
+        }
+        fragment_id
+    }
+
+    /// Submits fragments in the same way as jormungandr v1 api. Fragments which are already
+    /// in fragment logs, fragments which cannot be sent by clients and fragments rejected
+    /// immediately by fragment rules or strategy are reported as rejected. With `fail_fast`
+    /// fragments after the first rejected one are not processed at all
+    pub fn submit(&mut self, batch: FragmentsBatch) -> FragmentsProcessingSummary {
+        let mut summary = FragmentsProcessingSummary::default();
+
+        for message in batch.fragments {
+            let fragment = match decode_fragment(&message) {
+                Ok(fragment) => fragment,
+                Err(id) => {
+                    let reason = if batch.fail_fast && !summary.rejected.is_empty() {
+                        FragmentRejectionReason::PreviousFragmentInvalid
+                    } else {
+                        FragmentRejectionReason::FragmentInvalid
+                    };
+                    summary.rejected.push(RejectedFragmentInfo {
+                        id: id.to_string(),
+                        pool_number: 0,
+                        reason,
+                    });
+                    continue;
+                }
+            };
+            let id = fragment.id();
+            let reason = if batch.fail_fast && !summary.rejected.is_empty() {
+                Some(FragmentRejectionReason::PreviousFragmentInvalid)
+            } else if self.fragment_log(&id).is_some() {
+                Some(FragmentRejectionReason::FragmentAlreadyInLog)
+            } else if !is_fragment_valid(&fragment) {
+                Some(FragmentRejectionReason::FragmentInvalid)
+            } else {
+                self.message(fragment);
+                match self.fragment_log(&id).map(|log| log.status()) {
+                    Some(FragmentStatus::Rejected { .. }) => {
+                        Some(FragmentRejectionReason::FragmentInvalid)
+                    }
+                    _ => None,
+                }
+            };
+
+            match reason {
+                Some(reason) => summary.rejected.push(RejectedFragmentInfo {
+                    id: id.to_string(),
+                    pool_number: 0,
+                    reason,
+                }),
+                None => summary.accepted.push(id.to_string()),
+            }
+        }
+        summary
+    }
+
+    /// Puts fragment created by mock itself directly into mempool. Fragment strategy
+    /// and fragment rules are not applied, so it always ends up in the next block
+    /// unless ledger rejects it
+    pub fn inject(&mut self, fragment: Fragment) -> FragmentId {
+        self.produce_blocks();
+
+        self.received_fragments.push(fragment.clone());
+        let fragment_id = fragment.id();
+        self.fragment_logs
+            .push(FragmentLog::new(fragment.id(), FragmentOrigin::Rest));
+        self.resolve(fragment, FragmentRecieveStrategy::None, None);
         fragment_id
     }
 
+    /// Ledger as it will be after the next block is minted, that is with fragments waiting
+    /// in mempool applied, together with date of that block. Fragments which would be rejected
+    /// are skipped
+    pub fn pending_ledger(&self) -> (Ledger, BlockDate) {
+        let date = self.block_date_of(self.slot_index_of(&self.tip().date()) + 1);
+        let parameters = self.ledger.get_ledger_parameters();
+        let ledger = self
+            .mempool
+            .iter()
+            .fold(self.ledger.clone(), |ledger, (fragment, _)| {
+                ledger
+                    .apply_fragment(&parameters, fragment, date.into())
+                    .unwrap_or(ledger)
+            });
+        (ledger, date)
+    }
+
+    /// Puts fragment into mempool or sets its final status according to strategy
+    fn resolve(
+        &mut self,
+        fragment: Fragment,
+        strategy: FragmentRecieveStrategy,
+        reason: Option<String>,
+    ) {
+        match strategy {
+            FragmentRecieveStrategy::None | FragmentRecieveStrategy::Accept => {
+                self.mempool.push((fragment, strategy));
+            }
+            FragmentRecieveStrategy::Reject | FragmentRecieveStrategy::Pending => {
+                let (date, id) = (self.tip().date(), self.tip().id());
+                if let Some(fragment_log) = self.fragment_log_mut(&fragment.id()) {
+                    match (strategy, reason) {
+                        (FragmentRecieveStrategy::Reject, Some(reason)) => {
+                            fragment_log.modify(FragmentStatus::Rejected { reason })
+                        }
+                        _ => override_fragment_status(date, id, fragment_log, strategy),
+                    }
+                }
+            }
+        }
+    }
+
+    /// Mints all blocks which should have been created since last call.
+    /// Fragments waiting in mempool are applied in order of arrival and
+    /// put into first minted block, remaining slots get empty blocks
+    pub fn produce_blocks(&mut self) -> Vec<MockBlock> {
+        self.mint_blocks_until(self.curr_slot_index())
+    }
+
+    /// Mints block on every slot boundary after tip up to given slot
+    fn mint_blocks_until(&mut self, slot: u64) -> Vec<MockBlock> {
+        let mut minted = Vec::new();
+        while self.slot_index_of(&self.tip().date()) < slot {
+            let next_slot = self.slot_index_of(&self.tip().date()) + 1;
+            minted.push(self.mint_block(self.block_date_of(next_slot)));
+        }
+        minted
+    }
+
+    fn mint_block(&mut self, date: BlockDate) -> MockBlock {
+        let slot = self.slot_index_of(&date);
+        let (due, waiting): (Vec<_>, Vec<_>) = std::mem::take(&mut self.delayed)
+            .into_iter()
+            .partition(|delayed| delayed.due_slot <= slot);
+        self.delayed = waiting;
+        for delayed in due {
+            self.resolve(delayed.fragment, delayed.strategy, delayed.reason);
+        }
+
+        let parameters = self.ledger.get_ledger_parameters();
+        let mut applied = Vec::new();
+        let mut statuses = Vec::new();
+
+        for (fragment, strategy) in std::mem::take(&mut self.mempool) {
+            let result = self
+                .ledger
+                .apply_fragment(&parameters, &fragment, date.into());
+
+            match (result, strategy) {
+                (Ok(ledger), _) => {
+                    self.ledger = ledger;
+                    applied.push(fragment.id());
+                    statuses.push((fragment.id(), None));
+                }
+                (Err(_), FragmentRecieveStrategy::Accept) => {
+                    applied.push(fragment.id());
+                    statuses.push((fragment.id(), None));
+                }
+                (Err(error), _) => {
+                    statuses.push((fragment.id(), Some(format!("{:?}", error))));
+                }
+            }
+        }
+
+        let block = MockBlock::mint(self.tip(), date, applied);
+
+        for (id, rejection) in statuses {
+            let status = match rejection {
+                None => FragmentStatus::InABlock {
+                    date: block.date(),
+                    block: block.id().into(),
+                },
+                Some(reason) => FragmentStatus::Rejected { reason },
+            };
+            if let Some(fragment_log) = self.fragment_log_mut(&id) {
+                fragment_log.modify(status);
+            }
+        }
+        if !block.fragments().is_empty() {
+            self.blocks.push(block.clone());
+        }
+        self.recent_blocks.push_back(block.clone());
+        if self.recent_blocks.len() > MAX_RECENT_BLOCKS {
+            self.recent_blocks.pop_front();
+        }
+        block
+    }
+
+    pub fn fragment_log(&self, id: &FragmentId) -> Option<&FragmentLog> {
+        self.fragment_logs
+            .iter()
+            .rev()
+            .find(|x| (*x.fragment_id()).into_hash() == *id)
+    }
+
+    fn fragment_log_mut(&mut self, id: &FragmentId) -> Option<&mut FragmentLog> {
+        self.fragment_logs
+            .iter_mut()
+            .rev()
+            .find(|x| (*x.fragment_id()).into_hash() == *id)
+    }
+
     pub fn statuses(&self, ids: Vec<FragmentId>) -> HashMap<String, FragmentStatus> {
         self.fragment_logs
             .iter()
@@ -74,6 +493,49 @@ impl LedgerState {
             .collect()
     }
 
+    /// Votes cast by account, found among all received fragments in order of arrival,
+    /// together with current status of their fragments
+    pub fn account_votes(&self, id: &Identifier) -> Vec<AccountVote> {
+        let account = UnspecifiedAccountIdentifier::from_single_account(id.clone());
+
+        self.received_fragments
+            .iter()
+            .filter_map(|fragment| match fragment {
+                Fragment::VoteCast(tx) => Some((fragment.id(), tx)),
+                _ => None,
+            })
+            .filter(|(_, tx)| {
+                tx.as_slice()
+                    .inputs()
+                    .iter()
+                    .any(|input| match input.to_enum() {
+                        InputEnum::AccountInput(input_account, _) => input_account == account,
+                        InputEnum::UtxoInput(_) => false,
+                    })
+            })
+            .filter_map(|(fragment_id, tx)| {
+                let vote_cast = tx.as_slice().payload().into_payload();
+                let status = self
+                    .fragment_logs
+                    .iter()
+                    .rev()
+                    .find(|x| (*x.fragment_id()).into_hash() == fragment_id)?
+                    .status()
+                    .clone();
+                Some(AccountVote {
+                    vote_plan_id: vote_cast.vote_plan().to_string(),
+                    proposal_index: vote_cast.proposal_index(),
+                    choice: match vote_cast.payload() {
+                        Payload::Public { choice } => Some(choice.as_byte()),
+                        Payload::Private { .. } => None,
+                    },
+                    fragment_id: fragment_id.to_string(),
+                    status,
+                })
+            })
+            .collect()
+    }
+
     pub fn set_fragment_strategy(&mut self, fragment_strategy: FragmentRecieveStrategy) {
         self.fragment_strategy = fragment_strategy;
     }
@@ -82,6 +544,44 @@ impl LedgerState {
         self.ledger.accounts()
     }
 
+    /// Account state as reported by rest api, that is ledger state with account
+    /// overrides applied on top of it
+    pub fn account_state(&self, id: &Identifier) -> Option<AccountState<()>> {
+        let mut state = self.ledger.accounts().get_state(id).ok()?.clone();
+        if let Some(account_override) = self.account_overrides.get(&id.to_string()) {
+            state.value = Value(state.value.0.saturating_sub(account_override.drained));
+            state.counter = (u32::from(state.counter) + account_override.counter_shift).into();
+        }
+        Some(state)
+    }
+
+    /// Lowers reported balance of account by given value or to zero if value is not defined.
+    /// Funds are not moved on ledger, so only rest api responses are affected
+    pub fn drain_account(&mut self, id: &Identifier, value: Option<u64>) {
+        let reported = self
+            .account_state(id)
+            .map(|state| state.value.0)
+            .unwrap_or(0);
+        let drained = value.unwrap_or(reported).min(reported);
+        self.account_overrides
+            .entry(id.to_string())
+            .or_default()
+            .drained += drained;
+    }
+
+    /// Moves reported spending counter forward, so transactions signed by wallet
+    /// which trusts rest api are rejected by ledger as out of sync
+    pub fn bump_spending_counter(&mut self, id: &Identifier, count: u32) {
+        self.account_overrides
+            .entry(id.to_string())
+            .or_default()
+            .counter_shift += count;
+    }
+
+    pub fn reset_account(&mut self, id: &Identifier) {
+        self.account_overrides.remove(&id.to_string());
+    }
+
     pub fn active_vote_plans(&self) -> Vec<VotePlanStatus> {
         self.ledger.active_vote_plans()
     }
@@ -97,33 +597,22 @@ impl LedgerState {
     }
 
     pub fn set_status_for_recent_fragment(&mut self, fragment_strategy: FragmentRecieveStrategy) {
-        let block_date = self.curr_block_date();
+        let tip = self.tip().clone();
         let fragment_log = self.fragment_logs.last_mut().unwrap();
-        override_fragment_status(block_date, fragment_log, fragment_strategy);
+        let fragment_id = (*fragment_log.fragment_id()).into_hash();
+        override_fragment_status(tip.date(), tip.id(), fragment_log, fragment_strategy);
+        self.mempool
+            .retain(|(fragment, _)| fragment.id() != fragment_id);
+        self.delayed
+            .retain(|delayed| delayed.fragment.id() != fragment_id);
     }
 
-    pub fn set_fragment_status(
-        &mut self,
-        fragment_log: &mut FragmentLog,
-        fragment_strategy: FragmentRecieveStrategy,
-        result: Result<Ledger, LedgerError>,
-    ) {
-        if let FragmentRecieveStrategy::None = fragment_strategy {
-            match result {
-                Ok(ledger) => {
-                    self.ledger = ledger;
-                    fragment_log.modify(FragmentStatus::InABlock {
-                        date: self.curr_block_date(),
-                        block: TestGen::hash().into(),
-                    })
-                }
-                Err(error) => fragment_log.modify(FragmentStatus::Rejected {
-                    reason: format!("{:?}", error),
-                }),
-            };
-        } else {
-            override_fragment_status(self.curr_block_date(), fragment_log, fragment_strategy);
-        }
+    pub fn fragment_rules(&self) -> &FragmentRules {
+        &self.fragment_rules
+    }
+
+    pub fn fragment_rules_mut(&mut self) -> &mut FragmentRules {
+        &mut self.fragment_rules
     }
 
     pub fn fragment_logs(&self) -> Vec<FragmentLog> {
@@ -134,59 +623,211 @@ impl LedgerState {
         self.received_fragments.clone()
     }
 
-    pub fn curr_block_date(&self) -> BlockDate {
-        let curr_time = self.start_time;
+    pub fn block0_hash(&self) -> Hash {
+        self.blocks.first().unwrap().id()
+    }
+
+    pub fn fees(&self) -> LinearFee {
+        self.block0_configuration
+            .blockchain_configuration
+            .linear_fees
+    }
+
+    pub fn tip(&self) -> &MockBlock {
+        self.recent_blocks.back().unwrap()
+    }
+
+    pub fn mempool_size(&self) -> usize {
+        self.mempool.len()
+    }
+
+    /// Block with given id. Empty blocks are only found among recent blocks,
+    /// see [`MAX_RECENT_BLOCKS`]
+    pub fn block(&self, id: &Hash) -> Option<&MockBlock> {
+        self.stored_blocks().find(|block| block.id() == *id)
+    }
+
+    /// Block minted in given slot, see [`LedgerState::block`]
+    pub fn block_at(&self, date: &BlockDate) -> Option<&MockBlock> {
+        self.stored_blocks().find(|block| block.date() == *date)
+    }
+
+    /// Block with given chain length, see [`LedgerState::block`]
+    pub fn block_with_chain_length(&self, chain_length: u32) -> Option<&MockBlock> {
+        self.stored_blocks()
+            .find(|block| block.chain_length() == chain_length)
+    }
+
+    /// Recent blocks followed by older blocks with fragments, newest first
+    fn stored_blocks(&self) -> impl Iterator<Item = &MockBlock> {
+        self.recent_blocks
+            .iter()
+            .rev()
+            .chain(self.blocks.iter().rev())
+    }
+
+    /// Block in which fragment was applied. Fragments which are still pending
+    /// or were rejected are not part of any block
+    pub fn block_containing(&self, id: &FragmentId) -> Option<&MockBlock> {
+        self.blocks
+            .iter()
+            .find(|block| block.fragments().contains(id))
+    }
+
+    pub fn received_fragment(&self, id: &FragmentId) -> Option<&Fragment> {
+        self.received_fragments
+            .iter()
+            .find(|fragment| fragment.id() == *id)
+    }
+
+    fn slot_duration(&self) -> Duration {
         let slot_duration: u8 = self
             .block0_configuration
             .blockchain_configuration
             .slot_duration
             .into();
-        let slots_per_epoch: u32 = self
-            .block0_configuration
+        Duration::from_secs(slot_duration as u64)
+    }
+
+    pub fn slots_per_epoch(&self) -> u32 {
+        self.block0_configuration
             .blockchain_configuration
             .slots_per_epoch
-            .into();
-        let slot_duration = std::time::Duration::from_secs(slot_duration as u64);
-        let now = SystemTime::from_secs_since_epoch(
+            .into()
+    }
+
+    fn block0_time(&self) -> SystemTime {
+        SystemTime::from_secs_since_epoch(
             self.block0_configuration
                 .blockchain_configuration
                 .block0_date
                 .to_secs(),
-        );
-        let mut no_of_slots = 0;
-        loop {
-            if now.as_ref() < &curr_time.as_ref().add(slot_duration) {
-                let time_era = TimeEra::new(0.into(), chain_time::Epoch(0), slots_per_epoch);
-                return BlockDate::new(0u32, 0u32).shift_slot(no_of_slots, &time_era);
-            }
-            no_of_slots += 1;
+        )
+    }
+
+    pub fn clock_mut(&mut self) -> &mut MockClock {
+        &mut self.clock
+    }
+
+    pub fn advance_slots(&mut self, slots: u32) {
+        self.clock.advance(self.slot_duration() * slots);
+        self.produce_blocks();
+    }
+
+    /// Moves clock to the beginning of given epoch. Clock can only go forward,
+    /// since blocks which were already minted cannot be reverted
+    pub fn jump_to_epoch(&mut self, epoch: u32) -> Result<(), ClockError> {
+        let epoch_duration = self.slot_duration() * self.slots_per_epoch() * epoch;
+        let epoch_start = self.block0_time().as_ref().add(epoch_duration);
+        self.clock.set(epoch_start)?;
+        self.produce_blocks();
+        Ok(())
+    }
+
+    pub fn time_status(&self) -> TimeStatus {
+        TimeStatus {
+            now: self.clock.now().into(),
+            frozen: self.clock.is_frozen(),
+            block_date: self.curr_block_date(),
         }
     }
 
-    pub fn curr_slot_start_time(&self) -> SystemTime {
-        let mut curr_time = self.start_time;
-        let slot_duration: u8 = self
+    fn curr_slot_index(&self) -> u64 {
+        let elapsed = self
+            .clock
+            .now()
+            .duration_since(*self.block0_time().as_ref())
+            .unwrap_or_default();
+        elapsed.as_secs() / self.slot_duration().as_secs()
+    }
+
+    /// Epoch which contains given unix timestamp
+    pub fn epoch_at(&self, secs_since_epoch: u64) -> u32 {
+        let block0_date = self
             .block0_configuration
             .blockchain_configuration
-            .slot_duration
-            .into();
-        let slot_duration = std::time::Duration::from_secs(slot_duration as u64);
-        let now = SystemTime::from_secs_since_epoch(
-            self.block0_configuration
-                .blockchain_configuration
-                .block0_date
-                .to_secs(),
-        );
+            .block0_date
+            .to_secs();
+        let elapsed = secs_since_epoch.saturating_sub(block0_date);
+        let epoch_duration = self.slot_duration().as_secs() * self.slots_per_epoch() as u64;
+        (elapsed / epoch_duration) as u32
+    }
 
-        loop {
-            if now.as_ref() < &curr_time.as_ref().add(slot_duration) {
-                return curr_time;
-            }
+    fn slot_index_of(&self, date: &BlockDate) -> u64 {
+        let date: chain_impl_mockchain::block::BlockDate = (*date).into();
+        date.epoch as u64 * self.slots_per_epoch() as u64 + date.slot_id as u64
+    }
+
+    fn block_date_of(&self, slot_index: u64) -> BlockDate {
+        let time_era = TimeEra::new(0.into(), chain_time::Epoch(0), self.slots_per_epoch());
+        BlockDate::new(0u32, 0u32).shift_slot(slot_index as u32, &time_era)
+    }
+
+    pub fn curr_block_date(&self) -> BlockDate {
+        self.block_date_of(self.curr_slot_index())
+    }
+
+    fn time_of(&self, date: &BlockDate) -> SystemTime {
+        let slot_duration = self.slot_duration() * self.slot_index_of(date) as u32;
+        self.block0_time().as_ref().add(slot_duration).into()
+    }
+
+    /// Node statistics in jormungandr format. Mock has no peers and does not track
+    /// fees and values of fragments in block, so these are always 0
+    pub fn node_stats(&self) -> NodeStats {
+        let tip = self.tip();
+        let tip_fragments: Vec<&Fragment> = tip
+            .fragments()
+            .iter()
+            .filter_map(|id| self.received_fragment(id))
+            .collect();
+        let last_block_time = Some(self.time_of(&tip.date()));
 
-            curr_time = curr_time.as_ref().add(slot_duration).into();
+        NodeStats {
+            version: format!("vitup-mock {}", env!("CARGO_PKG_VERSION")),
+            state: "Running".to_string(),
+            block_recv_cnt: tip.chain_length() as u64,
+            last_block_content_size: tip_fragments
+                .iter()
+                .map(|fragment| fragment.serialize_as_vec().unwrap().len() as u32)
+                .sum(),
+            last_block_date: Some(tip.date().to_string()),
+            last_block_fees: 0,
+            last_block_hash: Some(tip.id().to_string()),
+            last_block_height: Some(tip.chain_length().to_string()),
+            last_block_sum: 0,
+            last_block_time,
+            last_block_tx: tip_fragments.len() as u64,
+            last_received_block_time: last_block_time,
+            node_id: self.block0_hash().to_string(),
+            peer_available_cnt: 0,
+            peer_connected_cnt: 0,
+            peer_quarantined_cnt: 0,
+            peer_total_cnt: 0,
+            peer_unreachable_cnt: 0,
+            tx_recv_cnt: self.received_fragments.len() as u64,
+            tx_rejected_cnt: self
+                .fragment_logs
+                .iter()
+                .filter(|log| matches!(log.status(), FragmentStatus::Rejected { .. }))
+                .count() as u64,
+            uptime: Some(self.started.elapsed().as_secs()),
         }
     }
 
+    /// Committee members defined in block0
+    pub fn committees(&self) -> Vec<CommitteeIdDef> {
+        self.block0_configuration
+            .blockchain_configuration
+            .committees
+            .clone()
+    }
+
+    pub fn curr_slot_start_time(&self) -> SystemTime {
+        let slot_duration = self.slot_duration() * self.curr_slot_index() as u32;
+        self.block0_time().as_ref().add(slot_duration).into()
+    }
+
     pub fn settings(&self) -> SettingsDto {
         let params = self.ledger.get_ledger_parameters();
         let slot_duration: u8 = self
@@ -240,8 +881,140 @@ impl LedgerState {
     }
 }
 
+/// Fragments which are never accepted from clients by jormungandr fragment pool
+fn is_fragment_valid(fragment: &Fragment) -> bool {
+    !matches!(
+        fragment,
+        Fragment::Initial(_) | Fragment::OldUtxoDeclaration(_)
+    )
+}
+
+/// Decodes hex encoded fragment from v1 api batch. On failure returns hash of submitted
+/// bytes (or of the raw message if it is not even valid hex), so the fragment can still
+/// be reported as rejected
+fn decode_fragment(message: &str) -> Result<Fragment, FragmentId> {
+    let bytes = hex::decode(message).map_err(|_| Hash::hash_bytes(message.as_bytes()))?;
+    Fragment::deserialize(bytes.as_slice()).map_err(|_| Hash::hash_bytes(&bytes))
+}
+
+/// Result of fragments submission, serialized the same way as in jormungandr v1 api
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+pub struct FragmentsProcessingSummary {
+    pub accepted: Vec<String>,
+    pub rejected: Vec<RejectedFragmentInfo>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RejectedFragmentInfo {
+    pub id: String,
+    pub pool_number: usize,
+    pub reason: FragmentRejectionReason,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+pub enum FragmentRejectionReason {
+    FragmentAlreadyInLog,
+    FragmentInvalid,
+    PreviousFragmentInvalid,
+    PoolOverflow,
+}
+
+/// Batch of hex encoded fragments accepted by v1 api
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct FragmentsBatch {
+    #[serde(default)]
+    pub fail_fast: bool,
+    pub fragments: Vec<String>,
+}
+
+/// Node statistics with the same json representation as jormungandr `NodeStatsDto`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeStats {
+    pub version: String,
+    pub state: String,
+    pub block_recv_cnt: u64,
+    pub last_block_content_size: u32,
+    pub last_block_date: Option<String>,
+    pub last_block_fees: u64,
+    pub last_block_hash: Option<String>,
+    pub last_block_height: Option<String>,
+    pub last_block_sum: u64,
+    pub last_block_time: Option<SystemTime>,
+    pub last_block_tx: u64,
+    pub last_received_block_time: Option<SystemTime>,
+    pub node_id: String,
+    pub peer_available_cnt: usize,
+    pub peer_connected_cnt: usize,
+    pub peer_quarantined_cnt: usize,
+    pub peer_total_cnt: usize,
+    pub peer_unreachable_cnt: usize,
+    pub tx_recv_cnt: u64,
+    pub tx_rejected_cnt: u64,
+    pub uptime: Option<u64>,
+}
+
+/// Fragment held by fragment rule, which is resolved when block at `due_slot` is minted
+struct DelayedFragment {
+    fragment: Fragment,
+    strategy: FragmentRecieveStrategy,
+    reason: Option<String>,
+    due_slot: u64,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DelayedFragmentDump {
+    id: String,
+    strategy: FragmentRecieveStrategy,
+    reason: Option<String>,
+    due_slot: u64,
+}
+
+/// Difference between real ledger state of account and state reported by rest api
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+pub struct AccountOverride {
+    drained: u64,
+    counter_shift: u32,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BlockDump {
+    date: BlockDate,
+    fragments: Vec<String>,
+}
+
+impl From<&MockBlock> for BlockDump {
+    fn from(block: &MockBlock) -> Self {
+        Self {
+            date: block.date(),
+            fragments: block.fragments().iter().map(ToString::to_string).collect(),
+        }
+    }
+}
+
+/// Serializable form of ledger state. Fragments are kept in hex format
+/// in order of arrival, blocks only with fragments ids
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct LedgerStateDump {
+    fragment_strategy: FragmentRecieveStrategy,
+    fragments: Vec<String>,
+    fragment_logs: Vec<FragmentLog>,
+    mempool: Vec<(String, FragmentRecieveStrategy)>,
+    #[serde(default)]
+    fragment_rules: FragmentRules,
+    #[serde(default)]
+    delayed: Vec<DelayedFragmentDump>,
+    #[serde(default)]
+    account_overrides: HashMap<String, AccountOverride>,
+    blocks: Vec<BlockDump>,
+    tip: BlockDate,
+    time: SystemTime,
+    frozen: bool,
+}
+
 pub fn override_fragment_status(
     block_date: BlockDate,
+    block_id: Hash,
     fragment_log: &mut FragmentLog,
     fragment_strategy: FragmentRecieveStrategy,
 ) {
@@ -252,7 +1025,7 @@ pub fn override_fragment_status(
         FragmentRecieveStrategy::Accept => {
             fragment_log.modify(FragmentStatus::InABlock {
                 date: block_date,
-                block: TestGen::hash().into(),
+                block: block_id.into(),
             });
         }
         FragmentRecieveStrategy::Reject => {
@@ -268,4 +1041,10 @@ pub fn override_fragment_status(
 pub enum Error {
     #[error("ledger error")]
     LedgerError(#[from] chain_impl_mockchain::ledger::Error),
+    #[error("cannot decode fragment hex")]
+    HexError(#[from] hex::FromHexError),
+    #[error("cannot decode fragment: {0}")]
+    FragmentDecodeError(String),
+    #[error("fragment {0} not found in dump")]
+    FragmentNotFound(String),
 }
@@ -0,0 +1,102 @@
+use super::fault::{Fault, InjectedFault};
+use super::ledger_state::LedgerState;
+use iapyx::utils::metrics::{render, RequestMetrics};
+use jormungandr_lib::interfaces::FragmentStatus;
+use prometheus::{IntCounterVec, IntGauge, IntGaugeVec, Opts, Registry};
+
+/// Prometheus metrics of mock. Request and fault counters are updated as requests are
+/// handled, ledger gauges are refreshed from ledger state on every scrape
+pub struct Metrics {
+    registry: Registry,
+    requests: RequestMetrics,
+    injected_faults: IntCounterVec,
+    fragments: IntGaugeVec,
+    mempool_size: IntGauge,
+    epoch: IntGauge,
+    slot: IntGauge,
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Self::new().unwrap()
+    }
+}
+
+impl Metrics {
+    pub fn new() -> Result<Self, prometheus::Error> {
+        let registry = Registry::new();
+        let requests = RequestMetrics::new(&registry)?;
+        let injected_faults = IntCounterVec::new(
+            Opts::new("mock_injected_faults_total", "Number of injected faults"),
+            &["fault"],
+        )?;
+        let fragments = IntGaugeVec::new(
+            Opts::new("mock_fragments", "Number of received fragments by status"),
+            &["status"],
+        )?;
+        let mempool_size = IntGauge::new(
+            "mock_mempool_size",
+            "Number of fragments waiting for next block",
+        )?;
+        let epoch = IntGauge::new("mock_epoch", "Current epoch")?;
+        let slot = IntGauge::new("mock_slot", "Current slot in epoch")?;
+
+        registry.register(Box::new(injected_faults.clone()))?;
+        registry.register(Box::new(fragments.clone()))?;
+        registry.register(Box::new(mempool_size.clone()))?;
+        registry.register(Box::new(epoch.clone()))?;
+        registry.register(Box::new(slot.clone()))?;
+
+        Ok(Self {
+            registry,
+            requests,
+            injected_faults,
+            fragments,
+            mempool_size,
+            epoch,
+            slot,
+        })
+    }
+
+    pub fn requests(&self) -> &RequestMetrics {
+        &self.requests
+    }
+
+    pub fn fault_injected(&self, fault: &InjectedFault) {
+        let name = match fault {
+            InjectedFault::Delay(_) => "latency",
+            InjectedFault::Respond(Fault::StatusCode(_)) => "status_code",
+            InjectedFault::Respond(Fault::Latency(_)) => "latency",
+            InjectedFault::Respond(Fault::Timeout(_)) => "timeout",
+            InjectedFault::Respond(Fault::MalformedBody) => "malformed_body",
+            InjectedFault::Respond(Fault::TooManyRequests(_)) => "too_many_requests",
+        };
+        self.injected_faults.with_label_values(&[name]).inc();
+    }
+
+    /// Refreshes ledger gauges and encodes all metrics in prometheus text format
+    pub fn render(&self, ledger: &LedgerState) -> String {
+        let (mut pending, mut in_a_block, mut rejected) = (0, 0, 0);
+        for log in ledger.fragment_logs() {
+            match log.status() {
+                FragmentStatus::Pending => pending += 1,
+                FragmentStatus::InABlock { .. } => in_a_block += 1,
+                FragmentStatus::Rejected { .. } => rejected += 1,
+            }
+        }
+        self.fragments.with_label_values(&["pending"]).set(pending);
+        self.fragments
+            .with_label_values(&["in_a_block"])
+            .set(in_a_block);
+        self.fragments
+            .with_label_values(&["rejected"])
+            .set(rejected);
+        self.mempool_size.set(ledger.mempool_size() as i64);
+
+        let date: chain_impl_mockchain::block::BlockDate = ledger.curr_block_date().into();
+        self.epoch.set(date.epoch as i64);
+        self.slot.set(date.slot_id as i64);
+
+        render(&self.registry)
+    }
+}
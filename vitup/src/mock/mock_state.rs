@@ -1,33 +1,257 @@
-use super::config::Configuration;
-use crate::config::VitStartParameters;
-use crate::mock::ledger_state::LedgerState;
+use super::config::{Artifacts, Configuration, VitData};
+use crate::config::{VitStartParameters, VoteOptionsError};
+use crate::mock::ledger_state::{LedgerState, LedgerStateDump};
+use crate::mock::vit_state::{self, VitStateMutator};
 use crate::{
-    scenario::network::build_template_generator,
+    scenario::committee::{self, PrivateCommittee, COMMITTEE_DIRECTORY},
+    scenario::network::{
+        build_external_template_generator, build_template_generator, ideascale_challenges,
+        ideascale_funds, ideascale_proposals,
+    },
+    scenario::vit_station::{VoteOptionsTemplateGenerator, VotePlanDetails},
     setup::start::quick::QuickVitBackendSettingsBuilder,
 };
+use assert_fs::TempDir;
+use chain_addr::{Address, Discrimination, Kind};
+use chain_core::property::Fragment as _;
+use chain_crypto::PublicKey;
+use chain_impl_mockchain::account::{AccountAlg, Identifier};
+use chain_impl_mockchain::certificate::{VotePlan, VoteTallyPayload};
+use chain_impl_mockchain::fragment::{Fragment, FragmentId};
+use chain_impl_mockchain::ledger::Ledger;
+use chain_impl_mockchain::value::Value;
+use chain_impl_mockchain::vote::{PayloadType, PrivateTallyState, Tally};
 use iapyx::VitVersion;
+use jormungandr_lib::interfaces::Block0Configuration;
 use jormungandr_scenario_tests::prepare_command;
 use jormungandr_scenario_tests::{Context, ProgressBarMode};
 use jormungandr_testing_utils::testing::network_builder::Seed;
+use jormungandr_testing_utils::testing::FragmentBuilder;
+use jormungandr_testing_utils::wallet::Wallet;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::Path;
 use std::path::PathBuf;
 use std::str::FromStr;
 use thiserror::Error;
+use vit_servicing_station_lib::db::models::api_tokens::ApiTokenData;
+use vit_servicing_station_lib::db::models::challenges::Challenge;
+use vit_servicing_station_lib::db::models::funds::Fund;
+use vit_servicing_station_lib::db::models::proposals::FullProposalInfo;
+use vit_servicing_station_tests::common::clients::RestError;
 use vit_servicing_station_tests::common::data::Snapshot;
-use vit_servicing_station_tests::common::data::ValidVotePlanGenerator;
+use vit_servicing_station_tests::common::data::{TemplateLoadError, ValidVotePlanGenerator};
+use vit_servicing_station_tests::common::startup::server::{
+    ServerBootstrapper, ServerBootstrapperError,
+};
+
+pub const STATE_FILE: &str = "state.json";
+pub const BLOCK0_BIN: &str = "block0.bin";
+pub const GENESIS_YAML: &str = "genesis.yaml";
+pub const FAUCET_ALIAS: &str = "faucet";
+pub const FAUCET_VALUE: u64 = 1_000_000_000_000;
+/// Directory (in state dir) with secret keys of committee and faucet wallets
+pub const WALLETS_DIR: &str = "wallets";
 
 pub struct MockState {
     pub available: bool,
     pub error_code: u16,
     version: VitVersion,
+    parameters: VitStartParameters,
     ledger_state: LedgerState,
     vit_state: Snapshot,
+    committee: Option<Committee>,
+    faucet: Option<Wallet>,
+}
+
+/// Committee data required to tally vote plans. It is only available
+/// when block0 was generated by mock itself. Every vote plan is tallied
+/// by its owner, so wallets are kept by alias
+struct Committee {
+    wallets: HashMap<String, Wallet>,
+    /// alias of owner and vote plan
+    vote_plans: Vec<(String, VotePlan)>,
+    private: Option<PrivateCommittee>,
+}
+
+/// Committee as saved with state, wallets and private committee keys are saved
+/// in [`WALLETS_DIR`]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+struct CommitteeDump {
+    /// vote plan id and alias of its owner
+    vote_plans: Vec<(String, String)>,
+}
+
+impl Committee {
+    fn save(&self, wallets_dir: &Path) -> Result<CommitteeDump, Error> {
+        for (alias, wallet) in self.wallets.iter() {
+            wallet.save_to_path(wallets_dir.join(alias))?;
+        }
+        if let Some(private) = &self.private {
+            private.write_to(wallets_dir.join(COMMITTEE_DIRECTORY))?;
+        }
+        Ok(CommitteeDump {
+            vote_plans: self
+                .vote_plans
+                .iter()
+                .map(|(owner, vote_plan)| (vote_plan.to_id().to_string(), owner.clone()))
+                .collect(),
+        })
+    }
+
+    fn load(
+        wallets_dir: &Path,
+        dump: CommitteeDump,
+        ledger_state: &LedgerState,
+    ) -> Result<Self, Error> {
+        let committee_dir = wallets_dir.join(COMMITTEE_DIRECTORY);
+        let owners: HashMap<String, String> = dump.vote_plans.into_iter().collect();
+        let vote_plans: Vec<(String, VotePlan)> = ledger_state
+            .block0_vote_plans()
+            .into_iter()
+            .filter_map(|vote_plan| {
+                owners
+                    .get(&vote_plan.to_id().to_string())
+                    .map(|owner| (owner.clone(), vote_plan))
+            })
+            .collect();
+        let mut wallets = HashMap::new();
+        for (owner, _) in vote_plans.iter() {
+            if !wallets.contains_key(owner) {
+                wallets.insert(owner.clone(), load_wallet(wallets_dir, owner, ledger_state));
+            }
+        }
+        Ok(Self {
+            wallets,
+            vote_plans,
+            private: if committee_dir.exists() {
+                Some(PrivateCommittee::read_from(committee_dir)?)
+            } else {
+                None
+            },
+        })
+    }
+}
+
+/// Imports wallet saved with state. Spending counter is taken from ledger, including
+/// fragments waiting in mempool
+fn load_wallet(wallets_dir: &Path, alias: &str, ledger_state: &LedgerState) -> Wallet {
+    let mut wallet = Wallet::import_account(wallets_dir.join(alias), None);
+    let (ledger, _) = ledger_state.pending_ledger();
+    if let Ok(state) = ledger.accounts().get_state(&wallet.identifier()) {
+        wallet.update_counter(u32::from(state.counter));
+    }
+    wallet
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct VitStateDump {
+    funds: Vec<Fund>,
+    challenges: Vec<Challenge>,
+    proposals: Vec<FullProposalInfo>,
+    #[serde(default)]
+    tokens: HashMap<String, ApiTokenData>,
+}
+
+impl From<&Snapshot> for VitStateDump {
+    fn from(snapshot: &Snapshot) -> Self {
+        Self {
+            funds: snapshot.funds(),
+            challenges: snapshot.challenges(),
+            proposals: snapshot.proposals(),
+            tokens: snapshot.tokens(),
+        }
+    }
 }
 
-pub fn context<P: AsRef<Path>>(testing_directory: P) -> Context {
+impl From<VitStateDump> for Snapshot {
+    fn from(dump: VitStateDump) -> Self {
+        let voteplans = dump
+            .funds
+            .iter()
+            .flat_map(|fund| fund.chain_vote_plans.clone())
+            .collect();
+        Snapshot::new(
+            dump.funds,
+            dump.proposals,
+            dump.challenges,
+            dump.tokens,
+            voteplans,
+        )
+    }
+}
+
+fn load_vit_data(vit_data: &VitData) -> Result<VitStateDump, Error> {
+    match vit_data {
+        VitData::Json {
+            funds,
+            challenges,
+            proposals,
+        } => Ok(VitStateDump {
+            funds: read_json(funds)?,
+            challenges: read_json(challenges)?,
+            proposals: read_json(proposals)?,
+            tokens: HashMap::new(),
+        }),
+        VitData::Storage(storage) => read_storage(storage.clone()),
+    }
+}
+
+fn read_json<T: DeserializeOwned, P: AsRef<Path>>(path: P) -> Result<T, Error> {
+    serde_json::from_str(&std::fs::read_to_string(path)?).map_err(Into::into)
+}
+
+/// Reads vit data using temporary vit-servicing-station instance. Its rest client is blocking,
+/// therefore it runs on a separate thread outside of mock runtime. Rest api exposes only
+/// current fund, other funds are fetched by ids referenced by challenges
+fn read_storage(storage: PathBuf) -> Result<VitStateDump, Error> {
+    std::thread::spawn(move || {
+        let temp_dir = TempDir::new()?;
+        let server = ServerBootstrapper::new()
+            .with_db_path(storage.to_str().unwrap())
+            .start_with_exe(&temp_dir, PathBuf::from("vit-servicing-station-server"))?;
+        let rest_client = server.rest_client();
+        let challenges = rest_client.challenges()?;
+
+        let mut funds = vec![rest_client.funds()?];
+        for challenge in challenges.iter() {
+            if !funds.iter().any(|fund| fund.id == challenge.fund_id) {
+                funds.push(rest_client.fund(&challenge.fund_id.to_string())?);
+            }
+        }
+
+        Ok(VitStateDump {
+            funds,
+            challenges,
+            proposals: rest_client.proposals()?,
+            tokens: HashMap::new(),
+        })
+    })
+    .join()
+    .map_err(|_| Error::VitStationReadFailed)?
+}
+
+fn parse_account(account: &str) -> Result<PublicKey<AccountAlg>, Error> {
+    PublicKey::<AccountAlg>::from_str(account)
+        .map_err(|_| Error::InvalidAccountId(account.to_string()))
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MockStateDump {
+    version: VitVersion,
+    parameters: VitStartParameters,
+    ledger: LedgerStateDump,
+    vit: VitStateDump,
+    #[serde(default)]
+    committee: Option<CommitteeDump>,
+    #[serde(default)]
+    faucet: Option<String>,
+}
+
+pub fn context<P: AsRef<Path>>(testing_directory: P, seed: Option<Seed>) -> Context {
     let jormungandr = prepare_command(PathBuf::from_str("jormungandr").unwrap());
     let jcli = prepare_command(PathBuf::from_str("jcli").unwrap());
-    let seed = Seed::generate(rand::rngs::OsRng);
+    let seed = seed.unwrap_or_else(|| Seed::generate(rand::rngs::OsRng));
     let generate_documentation = true;
     let log_level = "info".to_string();
 
@@ -43,18 +267,84 @@ pub fn context<P: AsRef<Path>>(testing_directory: P) -> Context {
 }
 
 impl MockState {
-    pub fn new(params: VitStartParameters, config: Configuration) -> Result<Self, Error> {
-        std::fs::remove_dir_all(&config.working_dir)?;
+    pub fn new(mut params: VitStartParameters, config: Configuration) -> Result<Self, Error> {
+        if let Some(artifacts) = &config.artifacts {
+            return Self::from_artifacts(params, artifacts);
+        }
+
+        if config.working_dir.exists() {
+            std::fs::remove_dir_all(&config.working_dir)?;
+        }
+
+        let ideascale = config.use_ideascale();
+        if ideascale {
+            let proposals = match &config.ideascale_data {
+                Some(data) => data.proposals.clone(),
+                None => ideascale_proposals(),
+            };
+            params.vote_options.import_proposals(proposals)?;
+        }
 
         let mut quick_setup = QuickVitBackendSettingsBuilder::new();
-        let context = context(&config.working_dir);
+        let context = context(&config.working_dir, config.seed.clone());
         quick_setup.upload_parameters(params);
+        quick_setup.faucet_wallet(FAUCET_ALIAS, FAUCET_VALUE);
+        if ideascale {
+            let (funds, challenges) = match &config.ideascale_data {
+                Some(data) => (data.funds.clone(), data.challenges.clone()),
+                None => (ideascale_funds(), ideascale_challenges()),
+            };
+            quick_setup
+                .validate_imported_fund(funds, challenges)
+                .map_err(|e| Error::SetupFailed(e.to_string()))?;
+        }
 
-        let template_generator = Box::leak(build_template_generator(config.ideascale));
-        let (_, controller, vit_parameters, version) = quick_setup.build(context).unwrap();
+        let template_generator = Box::leak(match &config.ideascale_data {
+            Some(data) => build_external_template_generator(
+                data.proposals.clone(),
+                data.challenges.clone(),
+                data.funds.clone(),
+            )?,
+            None => build_template_generator(ideascale, context.seed())?,
+        });
+        let (_, controller, vit_parameters, version) = quick_setup
+            .build(context)
+            .map_err(|e| Error::SetupFailed(e.to_string()))?;
+        let parameters = quick_setup.parameters().clone();
+        let vote_plans: Vec<(String, VotePlan)> = controller
+            .vote_plans()
+            .into_iter()
+            .map(|vote_plan| (vote_plan.owner(), VotePlan::from(vote_plan)))
+            .collect();
+        let mut wallets = HashMap::new();
+        for (owner, _) in vote_plans.iter() {
+            if !wallets.contains_key(owner) {
+                let wallet = controller
+                    .wallet(owner)
+                    .map_err(|e| Error::SetupFailed(e.to_string()))?;
+                wallets.insert(owner.clone(), wallet);
+            }
+        }
+        let committee = Committee {
+            wallets,
+            vote_plans,
+            private: if parameters.has_private_vote_plans() {
+                Some(PrivateCommittee::read_from(
+                    controller
+                        .working_directory()
+                        .path()
+                        .join(COMMITTEE_DIRECTORY),
+                )?)
+            } else {
+                None
+            },
+        };
 
+        let mut template_generator =
+            VoteOptionsTemplateGenerator::new(template_generator, &parameters);
         let mut generator = ValidVotePlanGenerator::new(vit_parameters);
-        let snapshot = generator.build(template_generator);
+        let mut snapshot = generator.build(&mut template_generator);
+        VotePlanDetails::apply_all(&quick_setup.vote_plans_details(&controller), &mut snapshot);
 
         Ok(Self {
             available: true,
@@ -65,9 +355,136 @@ impl MockState {
             )?,
             vit_state: snapshot,
             version: VitVersion::new(version),
+            parameters,
+            committee: Some(committee),
+            faucet: Some(
+                controller
+                    .wallet(FAUCET_ALIAS)
+                    .map_err(|e| Error::SetupFailed(e.to_string()))?,
+            ),
+        })
+    }
+
+    /// Builds state from existing block0 and vit data. Voting phases in parameters are
+    /// overridden by the ones defined for first vote plan in vit data. Committee and faucet keys
+    /// are not part of artifacts, so tally and funds transfers are not available
+    fn from_artifacts(
+        mut parameters: VitStartParameters,
+        artifacts: &Artifacts,
+    ) -> Result<Self, Error> {
+        let block0_configuration: Block0Configuration =
+            serde_yaml::from_str(&std::fs::read_to_string(&artifacts.genesis)?)?;
+        parameters.slot_duration = block0_configuration
+            .blockchain_configuration
+            .slot_duration
+            .into();
+        parameters.slots_per_epoch = block0_configuration
+            .blockchain_configuration
+            .slots_per_epoch
+            .into();
+
+        let ledger_state = LedgerState::new(block0_configuration, artifacts.block0.clone())?;
+        let vit_state: Snapshot = load_vit_data(&artifacts.vit_data)?.into();
+
+        if let Some(vote_plan) = vit_state
+            .funds()
+            .first()
+            .and_then(|fund| fund.chain_vote_plans.first().cloned())
+        {
+            parameters.vote_start =
+                ledger_state.epoch_at(vote_plan.chain_vote_start_time as u64) as u64;
+            parameters.vote_tally =
+                ledger_state.epoch_at(vote_plan.chain_vote_end_time as u64) as u64;
+            parameters.tally_end =
+                ledger_state.epoch_at(vote_plan.chain_committee_end_time as u64) as u64;
+        }
+
+        Ok(Self {
+            available: true,
+            error_code: 400,
+            ledger_state,
+            vit_state,
+            version: VitVersion::new(parameters.version.clone()),
+            parameters,
+            committee: None,
+            faucet: None,
+        })
+    }
+
+    /// Restores state previously saved with [`MockState::save`], including committee
+    /// and faucet wallets if mock was started from generated block0
+    pub fn load<P: AsRef<Path>>(state_dir: P) -> Result<Self, Error> {
+        let state_dir = state_dir.as_ref();
+        let dump: MockStateDump =
+            serde_json::from_str(&std::fs::read_to_string(state_dir.join(STATE_FILE))?)?;
+        let block0_configuration =
+            serde_yaml::from_str(&std::fs::read_to_string(state_dir.join(GENESIS_YAML))?)?;
+        let ledger_state = LedgerState::restore(
+            block0_configuration,
+            state_dir.join(BLOCK0_BIN),
+            dump.ledger,
+        )?;
+        let wallets_dir = state_dir.join(WALLETS_DIR);
+
+        Ok(Self {
+            available: true,
+            error_code: 400,
+            committee: dump
+                .committee
+                .map(|committee| Committee::load(&wallets_dir, committee, &ledger_state))
+                .transpose()?,
+            faucet: dump
+                .faucet
+                .map(|alias| load_wallet(&wallets_dir, &alias, &ledger_state)),
+            ledger_state,
+            vit_state: dump.vit.into(),
+            version: dump.version,
+            parameters: dump.parameters,
         })
     }
 
+    pub fn save<P: AsRef<Path>>(&self, state_dir: P) -> Result<(), Error> {
+        let state_dir = state_dir.as_ref();
+        std::fs::create_dir_all(state_dir)?;
+        std::fs::write(state_dir.join(BLOCK0_BIN), self.ledger_state.block0_bin())?;
+        std::fs::write(
+            state_dir.join(GENESIS_YAML),
+            serde_yaml::to_string(self.ledger_state.block0_configuration())?,
+        )?;
+
+        let wallets_dir = state_dir.join(WALLETS_DIR);
+        std::fs::create_dir_all(&wallets_dir)?;
+        let faucet = match &self.faucet {
+            Some(faucet) => {
+                faucet.save_to_path(wallets_dir.join(FAUCET_ALIAS))?;
+                Some(FAUCET_ALIAS.to_string())
+            }
+            None => None,
+        };
+
+        let dump = MockStateDump {
+            version: self.version.clone(),
+            parameters: self.parameters.clone(),
+            ledger: self.ledger_state.dump(),
+            vit: (&self.vit_state).into(),
+            committee: self
+                .committee
+                .as_ref()
+                .map(|committee| committee.save(&wallets_dir))
+                .transpose()?,
+            faucet,
+        };
+        std::fs::write(
+            state_dir.join(STATE_FILE),
+            serde_json::to_string_pretty(&dump)?,
+        )?;
+        Ok(())
+    }
+
+    pub fn is_saved_in<P: AsRef<Path>>(state_dir: P) -> bool {
+        state_dir.as_ref().join(STATE_FILE).exists()
+    }
+
     pub fn version(&self) -> VitVersion {
         self.version.clone()
     }
@@ -76,10 +493,18 @@ impl MockState {
         self.version = VitVersion::new(version);
     }
 
+    pub fn parameters(&self) -> &VitStartParameters {
+        &self.parameters
+    }
+
     pub fn vit(&self) -> &Snapshot {
         &self.vit_state
     }
 
+    pub fn vit_mutator(&mut self) -> VitStateMutator {
+        VitStateMutator::new(&mut self.vit_state)
+    }
+
     pub fn ledger(&self) -> &LedgerState {
         &self.ledger_state
     }
@@ -88,27 +513,187 @@ impl MockState {
         &mut self.ledger_state
     }
 
-    pub fn set_fund_id(&mut self, id: i32) {
-        let funds = self.vit_state.funds_mut();
-        let mut fund = funds.last_mut().unwrap();
+    /// Tallies all vote plans, each with wallet of its owner. Private vote plans require two
+    /// steps (encrypted tally and decryption shares), so encrypted tally is applied on
+    /// pending ledger (see [`LedgerState::pending_ledger`]) in order to decrypt results.
+    /// All tally fragments are checked against ledger before they are put into mempool,
+    /// so clock is not moved and spending counters of committee wallets are only updated
+    /// when whole tally is accepted. Tally is rejected if clock is not in tally phase
+    pub fn tally(&mut self) -> Result<Vec<FragmentId>, Error> {
+        self.ledger_state.produce_blocks();
+        let committee = self
+            .committee
+            .as_mut()
+            .ok_or(Error::CommitteeNotAvailable)?;
+        let fragment_builder = FragmentBuilder::new(
+            &self.ledger_state.block0_hash().into(),
+            &self.ledger_state.fees(),
+        );
+        let (mut ledger, date) = self.ledger_state.pending_ledger();
+        let parameters = ledger.get_ledger_parameters();
+        let mut wallets = committee.wallets.clone();
+        let mut fragments = Vec::new();
+        let mut private_vote_plans = Vec::new();
 
-        fund.id = id;
+        let mut apply = |ledger: &Ledger, fragment: Fragment| {
+            let applied = ledger
+                .apply_fragment(&parameters, &fragment, date.into())
+                .map_err(|error| Error::TallyRejected {
+                    id: fragment.id().to_string(),
+                    reason: format!("{:?}", error),
+                })?;
+            fragments.push(fragment);
+            Ok::<_, Error>(applied)
+        };
 
-        for challenge in fund.challenges.iter_mut() {
-            challenge.fund_id = id;
+        for (owner, vote_plan) in committee.vote_plans.iter() {
+            let wallet = wallets
+                .get_mut(owner)
+                .ok_or_else(|| Error::CommitteeWalletNotFound(owner.clone()))?;
+            let fragment = match vote_plan.payload_type() {
+                PayloadType::Public => {
+                    fragment_builder.vote_tally(wallet, vote_plan, VoteTallyPayload::Public)
+                }
+                PayloadType::Private => {
+                    private_vote_plans.push((owner.clone(), vote_plan.clone()));
+                    fragment_builder.encrypted_tally(wallet, vote_plan)
+                }
+            };
+            ledger = apply(&ledger, fragment)?;
+            wallet.confirm_transaction();
         }
 
-        for vote_plan in fund.chain_vote_plans.iter_mut() {
-            vote_plan.fund_id = id;
+        for (owner, vote_plan) in private_vote_plans {
+            let vote_plan_status = ledger
+                .active_vote_plans()
+                .into_iter()
+                .find(|x| x.id == vote_plan.to_id())
+                .ok_or_else(|| Error::VotePlanNotFound(vote_plan.to_id().to_string()))?;
+            let encrypted_tallies = vote_plan_status
+                .proposals
+                .iter()
+                .map(|proposal| match &proposal.tally {
+                    Some(Tally::Private {
+                        state:
+                            PrivateTallyState::Encrypted {
+                                encrypted_tally,
+                                total_stake,
+                            },
+                    }) => Ok((encrypted_tally.clone(), total_stake.0)),
+                    _ => Err(Error::VotePlanNotEncrypted(vote_plan.to_id().to_string())),
+                })
+                .collect::<Result<Vec<_>, Error>>()?;
+            let (shares, _) = committee
+                .private
+                .as_ref()
+                .ok_or(Error::CommitteeNotAvailable)?
+                .decrypt_tally(&mut rand::rngs::OsRng, encrypted_tallies)?;
+
+            let wallet = wallets
+                .get_mut(&owner)
+                .ok_or_else(|| Error::CommitteeWalletNotFound(owner.clone()))?;
+            let fragment = fragment_builder.vote_tally(
+                wallet,
+                &vote_plan,
+                VoteTallyPayload::Private { inner: shares },
+            );
+            ledger = apply(&ledger, fragment)?;
+            wallet.confirm_transaction();
         }
 
-        for challenge in self.vit_state.challenges_mut() {
-            challenge.fund_id = id;
+        committee.wallets = wallets;
+        Ok(fragments
+            .into_iter()
+            .map(|fragment| self.ledger_state.inject(fragment))
+            .collect())
+    }
+
+    /// Creates new account by transferring funds from faucet wallet. Account becomes
+    /// visible when transfer is applied in the next block
+    pub fn create_account(&mut self, account: &str, value: u64) -> Result<FragmentId, Error> {
+        let key = parse_account(account)?;
+        if self.account_exists(&key.clone().into()) {
+            return Err(Error::AccountAlreadyExists(account.to_string()));
         }
+        self.faucet_transfer(key, value)
+    }
 
-        for proposal in self.vit_state.proposals_mut() {
-            proposal.proposal.fund_id = id;
+    /// Transfers funds from faucet wallet to existing account
+    pub fn top_up_account(&mut self, account: &str, value: u64) -> Result<FragmentId, Error> {
+        let key = parse_account(account)?;
+        if !self.account_exists(&key.clone().into()) {
+            return Err(Error::AccountNotFound(account.to_string()));
         }
+        self.faucet_transfer(key, value)
+    }
+
+    /// Lowers balance reported for account, see [`LedgerState::drain_account`]
+    pub fn drain_account(&mut self, account: &str, value: Option<u64>) -> Result<(), Error> {
+        let id = self.existing_account(account)?;
+        self.ledger_state.drain_account(&id, value);
+        Ok(())
+    }
+
+    /// Moves spending counter reported for account, see [`LedgerState::bump_spending_counter`]
+    pub fn bump_spending_counter(&mut self, account: &str, count: u32) -> Result<(), Error> {
+        let id = self.existing_account(account)?;
+        self.ledger_state.bump_spending_counter(&id, count);
+        Ok(())
+    }
+
+    /// Removes all overrides of reported balance and spending counter for account
+    pub fn reset_account(&mut self, account: &str) -> Result<(), Error> {
+        let id = self.existing_account(account)?;
+        self.ledger_state.reset_account(&id);
+        Ok(())
+    }
+
+    fn existing_account(&self, account: &str) -> Result<Identifier, Error> {
+        let id: Identifier = parse_account(account)?.into();
+        if !self.account_exists(&id) {
+            return Err(Error::AccountNotFound(account.to_string()));
+        }
+        Ok(id)
+    }
+
+    /// Account exists if it is in ledger or will be created by fragment waiting in mempool,
+    /// so account cannot be created twice before the next block
+    fn account_exists(&self, id: &Identifier) -> bool {
+        self.ledger_state.accounts().get_state(id).is_ok()
+            || self
+                .ledger_state
+                .pending_ledger()
+                .0
+                .accounts()
+                .get_state(id)
+                .is_ok()
+    }
+
+    fn faucet_transfer(
+        &mut self,
+        key: PublicKey<AccountAlg>,
+        value: u64,
+    ) -> Result<FragmentId, Error> {
+        let faucet = self.faucet.as_mut().ok_or(Error::FaucetNotAvailable)?;
+        let address = Address(Discrimination::Production, Kind::Account(key));
+        let fragment = FragmentBuilder::new(
+            &self.ledger_state.block0_hash().into(),
+            &self.ledger_state.fees(),
+        )
+        .transaction(faucet, address.into(), Value(value))
+        .map_err(|e| Error::FaucetTransferFailed(e.to_string()))?;
+
+        let fragment_id = self.ledger_state.inject(fragment);
+        faucet.confirm_transaction();
+        Ok(fragment_id)
+    }
+
+    pub fn current_fund(&self) -> Option<&Fund> {
+        vit_state::current_fund(&self.vit_state)
+    }
+
+    pub fn set_fund_id(&mut self, id: i32) -> Result<(), vit_state::Error> {
+        self.vit_mutator().set_current_fund_id(id)
     }
 }
 
@@ -118,4 +703,44 @@ pub enum Error {
     LedgerError(#[from] super::ledger_state::Error),
     #[error("IO error")]
     IoError(#[from] std::io::Error),
+    #[error("cannot serialize or deserialize state")]
+    SerdeError(#[from] serde_json::Error),
+    #[error("cannot serialize or deserialize genesis")]
+    SerdeYamlError(#[from] serde_yaml::Error),
+    #[error("committee data is not available, mock was not started from generated block0")]
+    CommitteeNotAvailable,
+    #[error("wallet of committee member {0}, which owns vote plan, is not available")]
+    CommitteeWalletNotFound(String),
+    #[error("private committee error")]
+    PrivateCommitteeError(#[from] committee::Error),
+    #[error("tally fragment {id} rejected by ledger: {reason}")]
+    TallyRejected { id: String, reason: String },
+    #[error("vote plan {0} not found in ledger")]
+    VotePlanNotFound(String),
+    #[error("tally of vote plan {0} is not encrypted")]
+    VotePlanNotEncrypted(String),
+    #[error("cannot create temporary directory")]
+    TempDirError(#[from] assert_fs::fixture::FixtureError),
+    #[error("cannot start vit station")]
+    VitServerBootstrapperError(#[from] ServerBootstrapperError),
+    #[error("vit station rest error")]
+    VitRestError(#[from] RestError),
+    #[error("reading vit station storage failed unexpectedly")]
+    VitStationReadFailed,
+    #[error("faucet wallet is not available, mock was not started from generated block0")]
+    FaucetNotAvailable,
+    #[error("cannot transfer funds from faucet: {0}")]
+    FaucetTransferFailed(String),
+    #[error("invalid account id: {0}")]
+    InvalidAccountId(String),
+    #[error("account {0} does not exist")]
+    AccountNotFound(String),
+    #[error("account {0} already exists")]
+    AccountAlreadyExists(String),
+    #[error("cannot import vote options")]
+    VoteOptionsError(#[from] VoteOptionsError),
+    #[error("cannot load ideascale data")]
+    TemplateLoadError(#[from] TemplateLoadError),
+    #[error("cannot set up backend: {0}")]
+    SetupFailed(String),
 }
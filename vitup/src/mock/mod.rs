@@ -1,12 +1,27 @@
 mod args;
+mod clock;
 mod config;
 mod context;
+mod controller;
+mod explorer;
+mod fault;
+mod fragment_rules;
 mod ledger_state;
 mod logger;
+mod metrics;
 mod mock_state;
+mod recorder;
 mod rest;
+mod tls;
+mod vit_state;
 
 pub use args::Error;
 pub use args::MockStartCommandArgs;
+pub use config::{Artifacts, Configuration, IdeascaleData, Tls, VitData};
+pub use context::{Context, ContextLock};
+pub use controller::{Error as MockControllerError, MockController};
+pub use fault::{Fault, FaultRule, FaultRuleEntry};
+pub use fragment_rules::{FragmentRule, FragmentRuleEntry};
 pub use ledger_state::FragmentRecieveStrategy;
 pub use logger::Logger;
+pub use recorder::{Record, RecordedBody, REDACTED_HEADER_VALUE};
@@ -0,0 +1,168 @@
+use chrono::offset::Utc;
+use chrono::DateTime;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::time::Duration;
+use warp::http::{request, response, HeaderMap};
+
+/// Requests to those paths are not recorded, so downloading recording does not pollute it
+const SKIPPED_PATHS: [&str; 2] = ["/api/control/logs", "/api/health"];
+/// Headers carrying credentials, their values are never stored in recording
+const REDACTED_HEADERS: [&str; 5] = [
+    "api-token",
+    "authorization",
+    "proxy-authorization",
+    "cookie",
+    "set-cookie",
+];
+/// Value stored in place of redacted header value
+pub const REDACTED_HEADER_VALUE: &str = "<redacted>";
+/// Maximum number of kept records, the oldest ones are dropped first
+const MAX_RECORDS: usize = 10_000;
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordedBody {
+    Empty,
+    Text(String),
+    /// base64 encoded content which is not valid utf8
+    Binary(String),
+}
+
+impl From<&[u8]> for RecordedBody {
+    fn from(bytes: &[u8]) -> Self {
+        if bytes.is_empty() {
+            return Self::Empty;
+        }
+        match std::str::from_utf8(bytes) {
+            Ok(text) => Self::Text(text.to_string()),
+            Err(_) => Self::Binary(base64::encode(bytes)),
+        }
+    }
+}
+
+impl RecordedBody {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match self {
+            Self::Empty => Vec::new(),
+            Self::Text(text) => text.as_bytes().to_vec(),
+            Self::Binary(content) => base64::decode(content).unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RecordedRequest {
+    pub method: String,
+    /// path with query
+    pub path: String,
+    pub headers: Vec<(String, String)>,
+    pub body: RecordedBody,
+}
+
+impl RecordedRequest {
+    pub fn new(parts: &request::Parts, body: &[u8]) -> Self {
+        Self {
+            method: parts.method.to_string(),
+            path: parts
+                .uri
+                .path_and_query()
+                .map(ToString::to_string)
+                .unwrap_or_else(|| parts.uri.path().to_string()),
+            headers: headers(&parts.headers),
+            body: body.into(),
+        }
+    }
+
+    pub fn should_be_recorded(&self) -> bool {
+        !SKIPPED_PATHS.iter().any(|path| self.path.starts_with(path))
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RecordedResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: RecordedBody,
+}
+
+impl RecordedResponse {
+    pub fn new(parts: &response::Parts, body: &[u8]) -> Self {
+        Self {
+            status: parts.status.as_u16(),
+            headers: headers(&parts.headers),
+            body: body.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Record {
+    pub id: u64,
+    pub timestamp: DateTime<Utc>,
+    pub duration_ms: u64,
+    pub request: RecordedRequest,
+    pub response: RecordedResponse,
+}
+
+fn headers(headers: &HeaderMap) -> Vec<(String, String)> {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            let value = if REDACTED_HEADERS.contains(&name.as_str()) {
+                REDACTED_HEADER_VALUE.to_string()
+            } else {
+                String::from_utf8_lossy(value.as_bytes()).to_string()
+            };
+            (name.to_string(), value)
+        })
+        .collect()
+}
+
+/// Keeps last [`MAX_RECORDS`] requests served by mock together with response and timing
+pub struct Recorder {
+    records: VecDeque<Record>,
+    next_id: u64,
+}
+
+impl Recorder {
+    pub fn new() -> Self {
+        Self {
+            records: VecDeque::new(),
+            next_id: 0,
+        }
+    }
+
+    pub fn record(
+        &mut self,
+        timestamp: DateTime<Utc>,
+        duration: Duration,
+        request: RecordedRequest,
+        response: RecordedResponse,
+    ) {
+        if self.records.len() == MAX_RECORDS {
+            self.records.pop_front();
+        }
+        self.records.push_back(Record {
+            id: self.next_id,
+            timestamp,
+            duration_ms: duration.as_millis() as u64,
+            request,
+            response,
+        });
+        self.next_id += 1;
+    }
+
+    /// Exports all records as json lines
+    pub fn to_json_lines(&self) -> String {
+        self.records
+            .iter()
+            .map(|record| serde_json::to_string(record).unwrap())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    pub fn clear(&mut self) {
+        self.records.clear();
+    }
+}
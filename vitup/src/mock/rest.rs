@@ -1,25 +1,46 @@
 use super::FragmentRecieveStrategy;
 use crate::config::VitStartParameters;
 use crate::mock::context::{Context, ContextLock};
+use crate::mock::explorer::{self, ExplorerSchema};
+use crate::mock::fault::{Fault, FaultRule, InjectedFault};
+use crate::mock::fragment_rules::FragmentRule;
+use crate::mock::ledger_state::FragmentsBatch;
+use crate::mock::recorder::{RecordedRequest, RecordedResponse};
+use crate::mock::vit_state::{Error as VitStateError, FundTimestamps};
 use chain_core::property::Deserialize;
 use chain_crypto::PublicKey;
 use chain_impl_mockchain::account::AccountAlg;
 use chain_impl_mockchain::account::Identifier;
+use chrono::Utc;
 use jormungandr_lib::interfaces::VotePlanStatus;
 use jortestkit::web::api_token::TokenError;
 use jortestkit::web::api_token::{APIToken, APITokenManager, API_TOKEN_HEADER};
 use serde::{Deserialize as SerdeDeserialize, Serialize as SerdeSerialize};
 use std::convert::Infallible;
-use std::fs::File;
+use std::future::Future;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use thiserror::Error;
-use tracing_subscriber::fmt::format::FmtSpan;
+use tokio::net::TcpListener;
+use tokio_rustls::rustls::ServerConfig;
+use tokio_rustls::TlsAcceptor;
 use vit_servicing_station_lib::db::models::challenges::Challenge;
+use vit_servicing_station_lib::db::models::proposals::FullProposalInfo;
 use vit_servicing_station_lib::db::models::proposals::Proposal;
 use vit_servicing_station_lib::v0::errors::HandleError;
 use vit_servicing_station_lib::v0::result::HandlerResult;
+use warp::hyper::{
+    self,
+    server::conn::Http,
+    service::{make_service_fn, service_fn, Service},
+    Body, Request, Response,
+};
 use warp::{http::StatusCode, reject::Reject, Filter, Rejection, Reply};
 impl Reject for crate::mock::context::Error {}
+impl Reject for crate::mock::clock::Error {}
+impl Reject for crate::mock::fault::Error {}
+impl Reject for crate::mock::mock_state::Error {}
+impl Reject for crate::mock::vit_state::Error {}
 use crate::manager::file_lister::dump_json;
 use crate::mock::context::Error::AccountDoesNotExist;
 use chain_core::property::Fragment as _;
@@ -37,19 +58,26 @@ pub enum Error {
 impl Reject for Error {}
 
 pub async fn start_rest_server(context: ContextLock) {
-    let is_token_enabled = context.lock().unwrap().api_token().is_some();
     let address = *context.lock().unwrap().address();
+    let listener = std::net::TcpListener::bind(address).unwrap();
+    serve(context, listener, futures::future::pending()).await
+}
+
+/// Serves mock api on already bound listener until shutdown future completes.
+/// Block production stops together with server
+pub async fn serve<F>(context: ContextLock, listener: std::net::TcpListener, shutdown: F)
+where
+    F: Future<Output = ()> + Send + 'static,
+{
+    let is_token_enabled = context.lock().unwrap().api_token().is_some();
+    let tls_config = context.lock().unwrap().tls_config();
     let working_dir = context.lock().unwrap().working_dir();
-    let with_context = warp::any().map(move || context.clone());
 
-    let (non_block, _guard) = tracing_appender::non_blocking(File::create("vole.trace").unwrap());
+    let block_production = tokio::spawn(produce_blocks(context.clone()));
 
-    let filter = std::env::var("RUST_LOG").unwrap_or_else(|_| "tracing=info,warp=debug".to_owned());
-    tracing_subscriber::fmt()
-        .with_env_filter(filter)
-        .with_writer(non_block)
-        .with_span_events(FmtSpan::CLOSE)
-        .init();
+    let recorder_context = context.clone();
+    let explorer_schema = explorer::schema(context.clone());
+    let with_context = warp::any().map(move || context.clone());
 
     let root = warp::path!("api" / ..);
 
@@ -80,7 +108,18 @@ pub async fn start_rest_server(context: ContextLock) {
                 .and(with_context.clone())
                 .and_then(logs_clear);
 
-            root.and(clear.or(list)).boxed()
+            let requests = warp::path!("requests")
+                .and(warp::get())
+                .and(with_context.clone())
+                .and_then(logs_requests);
+
+            let requests_clear = warp::path!("requests" / "clear")
+                .and(warp::post())
+                .and(with_context.clone())
+                .and_then(logs_requests_clear);
+
+            root.and(clear.or(list).or(requests).or(requests_clear))
+                .boxed()
         };
 
         let files = {
@@ -124,6 +163,11 @@ pub async fn start_rest_server(context: ContextLock) {
                 .and(with_context.clone())
                 .and_then(command_version);
 
+            let tally = warp::path!("tally")
+                .and(warp::post())
+                .and(with_context.clone())
+                .and_then(command_tally);
+
             let fragment_strategy = {
                 let root = warp::path!("fragments" / ..);
 
@@ -147,7 +191,35 @@ pub async fn start_rest_server(context: ContextLock) {
                     .and(with_context.clone())
                     .and_then(command_reset);
 
-                root.and(reject.or(accept).or(pending).or(reset)).boxed()
+                let rules = {
+                    let root = warp::path!("rules" / ..);
+
+                    let list = warp::path!("list")
+                        .and(warp::get())
+                        .and(with_context.clone())
+                        .and_then(fragment_rules_list);
+
+                    let add = warp::path!("add")
+                        .and(warp::post())
+                        .and(warp::body::json())
+                        .and(with_context.clone())
+                        .and_then(fragment_rules_add);
+
+                    let remove = warp::path!("remove" / u32)
+                        .and(warp::post())
+                        .and(with_context.clone())
+                        .and_then(fragment_rules_remove);
+
+                    let clear = warp::path!("clear")
+                        .and(warp::post())
+                        .and(with_context.clone())
+                        .and_then(fragment_rules_clear);
+
+                    root.and(list.or(add).or(remove).or(clear)).boxed()
+                };
+
+                root.and(reject.or(accept).or(pending).or(reset).or(rules))
+                    .boxed()
             };
 
             root.and(
@@ -156,12 +228,244 @@ pub async fn start_rest_server(context: ContextLock) {
                     .or(set_error_code)
                     .or(fund_id)
                     .or(fragment_strategy)
-                    .or(version),
+                    .or(version)
+                    .or(tally),
             )
             .boxed()
         };
+        let time = {
+            let root = warp::path!("time" / ..);
+
+            let status = warp::path!("status")
+                .and(warp::get())
+                .and(with_context.clone())
+                .and_then(time_status);
+
+            let freeze = warp::path!("freeze")
+                .and(warp::post())
+                .and(with_context.clone())
+                .and_then(time_freeze);
+
+            let resume = warp::path!("resume")
+                .and(warp::post())
+                .and(with_context.clone())
+                .and_then(time_resume);
+
+            let advance = warp::path!("advance" / u32)
+                .and(warp::post())
+                .and(with_context.clone())
+                .and_then(time_advance);
+
+            let jump = {
+                let root = warp::path!("jump" / ..);
+
+                let vote_start = warp::path!("vote-start")
+                    .and(warp::post())
+                    .and(with_context.clone())
+                    .and_then(time_jump_to_vote_start);
+
+                let tally_start = warp::path!("tally-start")
+                    .and(warp::post())
+                    .and(with_context.clone())
+                    .and_then(time_jump_to_tally_start);
+
+                let tally_end = warp::path!("tally-end")
+                    .and(warp::post())
+                    .and(with_context.clone())
+                    .and_then(time_jump_to_tally_end);
+
+                root.and(vote_start.or(tally_start).or(tally_end)).boxed()
+            };
+
+            root.and(status.or(freeze).or(resume).or(advance).or(jump))
+                .boxed()
+        };
+
+        let state = {
+            let root = warp::path!("state" / ..);
+
+            let save = warp::path!("save")
+                .and(warp::post())
+                .and(with_context.clone())
+                .and_then(state_save);
+
+            let load = warp::path!("load")
+                .and(warp::post())
+                .and(with_context.clone())
+                .and_then(state_load);
+
+            root.and(save.or(load)).boxed()
+        };
+
+        let data = {
+            let root = warp::path!("data" / ..);
+
+            let funds = {
+                let root = warp::path!("funds" / ..);
+
+                let add = warp::path!("add")
+                    .and(warp::post())
+                    .and(warp::body::json())
+                    .and(with_context.clone())
+                    .and_then(data_add_fund);
+
+                let update = warp::path!("update" / i32)
+                    .and(warp::post())
+                    .and(warp::body::json())
+                    .and(with_context.clone())
+                    .and_then(data_update_fund);
+
+                let timestamps = warp::path!("timestamps" / i32)
+                    .and(warp::post())
+                    .and(warp::body::json())
+                    .and(with_context.clone())
+                    .and_then(data_update_fund_timestamps);
+
+                let remove = warp::path!("remove" / i32)
+                    .and(warp::post())
+                    .and(with_context.clone())
+                    .and_then(data_remove_fund);
+
+                root.and(add.or(update).or(timestamps).or(remove)).boxed()
+            };
+
+            let challenges = {
+                let root = warp::path!("challenges" / ..);
+
+                let add = warp::path!("add")
+                    .and(warp::post())
+                    .and(warp::body::json())
+                    .and(with_context.clone())
+                    .and_then(data_add_challenge);
+
+                let update = warp::path!("update" / i32)
+                    .and(warp::post())
+                    .and(warp::body::json())
+                    .and(with_context.clone())
+                    .and_then(data_update_challenge);
+
+                let remove = warp::path!("remove" / i32)
+                    .and(warp::post())
+                    .and(with_context.clone())
+                    .and_then(data_remove_challenge);
+
+                root.and(add.or(update).or(remove)).boxed()
+            };
+
+            let proposals = {
+                let root = warp::path!("proposals" / ..);
+
+                let add = warp::path!("add")
+                    .and(warp::post())
+                    .and(warp::body::json())
+                    .and(with_context.clone())
+                    .and_then(data_add_proposal);
+
+                let update = warp::path!("update" / i32)
+                    .and(warp::post())
+                    .and(warp::body::json())
+                    .and(with_context.clone())
+                    .and_then(data_update_proposal);
+
+                let vote_options = warp::path!("vote-options" / i32)
+                    .and(warp::post())
+                    .and(warp::body::json())
+                    .and(with_context.clone())
+                    .and_then(data_update_vote_options);
+
+                let remove = warp::path!("remove" / i32)
+                    .and(warp::post())
+                    .and(with_context.clone())
+                    .and_then(data_remove_proposal);
+
+                root.and(add.or(update).or(vote_options).or(remove)).boxed()
+            };
+
+            root.and(funds.or(challenges).or(proposals)).boxed()
+        };
+
+        let accounts = {
+            let root = warp::path!("accounts" / ..);
+
+            let create = warp::path!("create" / String / u64)
+                .and(warp::post())
+                .and(with_context.clone())
+                .and_then(accounts_create);
+
+            let top_up = warp::path!("top-up" / String / u64)
+                .and(warp::post())
+                .and(with_context.clone())
+                .and_then(accounts_top_up);
+
+            let drain = warp::path!("drain" / String)
+                .and(warp::post())
+                .and(with_context.clone())
+                .and_then(accounts_drain);
+
+            let drain_value = warp::path!("drain" / String / u64)
+                .and(warp::post())
+                .and(with_context.clone())
+                .and_then(accounts_drain_value);
+
+            let bump_counter = warp::path!("bump-counter" / String / u32)
+                .and(warp::post())
+                .and(with_context.clone())
+                .and_then(accounts_bump_counter);
+
+            let reset = warp::path!("reset" / String)
+                .and(warp::post())
+                .and(with_context.clone())
+                .and_then(accounts_reset);
+
+            root.and(
+                create
+                    .or(top_up)
+                    .or(drain)
+                    .or(drain_value)
+                    .or(bump_counter)
+                    .or(reset),
+            )
+            .boxed()
+        };
+
+        let faults = {
+            let root = warp::path!("faults" / ..);
+
+            let list = warp::path!("list")
+                .and(warp::get())
+                .and(with_context.clone())
+                .and_then(faults_list);
+
+            let add = warp::path!("add")
+                .and(warp::post())
+                .and(warp::body::json())
+                .and(with_context.clone())
+                .and_then(faults_add);
+
+            let remove = warp::path!("remove" / u32)
+                .and(warp::post())
+                .and(with_context.clone())
+                .and_then(faults_remove);
+
+            let clear = warp::path!("clear")
+                .and(warp::post())
+                .and(with_context.clone())
+                .and_then(faults_clear);
+
+            root.and(list.or(add).or(remove).or(clear)).boxed()
+        };
+
         root.and(api_token_filter)
-            .and(command.or(files).or(logs))
+            .and(
+                command
+                    .or(files)
+                    .or(logs)
+                    .or(time)
+                    .or(state)
+                    .or(faults)
+                    .or(data)
+                    .or(accounts),
+            )
             .boxed()
     };
 
@@ -251,7 +555,13 @@ pub async fn start_rest_server(context: ContextLock) {
                 .and_then(debug_message)
                 .boxed();
 
-            root.and(logs.or(debug)).boxed()
+            let by_id = warp::path!(String)
+                .and(warp::get())
+                .and(with_context.clone())
+                .and_then(get_fragment)
+                .boxed();
+
+            root.and(logs.or(debug).or(by_id)).boxed()
         };
 
         let message = warp::path!("message")
@@ -261,10 +571,39 @@ pub async fn start_rest_server(context: ContextLock) {
             .and_then(post_message)
             .boxed();
 
-        let votes = warp::path!("vote" / "active" / "plans")
+        let votes = {
+            let root = warp::path!("vote" / "active" / ..);
+
+            let plans = warp::path!("plans")
+                .and(warp::get())
+                .and(with_context.clone())
+                .and_then(get_active_vote_plans)
+                .boxed();
+
+            let committees = warp::path!("committees")
+                .and(warp::get())
+                .and(with_context.clone())
+                .and_then(get_active_committees)
+                .boxed();
+
+            root.and(plans.or(committees)).boxed()
+        };
+
+        let node_stats = warp::path!("node" / "stats")
             .and(warp::get())
             .and(with_context.clone())
-            .and_then(get_active_vote_plans)
+            .and_then(get_node_stats)
+            .boxed();
+
+        let tip = warp::path!("tip")
+            .and(warp::get())
+            .and(with_context.clone())
+            .and_then(get_tip)
+            .boxed();
+
+        let explorer = warp::path!("explorer" / "graphql")
+            .and(async_graphql_warp::graphql(explorer_schema.clone()))
+            .and_then(explorer_graphql)
             .boxed();
 
         let block0 =
@@ -284,6 +623,9 @@ pub async fn start_rest_server(context: ContextLock) {
                 .or(account)
                 .or(fragment)
                 .or(votes)
+                .or(node_stats)
+                .or(tip)
+                .or(explorer)
                 .or(message),
         )
         .boxed()
@@ -318,122 +660,720 @@ pub async fn start_rest_server(context: ContextLock) {
             root.and(post.or(status).or(logs)).boxed()
         };
 
-        root.and(fragments)
+        let votes = warp::path!("votes" / "account" / String)
+            .and(warp::get())
+            .and(with_context.clone())
+            .and_then(get_account_votes)
+            .boxed();
+
+        root.and(fragments.or(votes))
     };
 
     let version = warp::path!("version")
         .and(with_context.clone())
         .map(move |context: ContextLock| warp::reply::json(&context.lock().unwrap().version()));
 
+    let fault_injection = warp::method()
+        .and(warp::path::full())
+        .and(with_context.clone())
+        .and_then(inject_faults)
+        .untuple_one();
+
+    // explorer is also served outside of api, as jormungandr does
+    let explorer = warp::path!("explorer" / "graphql")
+        .and(async_graphql_warp::graphql(explorer_schema))
+        .and_then(explorer_graphql);
+
+    let metrics = warp::path!("metrics")
+        .and(warp::get())
+        .and(with_context.clone())
+        .map(|context: ContextLock| {
+            let context = context.lock().unwrap();
+            context.metrics().render(context.state().ledger())
+        });
+
     let api = root
-        .and(health.or(control).or(v0).or(v1).or(version))
+        .and(
+            health
+                .or(control)
+                .or(fault_injection.and(v0.or(v1)))
+                .or(version),
+        )
+        .or(explorer)
+        .or(metrics)
         .recover(report_invalid)
         .boxed();
 
-    let server = warp::serve(api);
+    let service = warp::service(api);
+
+    if let Some(tls_config) = tls_config {
+        serve_tls(listener, tls_config, service, recorder_context, shutdown).await;
+    } else {
+        let make_service = make_service_fn(move |_| {
+            let service = service.clone();
+            let context = recorder_context.clone();
+            async move {
+                Ok::<_, Infallible>(service_fn(move |request| {
+                    record_exchange(request, service.clone(), context.clone())
+                }))
+            }
+        });
+
+        hyper::Server::from_tcp(listener)
+            .unwrap()
+            .serve(make_service)
+            .with_graceful_shutdown(shutdown)
+            .await
+            .unwrap();
+    }
+    block_production.abort();
+}
+
+/// Accepts tls connections until shutdown and serves each of them on separate task.
+/// Connections which fail on handshake are dropped
+async fn serve_tls<S, F>(
+    listener: std::net::TcpListener,
+    tls_config: ServerConfig,
+    service: S,
+    context: ContextLock,
+    shutdown: F,
+) where
+    S: Service<Request<Body>, Response = Response<Body>, Error = Infallible>
+        + Clone
+        + Send
+        + 'static,
+    S::Future: Send,
+    F: Future<Output = ()>,
+{
+    let acceptor = TlsAcceptor::from(Arc::new(tls_config));
+    listener.set_nonblocking(true).unwrap();
+    let listener = TcpListener::from_std(listener).unwrap();
+    tokio::pin!(shutdown);
+
+    loop {
+        let stream = tokio::select! {
+            _ = &mut shutdown => break,
+            accepted = listener.accept() => match accepted {
+                Ok((stream, _)) => stream,
+                Err(_) => continue,
+            },
+        };
+        let acceptor = acceptor.clone();
+        let service = service.clone();
+        let context = context.clone();
+
+        tokio::spawn(async move {
+            if let Ok(stream) = acceptor.accept(stream).await {
+                let _ = Http::new()
+                    .serve_connection(
+                        stream,
+                        service_fn(move |request| {
+                            record_exchange(request, service.clone(), context.clone())
+                        }),
+                    )
+                    .await;
+            }
+        });
+    }
+}
+
+/// Passes request to mock api and stores both request and response in recorder.
+/// Bodies are buffered, since they need to be recorded and then forwarded
+async fn record_exchange<S>(
+    request: Request<Body>,
+    mut service: S,
+    context: ContextLock,
+) -> Result<Response<Body>, Infallible>
+where
+    S: Service<Request<Body>, Response = Response<Body>, Error = Infallible>,
+{
+    let timestamp = Utc::now();
+    let started = Instant::now();
+
+    let (parts, body) = request.into_parts();
+    let body = hyper::body::to_bytes(body).await.unwrap_or_default();
+    let recorded_request = RecordedRequest::new(&parts, &body);
+    let (method, path) = (parts.method.clone(), parts.uri.path().to_string());
+
+    let response = service
+        .call(Request::from_parts(parts, Body::from(body)))
+        .await?;
+    context.lock().unwrap().metrics().requests().observe(
+        method.as_str(),
+        &path,
+        response.status().as_u16(),
+        started.elapsed(),
+    );
+    if !recorded_request.should_be_recorded() {
+        return Ok(response);
+    }
+
+    let (parts, body) = response.into_parts();
+    let body = hyper::body::to_bytes(body).await.unwrap_or_default();
+    context.lock().unwrap().recorder_mut().record(
+        timestamp,
+        started.elapsed(),
+        recorded_request,
+        RecordedResponse::new(&parts, &body),
+    );
+    Ok(Response::from_parts(parts, Body::from(body)))
+}
+
+async fn explorer_graphql(
+    (schema, request): (ExplorerSchema, async_graphql::Request),
+) -> Result<impl Reply, Infallible> {
+    Ok(async_graphql_warp::Response::from(
+        schema.execute(request).await,
+    ))
+}
+
+async fn produce_blocks(context: ContextLock) {
+    loop {
+        tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+
+        let mut context = context.lock().unwrap();
+        let blocks = context.state_mut().ledger_mut().produce_blocks();
+        for block in blocks.iter().filter(|x| !x.fragments().is_empty()) {
+            context.log(format!(
+                "block {} minted at {} (chain length: {}) with {} fragments",
+                block.id(),
+                block.date(),
+                block.chain_length(),
+                block.fragments().len()
+            ));
+        }
+    }
+}
+
+pub async fn logs_get(context: ContextLock) -> Result<impl Reply, Rejection> {
+    let context_lock = context.lock().unwrap();
+    Ok(HandlerResult(Ok(context_lock.logs())))
+}
+
+pub async fn logs_clear(context: ContextLock) -> Result<impl Reply, Rejection> {
+    let mut context_lock = context.lock().unwrap();
+    context_lock.clear_logs();
+    Ok(warp::reply())
+}
+
+pub async fn logs_requests_clear(context: ContextLock) -> Result<impl Reply, Rejection> {
+    context.lock().unwrap().recorder_mut().clear();
+    Ok(warp::reply())
+}
+
+pub async fn logs_requests(context: ContextLock) -> Result<impl Reply, Rejection> {
+    let context_lock = context.lock().unwrap();
+    Ok(context_lock.recorder().to_json_lines())
+}
+
+pub async fn file_lister_handler(context: ContextLock) -> Result<impl Reply, Rejection> {
+    let context_lock = context.lock().unwrap();
+    Ok(dump_json(context_lock.working_dir())?).map(|r| warp::reply::json(&r))
+}
+
+pub async fn get_active_vote_plans(context: ContextLock) -> Result<impl Reply, Rejection> {
+    context.lock().unwrap().log("get_active_vote_plans");
+
+    if !context.lock().unwrap().available() {
+        let code = context.lock().unwrap().state().error_code;
+        context.lock().unwrap().log(&format!(
+            "unavailability mode is on. Rejecting with error code: {}",
+            code
+        ));
+        return Err(warp::reject::custom(ForcedErrorCode { code }));
+    }
+
+    let vp: Vec<VotePlanStatus> = context
+        .lock()
+        .unwrap()
+        .state()
+        .ledger()
+        .active_vote_plans()
+        .into_iter()
+        .map(VotePlanStatus::from)
+        .collect();
+    Ok(HandlerResult(Ok(vp)))
+}
+
+pub async fn debug_message(
+    fragment_id: String,
+    context: ContextLock,
+) -> Result<impl Reply, Rejection> {
+    let id = FragmentId::from_str(&fragment_id).map_err(|_| HandleError::NotFound(fragment_id))?;
+    let fragments = context
+        .lock()
+        .unwrap()
+        .state()
+        .ledger()
+        .received_fragments();
+    let fragment = fragments
+        .iter()
+        .find(|x| x.id() == id)
+        .ok_or_else(|| HandleError::NotFound(id.to_string()))?;
+
+    Ok(HandlerResult(Ok(format!("{:?}", fragment))))
+}
+
+pub async fn command_reset_mock(
+    context: ContextLock,
+    parameters: VitStartParameters,
+) -> Result<impl Reply, Rejection> {
+    context
+        .lock()
+        .unwrap()
+        .reset(parameters)
+        .map_err(warp::reject::custom)?;
+    Ok(warp::reply())
+}
+
+pub async fn command_available(
+    available: bool,
+    context: ContextLock,
+) -> Result<impl Reply, Rejection> {
+    context.lock().unwrap().state_mut().available = available;
+    Ok(warp::reply())
+}
+
+pub async fn command_error_code(
+    error_code: u16,
+    context: ContextLock,
+) -> Result<impl Reply, Rejection> {
+    context
+        .lock()
+        .unwrap()
+        .log(&format!("set-error-code: {}", error_code));
+    context.lock().unwrap().state_mut().error_code = error_code;
+    Ok(warp::reply())
+}
+
+pub async fn command_fund_id(id: i32, context: ContextLock) -> Result<impl Reply, Rejection> {
+    context
+        .lock()
+        .unwrap()
+        .state_mut()
+        .set_fund_id(id)
+        .map_err(warp::reject::custom)?;
+    Ok(warp::reply())
+}
+
+pub async fn command_version(
+    version: String,
+    context: ContextLock,
+) -> Result<impl Reply, Rejection> {
+    context.lock().unwrap().state_mut().set_version(version);
+    Ok(warp::reply())
+}
+
+pub async fn time_status(context: ContextLock) -> Result<impl Reply, Rejection> {
+    Ok(HandlerResult(Ok(context
+        .lock()
+        .unwrap()
+        .state()
+        .ledger()
+        .time_status())))
+}
+
+pub async fn time_freeze(context: ContextLock) -> Result<impl Reply, Rejection> {
+    let mut context = context.lock().unwrap();
+    context.log("time: freeze");
+    context.state_mut().ledger_mut().clock_mut().freeze();
+    Ok(warp::reply())
+}
+
+pub async fn time_resume(context: ContextLock) -> Result<impl Reply, Rejection> {
+    let mut context = context.lock().unwrap();
+    context.log("time: resume");
+    context.state_mut().ledger_mut().clock_mut().resume();
+    Ok(warp::reply())
+}
+
+pub async fn time_advance(slots: u32, context: ContextLock) -> Result<impl Reply, Rejection> {
+    let mut context = context.lock().unwrap();
+    context.log(format!("time: advance by {} slots", slots));
+    context.state_mut().ledger_mut().advance_slots(slots);
+    Ok(warp::reply())
+}
+
+pub async fn time_jump_to_vote_start(context: ContextLock) -> Result<impl Reply, Rejection> {
+    let mut context = context.lock().unwrap();
+    let epoch = context.state().parameters().vote_start as u32;
+    context.log(format!("time: jump to vote start (epoch {})", epoch));
+    context
+        .state_mut()
+        .ledger_mut()
+        .jump_to_epoch(epoch)
+        .map_err(warp::reject::custom)?;
+    Ok(warp::reply())
+}
+
+pub async fn time_jump_to_tally_start(context: ContextLock) -> Result<impl Reply, Rejection> {
+    let mut context = context.lock().unwrap();
+    let epoch = context.state().parameters().vote_tally as u32;
+    context.log(format!("time: jump to tally start (epoch {})", epoch));
+    context
+        .state_mut()
+        .ledger_mut()
+        .jump_to_epoch(epoch)
+        .map_err(warp::reject::custom)?;
+    Ok(warp::reply())
+}
+
+pub async fn time_jump_to_tally_end(context: ContextLock) -> Result<impl Reply, Rejection> {
+    let mut context = context.lock().unwrap();
+    let epoch = context.state().parameters().tally_end as u32;
+    context.log(format!("time: jump to tally end (epoch {})", epoch));
+    context
+        .state_mut()
+        .ledger_mut()
+        .jump_to_epoch(epoch)
+        .map_err(warp::reject::custom)?;
+    Ok(warp::reply())
+}
+
+pub async fn data_add_fund(fund: Fund, context: ContextLock) -> Result<impl Reply, Rejection> {
+    let mut context = context.lock().unwrap();
+    context.log(format!("data: add fund {}", fund.id));
+    context
+        .state_mut()
+        .vit_mutator()
+        .add_fund(fund)
+        .map_err(warp::reject::custom)?;
+    Ok(warp::reply())
+}
+
+pub async fn data_update_fund(
+    id: i32,
+    fund: Fund,
+    context: ContextLock,
+) -> Result<impl Reply, Rejection> {
+    let mut context = context.lock().unwrap();
+    context.log(format!("data: update fund {}", id));
+    context
+        .state_mut()
+        .vit_mutator()
+        .update_fund(id, fund)
+        .map_err(warp::reject::custom)?;
+    Ok(warp::reply())
+}
+
+pub async fn data_update_fund_timestamps(
+    id: i32,
+    timestamps: FundTimestamps,
+    context: ContextLock,
+) -> Result<impl Reply, Rejection> {
+    let mut context = context.lock().unwrap();
+    context.log(format!(
+        "data: update fund {} timestamps {:?}",
+        id, timestamps
+    ));
+    context
+        .state_mut()
+        .vit_mutator()
+        .update_fund_timestamps(id, timestamps)
+        .map_err(warp::reject::custom)?;
+    Ok(warp::reply())
+}
+
+pub async fn data_remove_fund(id: i32, context: ContextLock) -> Result<impl Reply, Rejection> {
+    let mut context = context.lock().unwrap();
+    context.log(format!("data: remove fund {}", id));
+    context
+        .state_mut()
+        .vit_mutator()
+        .remove_fund(id)
+        .map_err(warp::reject::custom)?;
+    Ok(warp::reply())
+}
+
+pub async fn data_add_challenge(
+    challenge: Challenge,
+    context: ContextLock,
+) -> Result<impl Reply, Rejection> {
+    let mut context = context.lock().unwrap();
+    context.log(format!("data: add challenge {}", challenge.id));
+    context
+        .state_mut()
+        .vit_mutator()
+        .add_challenge(challenge)
+        .map_err(warp::reject::custom)?;
+    Ok(warp::reply())
+}
+
+pub async fn data_update_challenge(
+    id: i32,
+    challenge: Challenge,
+    context: ContextLock,
+) -> Result<impl Reply, Rejection> {
+    let mut context = context.lock().unwrap();
+    context.log(format!("data: update challenge {}", id));
+    context
+        .state_mut()
+        .vit_mutator()
+        .update_challenge(id, challenge)
+        .map_err(warp::reject::custom)?;
+    Ok(warp::reply())
+}
+
+pub async fn data_remove_challenge(id: i32, context: ContextLock) -> Result<impl Reply, Rejection> {
+    let mut context = context.lock().unwrap();
+    context.log(format!("data: remove challenge {}", id));
+    context
+        .state_mut()
+        .vit_mutator()
+        .remove_challenge(id)
+        .map_err(warp::reject::custom)?;
+    Ok(warp::reply())
+}
+
+pub async fn data_add_proposal(
+    proposal: FullProposalInfo,
+    context: ContextLock,
+) -> Result<impl Reply, Rejection> {
+    let mut context = context.lock().unwrap();
+    context.log(format!(
+        "data: add proposal {}",
+        proposal.proposal.internal_id
+    ));
+    context
+        .state_mut()
+        .vit_mutator()
+        .add_proposal(proposal)
+        .map_err(warp::reject::custom)?;
+    Ok(warp::reply())
+}
+
+pub async fn data_update_proposal(
+    id: i32,
+    proposal: FullProposalInfo,
+    context: ContextLock,
+) -> Result<impl Reply, Rejection> {
+    let mut context = context.lock().unwrap();
+    context.log(format!("data: update proposal {}", id));
+    context
+        .state_mut()
+        .vit_mutator()
+        .update_proposal(id, proposal)
+        .map_err(warp::reject::custom)?;
+    Ok(warp::reply())
+}
+
+pub async fn data_update_vote_options(
+    id: i32,
+    options: Vec<String>,
+    context: ContextLock,
+) -> Result<impl Reply, Rejection> {
+    let mut context = context.lock().unwrap();
+    context.log(format!(
+        "data: set proposal {} vote options {:?}",
+        id, options
+    ));
+    context
+        .state_mut()
+        .vit_mutator()
+        .update_vote_options(id, options)
+        .map_err(warp::reject::custom)?;
+    Ok(warp::reply())
+}
+
+pub async fn data_remove_proposal(id: i32, context: ContextLock) -> Result<impl Reply, Rejection> {
+    let mut context = context.lock().unwrap();
+    context.log(format!("data: remove proposal {}", id));
+    context
+        .state_mut()
+        .vit_mutator()
+        .remove_proposal(id)
+        .map_err(warp::reject::custom)?;
+    Ok(warp::reply())
+}
 
-    let server_fut = server.bind(address);
-    server_fut.await;
+pub async fn faults_list(context: ContextLock) -> Result<impl Reply, Rejection> {
+    Ok(HandlerResult(Ok(context.lock().unwrap().faults().rules())))
 }
 
-pub async fn logs_get(context: ContextLock) -> Result<impl Reply, Rejection> {
-    let context_lock = context.lock().unwrap();
-    Ok(HandlerResult(Ok(context_lock.logs())))
+pub async fn faults_add(rule: FaultRule, context: ContextLock) -> Result<impl Reply, Rejection> {
+    let mut context = context.lock().unwrap();
+    context.log(format!("faults: add rule {:?}", rule));
+    let id = context
+        .faults_mut()
+        .add(rule)
+        .map_err(warp::reject::custom)?;
+    Ok(HandlerResult(Ok(id)))
 }
 
-pub async fn logs_clear(context: ContextLock) -> Result<impl Reply, Rejection> {
-    let mut context_lock = context.lock().unwrap();
-    context_lock.clear_logs();
+pub async fn faults_remove(id: u32, context: ContextLock) -> Result<impl Reply, Rejection> {
+    let mut context = context.lock().unwrap();
+    context.log(format!("faults: remove rule {}", id));
+    if !context.faults_mut().remove(id) {
+        return Err(warp::reject::not_found());
+    }
     Ok(warp::reply())
 }
 
-pub async fn file_lister_handler(context: ContextLock) -> Result<impl Reply, Rejection> {
-    let context_lock = context.lock().unwrap();
-    Ok(dump_json(context_lock.working_dir())?).map(|r| warp::reply::json(&r))
+pub async fn faults_clear(context: ContextLock) -> Result<impl Reply, Rejection> {
+    let mut context = context.lock().unwrap();
+    context.log("faults: clear all rules");
+    context.faults_mut().clear();
+    Ok(warp::reply())
 }
 
-pub async fn get_active_vote_plans(context: ContextLock) -> Result<impl Reply, Rejection> {
-    let mut context_lock = context.lock().unwrap();
-    context_lock.log("get_active_vote_plans");
+pub async fn state_save(context: ContextLock) -> Result<impl Reply, Rejection> {
+    let mut context = context.lock().unwrap();
+    let state_dir = context.save_state().map_err(warp::reject::custom)?;
+    context.log(format!("state: saved to {:?}", state_dir));
+    Ok(warp::reply())
+}
 
-    if !context.lock().unwrap().available() {
-        let code = context.lock().unwrap().state().error_code;
-        context.lock().unwrap().log(&format!(
-            "unavailability mode is on. Rejecting with error code: {}",
-            code
-        ));
-        return Err(warp::reject::custom(ForcedErrorCode { code }));
-    }
+pub async fn state_load(context: ContextLock) -> Result<impl Reply, Rejection> {
+    let mut context = context.lock().unwrap();
+    let state_dir = context.load_state().map_err(warp::reject::custom)?;
+    context.log(format!("state: loaded from {:?}", state_dir));
+    Ok(warp::reply())
+}
 
-    let vp: Vec<VotePlanStatus> = context
-        .lock()
-        .unwrap()
-        .state()
-        .ledger()
-        .active_vote_plans()
-        .into_iter()
-        .map(VotePlanStatus::from)
+pub async fn command_tally(context: ContextLock) -> Result<impl Reply, Rejection> {
+    let mut context = context.lock().unwrap();
+    context.log("tally all vote plans");
+    let fragment_ids: Vec<String> = context
+        .state_mut()
+        .tally()
+        .map_err(warp::reject::custom)?
+        .iter()
+        .map(ToString::to_string)
         .collect();
-    Ok(HandlerResult(Ok(vp)))
+    Ok(HandlerResult(Ok(fragment_ids)))
 }
 
-pub async fn debug_message(
-    fragment_id: String,
+pub async fn accounts_create(
+    account: String,
+    value: u64,
     context: ContextLock,
 ) -> Result<impl Reply, Rejection> {
-    let id = FragmentId::from_str(&fragment_id).map_err(|_| HandleError::NotFound(fragment_id))?;
-    let fragments = context
-        .lock()
-        .unwrap()
-        .state()
-        .ledger()
-        .received_fragments();
-    let fragment = fragments
-        .iter()
-        .find(|x| x.id() == id)
-        .ok_or_else(|| HandleError::NotFound(id.to_string()))?;
+    let mut context = context.lock().unwrap();
+    context.log(format!("accounts: create {} with {}", account, value));
+    let fragment_id = context
+        .state_mut()
+        .create_account(&account, value)
+        .map_err(warp::reject::custom)?;
+    Ok(HandlerResult(Ok(fragment_id.to_string())))
+}
 
-    Ok(HandlerResult(Ok(format!("{:?}", fragment))))
+pub async fn accounts_top_up(
+    account: String,
+    value: u64,
+    context: ContextLock,
+) -> Result<impl Reply, Rejection> {
+    let mut context = context.lock().unwrap();
+    context.log(format!("accounts: top up {} with {}", account, value));
+    let fragment_id = context
+        .state_mut()
+        .top_up_account(&account, value)
+        .map_err(warp::reject::custom)?;
+    Ok(HandlerResult(Ok(fragment_id.to_string())))
 }
 
-pub async fn command_reset_mock(
+pub async fn accounts_drain(
+    account: String,
     context: ContextLock,
-    parameters: VitStartParameters,
 ) -> Result<impl Reply, Rejection> {
-    context.lock().unwrap().reset(parameters);
+    let mut context = context.lock().unwrap();
+    context.log(format!("accounts: drain {}", account));
+    context
+        .state_mut()
+        .drain_account(&account, None)
+        .map_err(warp::reject::custom)?;
     Ok(warp::reply())
 }
 
-pub async fn command_available(
-    available: bool,
+pub async fn accounts_drain_value(
+    account: String,
+    value: u64,
     context: ContextLock,
 ) -> Result<impl Reply, Rejection> {
-    context.lock().unwrap().state_mut().available = available;
+    let mut context = context.lock().unwrap();
+    context.log(format!("accounts: drain {} from {}", value, account));
+    context
+        .state_mut()
+        .drain_account(&account, Some(value))
+        .map_err(warp::reject::custom)?;
     Ok(warp::reply())
 }
 
-pub async fn command_error_code(
-    error_code: u16,
+pub async fn accounts_bump_counter(
+    account: String,
+    count: u32,
     context: ContextLock,
 ) -> Result<impl Reply, Rejection> {
+    let mut context = context.lock().unwrap();
+    context.log(format!(
+        "accounts: bump spending counter of {} by {}",
+        account, count
+    ));
     context
-        .lock()
-        .unwrap()
-        .log(&format!("set-error-code: {}", error_code));
-    context.lock().unwrap().state_mut().error_code = error_code;
+        .state_mut()
+        .bump_spending_counter(&account, count)
+        .map_err(warp::reject::custom)?;
     Ok(warp::reply())
 }
 
-pub async fn command_fund_id(id: i32, context: ContextLock) -> Result<impl Reply, Rejection> {
-    context.lock().unwrap().state_mut().set_fund_id(id);
+pub async fn accounts_reset(
+    account: String,
+    context: ContextLock,
+) -> Result<impl Reply, Rejection> {
+    let mut context = context.lock().unwrap();
+    context.log(format!("accounts: reset {}", account));
+    context
+        .state_mut()
+        .reset_account(&account)
+        .map_err(warp::reject::custom)?;
     Ok(warp::reply())
 }
 
-pub async fn command_version(
-    version: String,
+pub async fn fragment_rules_list(context: ContextLock) -> Result<impl Reply, Rejection> {
+    Ok(HandlerResult(Ok(context
+        .lock()
+        .unwrap()
+        .state()
+        .ledger()
+        .fragment_rules()
+        .rules())))
+}
+
+pub async fn fragment_rules_add(
+    rule: FragmentRule,
     context: ContextLock,
 ) -> Result<impl Reply, Rejection> {
-    context.lock().unwrap().state_mut().set_version(version);
+    let mut context = context.lock().unwrap();
+    context.log(format!("fragments: add rule {:?}", rule));
+    let id = context
+        .state_mut()
+        .ledger_mut()
+        .fragment_rules_mut()
+        .add(rule);
+    Ok(HandlerResult(Ok(id)))
+}
+
+pub async fn fragment_rules_remove(id: u32, context: ContextLock) -> Result<impl Reply, Rejection> {
+    let mut context = context.lock().unwrap();
+    context.log(format!("fragments: remove rule {}", id));
+    if !context
+        .state_mut()
+        .ledger_mut()
+        .fragment_rules_mut()
+        .remove(id)
+    {
+        return Err(warp::reject::not_found());
+    }
+    Ok(warp::reply())
+}
+
+pub async fn fragment_rules_clear(context: ContextLock) -> Result<impl Reply, Rejection> {
+    let mut context = context.lock().unwrap();
+    context.log("fragments: clear all rules");
+    context
+        .state_mut()
+        .ledger_mut()
+        .fragment_rules_mut()
+        .clear();
     Ok(warp::reply())
 }
 
@@ -559,8 +1499,10 @@ pub async fn get_fragment_statuses(
         .statuses(ids))))
 }
 
+/// Returns 200 when all fragments were accepted, otherwise 400. In both cases
+/// body contains processing summary
 pub async fn post_fragments(
-    messages: Vec<String>,
+    batch: FragmentsBatch,
     context: ContextLock,
 ) -> Result<impl Reply, Rejection> {
     context.lock().unwrap().log("post_fragments");
@@ -574,24 +1516,102 @@ pub async fn post_fragments(
         return Err(warp::reject::custom(ForcedErrorCode { code }));
     }
 
-    let fragments: Vec<Fragment> = messages
-        .into_iter()
-        .map(|message| {
-            let message = hex::decode(message).unwrap();
-            Fragment::deserialize(message.as_slice()).unwrap()
-        })
-        .collect();
-    let fragment_ids: Vec<String> = fragments
-        .iter()
-        .map(|fragment| fragment.id().to_string())
-        .collect();
+    let summary = context
+        .lock()
+        .unwrap()
+        .state_mut()
+        .ledger_mut()
+        .submit(batch);
 
-    let mut context = context.lock().unwrap();
+    let status = if summary.rejected.is_empty() {
+        StatusCode::OK
+    } else {
+        StatusCode::BAD_REQUEST
+    };
+    Ok(warp::reply::with_status(
+        warp::reply::json(&summary),
+        status,
+    ))
+}
 
-    for fragment in fragments.into_iter() {
-        let _ = context.state_mut().ledger_mut().message(fragment);
+pub async fn get_fragment(
+    fragment_id: String,
+    context: ContextLock,
+) -> Result<impl Reply, Rejection> {
+    context
+        .lock()
+        .unwrap()
+        .log(format!("get_fragment {}...", &fragment_id));
+
+    if !context.lock().unwrap().available() {
+        let code = context.lock().unwrap().state().error_code;
+        context.lock().unwrap().log(&format!(
+            "unavailability mode is on. Rejecting with error code: {}",
+            code
+        ));
+        return Err(warp::reject::custom(ForcedErrorCode { code }));
     }
-    Ok(HandlerResult(Ok(fragment_ids)))
+
+    let id = FragmentId::from_str(&fragment_id).map_err(|_| HandleError::NotFound(fragment_id))?;
+    let fragment_log = context
+        .lock()
+        .unwrap()
+        .state()
+        .ledger()
+        .fragment_log(&id)
+        .cloned()
+        .ok_or_else(|| HandleError::NotFound(id.to_string()))?;
+
+    Ok(HandlerResult(Ok(fragment_log)))
+}
+
+pub async fn get_node_stats(context: ContextLock) -> Result<impl Reply, Rejection> {
+    context.lock().unwrap().log("get_node_stats");
+
+    if !context.lock().unwrap().available() {
+        let code = context.lock().unwrap().state().error_code;
+        context.lock().unwrap().log(&format!(
+            "unavailability mode is on. Rejecting with error code: {}",
+            code
+        ));
+        return Err(warp::reject::custom(ForcedErrorCode { code }));
+    }
+
+    let stats = context.lock().unwrap().state().ledger().node_stats();
+    Ok(HandlerResult(Ok(stats)))
+}
+
+/// Tip hash as plain text, as in jormungandr
+pub async fn get_tip(context: ContextLock) -> Result<impl Reply, Rejection> {
+    context.lock().unwrap().log("get_tip");
+
+    if !context.lock().unwrap().available() {
+        let code = context.lock().unwrap().state().error_code;
+        context.lock().unwrap().log(&format!(
+            "unavailability mode is on. Rejecting with error code: {}",
+            code
+        ));
+        return Err(warp::reject::custom(ForcedErrorCode { code }));
+    }
+
+    let tip = context.lock().unwrap().state().ledger().tip().id();
+    Ok(tip.to_string())
+}
+
+pub async fn get_active_committees(context: ContextLock) -> Result<impl Reply, Rejection> {
+    context.lock().unwrap().log("get_active_committees");
+
+    if !context.lock().unwrap().available() {
+        let code = context.lock().unwrap().state().error_code;
+        context.lock().unwrap().log(&format!(
+            "unavailability mode is on. Rejecting with error code: {}",
+            code
+        ));
+        return Err(warp::reject::custom(ForcedErrorCode { code }));
+    }
+
+    let committees = context.lock().unwrap().state().ledger().committees();
+    Ok(HandlerResult(Ok(committees)))
 }
 
 pub async fn get_fragment_logs(context: ContextLock) -> Result<impl Reply, Rejection> {
@@ -721,9 +1741,9 @@ pub async fn get_proposal(id: i32, context: ContextLock) -> Result<impl Reply, R
         .vit()
         .proposals()
         .iter()
-        .find(|x| x.proposal.internal_id.to_string() == id.to_string())
+        .find(|x| x.proposal.internal_id == id)
         .map(|x| x.proposal.clone())
-        .unwrap();
+        .ok_or_else(|| warp::reject::custom(VitStateError::ProposalNotFound(id)))?;
 
     Ok(HandlerResult(Ok(proposal)))
 }
@@ -745,7 +1765,10 @@ pub async fn get_fund_by_id(id: i32, context: ContextLock) -> Result<impl Reply,
 
     let funds = context.lock().unwrap().state().vit().funds();
 
-    let fund = funds.iter().find(|x| x.id == id).unwrap();
+    let fund = funds
+        .iter()
+        .find(|x| x.id == id)
+        .ok_or_else(|| warp::reject::custom(VitStateError::FundNotFound(id)))?;
 
     Ok(HandlerResult(Ok(fund.clone())))
 }
@@ -762,9 +1785,15 @@ pub async fn get_fund(context: ContextLock) -> Result<impl Reply, Rejection> {
         return Err(warp::reject::custom(ForcedErrorCode { code }));
     }
 
-    let funds: Vec<Fund> = context.lock().unwrap().state().vit().funds().to_vec();
+    let fund: Fund = context
+        .lock()
+        .unwrap()
+        .state()
+        .current_fund()
+        .cloned()
+        .ok_or_else(|| warp::reject::custom(VitStateError::NoFundDefined))?;
 
-    Ok(HandlerResult(Ok(funds.first().unwrap().clone())))
+    Ok(HandlerResult(Ok(fund)))
 }
 
 pub async fn get_settings(context: ContextLock) -> Result<impl Reply, Rejection> {
@@ -784,10 +1813,35 @@ pub async fn get_settings(context: ContextLock) -> Result<impl Reply, Rejection>
     Ok(HandlerResult(Ok(settings)))
 }
 
-fn parse_account_id(id_hex: &str) -> Identifier {
+fn parse_account_id(id_hex: &str) -> Result<Identifier, Rejection> {
     PublicKey::<AccountAlg>::from_str(id_hex)
         .map(Into::into)
-        .unwrap()
+        .map_err(|_| {
+            warp::reject::custom(crate::mock::mock_state::Error::InvalidAccountId(
+                id_hex.to_string(),
+            ))
+        })
+}
+
+pub async fn get_account_votes(
+    account_id: String,
+    context: ContextLock,
+) -> Result<impl Reply, Rejection> {
+    let id = parse_account_id(&account_id)?;
+    let mut context = context.lock().unwrap();
+    context.log(format!("get_account_votes {}...", &account_id));
+
+    if !context.available() {
+        let code = context.state().error_code;
+        context.log(&format!(
+            "unavailability mode is on. Rejecting with error code: {}",
+            code
+        ));
+        return Err(warp::reject::custom(ForcedErrorCode { code }));
+    }
+
+    let votes = context.state().ledger().account_votes(&id);
+    Ok(HandlerResult(Ok(votes)))
 }
 
 pub async fn get_account(
@@ -798,6 +1852,7 @@ pub async fn get_account(
         .lock()
         .unwrap()
         .log(format!("get_account {}...", &account_bech32));
+    let id = parse_account_id(&account_bech32)?;
 
     if !context.lock().unwrap().available() {
         let code = context.lock().unwrap().state().error_code;
@@ -812,10 +1867,9 @@ pub async fn get_account(
         .unwrap()
         .state()
         .ledger()
-        .accounts()
-        .get_state(&parse_account_id(&account_bech32))
-        .map(Into::into)
-        .map_err(|_| AccountDoesNotExist);
+        .account_state(&id)
+        .map(|state| (&state).into())
+        .ok_or(AccountDoesNotExist);
 
     Ok(HandlerResult(Ok(account_state?)))
 }
@@ -831,17 +1885,131 @@ struct ForcedErrorCode {
 
 impl warp::reject::Reject for ForcedErrorCode {}
 
-async fn report_invalid(r: Rejection) -> Result<impl Reply, Infallible> {
+#[derive(Debug)]
+struct InjectedFaultRejection {
+    pub fault: Fault,
+}
+
+impl warp::reject::Reject for InjectedFaultRejection {}
+
+impl InjectedFaultRejection {
+    fn to_response(&self) -> warp::reply::Response {
+        match &self.fault {
+            Fault::StatusCode(code) => warp::reply::with_status(
+                "injected fault".to_string(),
+                StatusCode::from_u16(*code).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR),
+            )
+            .into_response(),
+            Fault::MalformedBody => {
+                warp::reply::with_status("{\"malformed\":".to_string(), StatusCode::OK)
+                    .into_response()
+            }
+            Fault::TooManyRequests(retry_after) => {
+                let reply = warp::reply::with_status(
+                    "too many requests".to_string(),
+                    StatusCode::TOO_MANY_REQUESTS,
+                );
+                match retry_after {
+                    Some(secs) => warp::reply::with_header(reply, "Retry-After", secs.to_string())
+                        .into_response(),
+                    None => reply.into_response(),
+                }
+            }
+            Fault::Timeout(_) | Fault::Latency(_) => warp::reply::with_status(
+                "injected timeout".to_string(),
+                StatusCode::GATEWAY_TIMEOUT,
+            )
+            .into_response(),
+        }
+    }
+}
+
+pub async fn inject_faults(
+    method: warp::http::Method,
+    path: warp::path::FullPath,
+    context: ContextLock,
+) -> Result<(), Rejection> {
+    let injected = {
+        let mut context = context.lock().unwrap();
+        let injected = context.faults_mut().check(method.as_str(), path.as_str());
+        if let Some((id, fault)) = &injected {
+            context.metrics().fault_injected(fault);
+            context.log(format!(
+                "fault rule {} triggered for {} {}: {:?}",
+                id,
+                method,
+                path.as_str(),
+                fault
+            ));
+        }
+        injected
+    };
+
+    match injected {
+        None => Ok(()),
+        Some((_, InjectedFault::Delay(delay))) => {
+            tokio::time::sleep(delay).await;
+            Ok(())
+        }
+        Some((_, InjectedFault::Respond(fault))) => {
+            if let Fault::Timeout(timeout) = &fault {
+                match timeout {
+                    Some(secs) => tokio::time::sleep(Duration::from_secs(*secs)).await,
+                    None => std::future::pending::<()>().await,
+                }
+            }
+            Err(warp::reject::custom(InjectedFaultRejection { fault }))
+        }
+    }
+}
+
+async fn report_invalid(r: Rejection) -> Result<warp::reply::Response, Infallible> {
+    if let Some(injected_fault) = r.find::<InjectedFaultRejection>() {
+        return Ok(injected_fault.to_response());
+    }
     if let Some(forced_error_code) = r.find::<ForcedErrorCode>() {
         return Ok(warp::reply::with_status(
             "forced rejections".to_string(),
             StatusCode::from_u16(forced_error_code.code).unwrap(),
-        ));
+        )
+        .into_response());
+    }
+    if let Some(fault_error) = r.find::<crate::mock::fault::Error>() {
+        return Ok(
+            warp::reply::with_status(fault_error.to_string(), StatusCode::BAD_REQUEST)
+                .into_response(),
+        );
+    }
+    if let Some(clock_error) = r.find::<crate::mock::clock::Error>() {
+        return Ok(
+            warp::reply::with_status(clock_error.to_string(), StatusCode::BAD_REQUEST)
+                .into_response(),
+        );
+    }
+    if let Some(vit_error) = r.find::<crate::mock::vit_state::Error>() {
+        let status = match vit_error {
+            crate::mock::vit_state::Error::FundNotFound(_)
+            | crate::mock::vit_state::Error::ChallengeNotFound(_)
+            | crate::mock::vit_state::Error::ProposalNotFound(_)
+            | crate::mock::vit_state::Error::NoFundDefined => StatusCode::NOT_FOUND,
+            crate::mock::vit_state::Error::InvalidVoteOptions(_) => StatusCode::BAD_REQUEST,
+            _ => StatusCode::CONFLICT,
+        };
+        return Ok(warp::reply::with_status(vit_error.to_string(), status).into_response());
+    }
+    if let Some(state_error) = r.find::<crate::mock::mock_state::Error>() {
+        let status = match state_error {
+            crate::mock::mock_state::Error::AccountNotFound(_) => StatusCode::NOT_FOUND,
+            crate::mock::mock_state::Error::AccountAlreadyExists(_) => StatusCode::CONFLICT,
+            _ => StatusCode::BAD_REQUEST,
+        };
+        return Ok(warp::reply::with_status(state_error.to_string(), status).into_response());
     }
     Ok(warp::reply::with_status(
         format!("internal error: {:?}", r),
         StatusCode::INTERNAL_SERVER_ERROR,
-    ))
+    )
+    .into_response())
 }
 
 pub async fn authorize_token(
@@ -0,0 +1,158 @@
+use super::config::Tls;
+use rcgen::{BasicConstraints, Certificate, CertificateParams, DnType, IsCa, KeyPair, RcgenError};
+use std::io::BufReader;
+use std::path::Path;
+use thiserror::Error;
+use tokio_rustls::rustls::internal::pemfile::{certs, pkcs8_private_keys, rsa_private_keys};
+use tokio_rustls::rustls::{NoClientAuth, ServerConfig, TLSError};
+
+pub const CA_CERT: &str = "ca.crt";
+pub const CA_KEY: &str = "ca.key";
+pub const SERVER_CERT: &str = "server.crt";
+pub const SERVER_KEY: &str = "server.key";
+
+/// Server certificate and key in pem format. Generated certificate is signed by
+/// generated CA, which needs to be trusted by clients
+pub struct Certificates {
+    ca: Option<CaCertificate>,
+    cert: String,
+    key: String,
+}
+
+/// CA certificate and its key in pem format
+struct CaCertificate {
+    cert: String,
+    key: String,
+}
+
+impl CaCertificate {
+    fn params() -> CertificateParams {
+        let mut params = CertificateParams::new(Vec::new());
+        params.is_ca = IsCa::Ca(BasicConstraints::Unconstrained);
+        params
+            .distinguished_name
+            .push(DnType::CommonName, "vitup mock CA");
+        params
+    }
+
+    fn generate() -> Result<(Self, Certificate), Error> {
+        let ca = Certificate::from_params(Self::params())?;
+        Ok((
+            Self {
+                cert: ca.serialize_pem()?,
+                key: ca.serialize_private_key_pem(),
+            },
+            ca,
+        ))
+    }
+
+    /// Loads CA written by previous run, so clients which already trust it
+    /// do not need to install new one. Signer is rebuilt from the same name and key,
+    /// therefore certificates signed by it are verified by the original CA certificate
+    fn load(dir: &Path) -> Result<Option<(Self, Certificate)>, Error> {
+        let (cert_path, key_path) = (dir.join(CA_CERT), dir.join(CA_KEY));
+        if !cert_path.exists() || !key_path.exists() {
+            return Ok(None);
+        }
+        let ca = Self {
+            cert: std::fs::read_to_string(cert_path)?,
+            key: std::fs::read_to_string(key_path)?,
+        };
+        let mut params = Self::params();
+        params.key_pair = Some(KeyPair::from_pem(&ca.key)?);
+        let signer = Certificate::from_params(params)?;
+        Ok(Some((ca, signer)))
+    }
+}
+
+impl Certificates {
+    /// Loads certificates from configuration or generates them. Generated CA is reused
+    /// if it was already written into `dir`
+    pub fn from_config<P: AsRef<Path>>(tls: &Tls, dir: P) -> Result<Self, Error> {
+        match (&tls.cert, &tls.key) {
+            (Some(cert), Some(key)) => Self::load(cert, key),
+            (None, None) => Self::generate(&tls.hosts, dir),
+            _ => Err(Error::IncompleteConfiguration),
+        }
+    }
+
+    pub fn load<P: AsRef<Path>, Q: AsRef<Path>>(cert: P, key: Q) -> Result<Self, Error> {
+        Ok(Self {
+            ca: None,
+            cert: std::fs::read_to_string(cert)?,
+            key: std::fs::read_to_string(key)?,
+        })
+    }
+
+    /// Generates server certificate valid for localhost and given host names. It is signed
+    /// by CA from `dir` or by a new one when there is none
+    pub fn generate<P: AsRef<Path>>(hosts: &[String], dir: P) -> Result<Self, Error> {
+        let (ca, signer) = match CaCertificate::load(dir.as_ref())? {
+            Some(existing) => existing,
+            None => CaCertificate::generate()?,
+        };
+
+        let mut subject_alt_names = vec!["localhost".to_string()];
+        subject_alt_names.extend(hosts.iter().cloned());
+        let mut server_params = CertificateParams::new(subject_alt_names);
+        server_params
+            .distinguished_name
+            .push(DnType::CommonName, "vitup mock");
+        let server = Certificate::from_params(server_params)?;
+
+        Ok(Self {
+            cert: server.serialize_pem_with_signer(&signer)?,
+            key: server.serialize_private_key_pem(),
+            ca: Some(ca),
+        })
+    }
+
+    pub fn is_generated(&self) -> bool {
+        self.ca.is_some()
+    }
+
+    pub fn write<P: AsRef<Path>>(&self, dir: P) -> Result<(), Error> {
+        let dir = dir.as_ref();
+        std::fs::create_dir_all(dir)?;
+        if let Some(ca) = &self.ca {
+            std::fs::write(dir.join(CA_CERT), &ca.cert)?;
+            std::fs::write(dir.join(CA_KEY), &ca.key)?;
+        }
+        std::fs::write(dir.join(SERVER_CERT), &self.cert)?;
+        std::fs::write(dir.join(SERVER_KEY), &self.key)?;
+        Ok(())
+    }
+
+    pub fn server_config(&self) -> Result<ServerConfig, Error> {
+        let certs = certs(&mut BufReader::new(self.cert.as_bytes()))
+            .map_err(|_| Error::InvalidCertificate)?;
+
+        let mut keys = pkcs8_private_keys(&mut BufReader::new(self.key.as_bytes()))
+            .map_err(|_| Error::InvalidKey)?;
+        if keys.is_empty() {
+            keys = rsa_private_keys(&mut BufReader::new(self.key.as_bytes()))
+                .map_err(|_| Error::InvalidKey)?;
+        }
+        let key = keys.into_iter().next().ok_or(Error::InvalidKey)?;
+
+        let mut config = ServerConfig::new(NoClientAuth::new());
+        config.set_single_cert(certs, key)?;
+        Ok(config)
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("both certificate and key need to be defined, or none of them to generate them")]
+    IncompleteConfiguration,
+    #[error("cannot read or write certificate")]
+    IoError(#[from] std::io::Error),
+    #[error("cannot generate certificate")]
+    RcgenError(#[from] RcgenError),
+    #[error("cannot parse certificate")]
+    InvalidCertificate,
+    #[error("cannot parse private key")]
+    InvalidKey,
+    #[error("invalid tls configuration")]
+    TlsError(#[from] TLSError),
+}
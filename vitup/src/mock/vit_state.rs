@@ -0,0 +1,323 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use vit_servicing_station_lib::db::models::challenges::Challenge;
+use vit_servicing_station_lib::db::models::funds::Fund;
+use vit_servicing_station_lib::db::models::proposals::FullProposalInfo;
+use vit_servicing_station_lib::db::models::vote_options::VoteOptions;
+use vit_servicing_station_tests::common::data::Snapshot;
+
+/// Maximum number of vote options supported by a proposal on chain
+const MAX_VOTE_OPTIONS: usize = u8::MAX as usize;
+
+/// Fund timestamps which can be changed independently. Undefined fields are not modified
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct FundTimestamps {
+    #[serde(default)]
+    pub fund_start_time: Option<i64>,
+    #[serde(default)]
+    pub fund_end_time: Option<i64>,
+    #[serde(default)]
+    pub next_fund_start_time: Option<i64>,
+    #[serde(default)]
+    pub registration_snapshot_time: Option<i64>,
+}
+
+/// Runtime modifications of vit data. Relations between funds, challenges and proposals
+/// are kept consistent: removing fund removes its challenges and proposals, changing
+/// fund or challenge id updates all entities which refer to it
+pub struct VitStateMutator<'a> {
+    snapshot: &'a mut Snapshot,
+}
+
+impl<'a> VitStateMutator<'a> {
+    pub fn new(snapshot: &'a mut Snapshot) -> Self {
+        Self { snapshot }
+    }
+
+    pub fn add_fund(&mut self, fund: Fund) -> Result<(), Error> {
+        if self.fund_exists(fund.id) {
+            return Err(Error::FundAlreadyExists(fund.id));
+        }
+        self.snapshot.funds_mut().push(fund);
+        self.sync();
+        Ok(())
+    }
+
+    pub fn update_fund(&mut self, id: i32, fund: Fund) -> Result<(), Error> {
+        if !self.fund_exists(id) {
+            return Err(Error::FundNotFound(id));
+        }
+        if fund.id != id && self.fund_exists(fund.id) {
+            return Err(Error::FundAlreadyExists(fund.id));
+        }
+
+        for challenge in self.snapshot.challenges_mut() {
+            if challenge.fund_id == id {
+                challenge.fund_id = fund.id;
+            }
+        }
+        for existing in self.snapshot.funds_mut() {
+            if existing.id == id {
+                *existing = fund.clone();
+            }
+        }
+        self.sync();
+        Ok(())
+    }
+
+    /// Changes id of current fund (the one with the highest id, which is served as `/api/v0/fund`)
+    pub fn set_current_fund_id(&mut self, id: i32) -> Result<(), Error> {
+        let mut fund = current_fund(self.snapshot)
+            .cloned()
+            .ok_or(Error::NoFundDefined)?;
+        let current_id = fund.id;
+        fund.id = id;
+        self.update_fund(current_id, fund)
+    }
+
+    pub fn update_fund_timestamps(
+        &mut self,
+        id: i32,
+        timestamps: FundTimestamps,
+    ) -> Result<(), Error> {
+        let fund = self
+            .snapshot
+            .funds_mut()
+            .iter_mut()
+            .find(|fund| fund.id == id)
+            .ok_or(Error::FundNotFound(id))?;
+
+        if let Some(fund_start_time) = timestamps.fund_start_time {
+            fund.fund_start_time = fund_start_time;
+        }
+        if let Some(fund_end_time) = timestamps.fund_end_time {
+            fund.fund_end_time = fund_end_time;
+        }
+        if let Some(next_fund_start_time) = timestamps.next_fund_start_time {
+            fund.next_fund_start_time = next_fund_start_time;
+        }
+        if let Some(registration_snapshot_time) = timestamps.registration_snapshot_time {
+            fund.registration_snapshot_time = registration_snapshot_time;
+        }
+        Ok(())
+    }
+
+    pub fn remove_fund(&mut self, id: i32) -> Result<(), Error> {
+        if !self.fund_exists(id) {
+            return Err(Error::FundNotFound(id));
+        }
+        self.snapshot.funds_mut().retain(|fund| fund.id != id);
+        self.snapshot
+            .challenges_mut()
+            .retain(|challenge| challenge.fund_id != id);
+        self.sync();
+        Ok(())
+    }
+
+    pub fn add_challenge(&mut self, challenge: Challenge) -> Result<(), Error> {
+        if self.challenge_exists(challenge.id) {
+            return Err(Error::ChallengeAlreadyExists(challenge.id));
+        }
+        if !self.fund_exists(challenge.fund_id) {
+            return Err(Error::FundNotFound(challenge.fund_id));
+        }
+        self.snapshot.challenges_mut().push(challenge);
+        self.sync();
+        Ok(())
+    }
+
+    pub fn update_challenge(&mut self, id: i32, challenge: Challenge) -> Result<(), Error> {
+        if !self.challenge_exists(id) {
+            return Err(Error::ChallengeNotFound(id));
+        }
+        if challenge.id != id && self.challenge_exists(challenge.id) {
+            return Err(Error::ChallengeAlreadyExists(challenge.id));
+        }
+        if !self.fund_exists(challenge.fund_id) {
+            return Err(Error::FundNotFound(challenge.fund_id));
+        }
+
+        for proposal in self.snapshot.proposals_mut() {
+            if proposal.proposal.challenge_id == id {
+                proposal.proposal.challenge_id = challenge.id;
+            }
+        }
+        for existing in self.snapshot.challenges_mut() {
+            if existing.id == id {
+                *existing = challenge.clone();
+            }
+        }
+        self.sync();
+        Ok(())
+    }
+
+    pub fn remove_challenge(&mut self, id: i32) -> Result<(), Error> {
+        if !self.challenge_exists(id) {
+            return Err(Error::ChallengeNotFound(id));
+        }
+        self.snapshot
+            .challenges_mut()
+            .retain(|challenge| challenge.id != id);
+        self.sync();
+        Ok(())
+    }
+
+    pub fn add_proposal(&mut self, proposal: FullProposalInfo) -> Result<(), Error> {
+        let id = proposal.proposal.internal_id;
+        if self.proposal_exists(id) {
+            return Err(Error::ProposalAlreadyExists(id));
+        }
+        if !self.challenge_exists(proposal.proposal.challenge_id) {
+            return Err(Error::ChallengeNotFound(proposal.proposal.challenge_id));
+        }
+        self.snapshot.proposals_mut().push(proposal);
+        self.sync();
+        Ok(())
+    }
+
+    pub fn update_proposal(&mut self, id: i32, proposal: FullProposalInfo) -> Result<(), Error> {
+        if !self.proposal_exists(id) {
+            return Err(Error::ProposalNotFound(id));
+        }
+        let new_id = proposal.proposal.internal_id;
+        if new_id != id && self.proposal_exists(new_id) {
+            return Err(Error::ProposalAlreadyExists(new_id));
+        }
+        if !self.challenge_exists(proposal.proposal.challenge_id) {
+            return Err(Error::ChallengeNotFound(proposal.proposal.challenge_id));
+        }
+
+        for existing in self.snapshot.proposals_mut() {
+            if existing.proposal.internal_id == id {
+                *existing = proposal.clone();
+            }
+        }
+        self.sync();
+        Ok(())
+    }
+
+    pub fn update_vote_options(&mut self, id: i32, options: Vec<String>) -> Result<(), Error> {
+        validate_vote_options(&options)?;
+        let proposal = self
+            .snapshot
+            .proposals_mut()
+            .iter_mut()
+            .find(|proposal| proposal.proposal.internal_id == id)
+            .ok_or(Error::ProposalNotFound(id))?;
+        proposal.proposal.chain_vote_options =
+            VoteOptions::parse_coma_separated_value(&options.join(","));
+        self.sync();
+        Ok(())
+    }
+
+    pub fn remove_proposal(&mut self, id: i32) -> Result<(), Error> {
+        if !self.proposal_exists(id) {
+            return Err(Error::ProposalNotFound(id));
+        }
+        self.snapshot
+            .proposals_mut()
+            .retain(|proposal| proposal.proposal.internal_id != id);
+        self.sync();
+        Ok(())
+    }
+
+    fn fund_exists(&self, id: i32) -> bool {
+        self.snapshot.funds().iter().any(|fund| fund.id == id)
+    }
+
+    fn challenge_exists(&self, id: i32) -> bool {
+        self.snapshot
+            .challenges()
+            .iter()
+            .any(|challenge| challenge.id == id)
+    }
+
+    fn proposal_exists(&self, id: i32) -> bool {
+        self.snapshot
+            .proposals()
+            .iter()
+            .any(|proposal| proposal.proposal.internal_id == id)
+    }
+
+    /// Restores relations after modification: removes proposals of removed challenges,
+    /// copies fund id from challenge to its proposals and rebuilds challenges and
+    /// vote plans lists embedded in funds
+    fn sync(&mut self) {
+        let challenges = self.snapshot.challenges();
+
+        self.snapshot.proposals_mut().retain(|proposal| {
+            challenges
+                .iter()
+                .any(|challenge| challenge.id == proposal.proposal.challenge_id)
+        });
+        for proposal in self.snapshot.proposals_mut() {
+            if let Some(challenge) = challenges
+                .iter()
+                .find(|challenge| challenge.id == proposal.proposal.challenge_id)
+            {
+                proposal.proposal.fund_id = challenge.fund_id;
+            }
+        }
+
+        for fund in self.snapshot.funds_mut() {
+            fund.challenges = challenges
+                .iter()
+                .filter(|challenge| challenge.fund_id == fund.id)
+                .cloned()
+                .collect();
+            for vote_plan in fund.chain_vote_plans.iter_mut() {
+                vote_plan.fund_id = fund.id;
+            }
+        }
+    }
+}
+
+/// When there are many funds, the one with the highest id is the current one
+pub fn current_fund(snapshot: &Snapshot) -> Option<&Fund> {
+    snapshot.funds().iter().max_by_key(|fund| fund.id)
+}
+
+fn validate_vote_options(options: &[String]) -> Result<(), Error> {
+    if options.is_empty() || options.len() > MAX_VOTE_OPTIONS {
+        return Err(Error::InvalidVoteOptions(format!(
+            "expected from 1 to {} options, got {}",
+            MAX_VOTE_OPTIONS,
+            options.len()
+        )));
+    }
+    for (index, option) in options.iter().enumerate() {
+        if option.trim().is_empty() || option.contains(',') {
+            return Err(Error::InvalidVoteOptions(format!(
+                "option '{}' should be non empty and cannot contain ','",
+                option
+            )));
+        }
+        if options[..index].contains(option) {
+            return Err(Error::InvalidVoteOptions(format!(
+                "option '{}' is duplicated",
+                option
+            )));
+        }
+    }
+    Ok(())
+}
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("fund {0} does not exist")]
+    FundNotFound(i32),
+    #[error("fund {0} already exists")]
+    FundAlreadyExists(i32),
+    #[error("challenge {0} does not exist")]
+    ChallengeNotFound(i32),
+    #[error("challenge {0} already exists")]
+    ChallengeAlreadyExists(i32),
+    #[error("proposal {0} does not exist")]
+    ProposalNotFound(i32),
+    #[error("proposal {0} already exists")]
+    ProposalAlreadyExists(i32),
+    #[error("there is no fund defined")]
+    NoFundDefined,
+    #[error("invalid vote options: {0}")]
+    InvalidVoteOptions(String),
+}
@@ -0,0 +1,259 @@
+use chain_impl_mockchain::certificate::{DecryptedPrivateTally, DecryptedPrivateTallyProposal};
+use chain_vote::committee::{
+    ElectionPublicKey, MemberCommunicationKey, MemberPublicKey, MemberSecretKey, MemberState,
+};
+use chain_vote::{Crs, EncryptedTally, TallyOptimizationTable};
+use jormungandr_lib::interfaces::{PrivateTallyState, Tally, VotePlanStatus};
+use jormungandr_testing_utils::wallet::ElectionPublicKeyExtension;
+use rand_core::{CryptoRng, RngCore};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use thiserror::Error;
+
+/// Directory (in environment directory) with keys of private committee members
+pub const COMMITTEE_DIRECTORY: &str = "committees";
+const COMMITTEE_FILE: &str = "committee.json";
+const COMMUNICATION_KEY_FILE: &str = "communication_key.sk";
+const MEMBER_SECRET_KEY_FILE: &str = "member_secret_key.sk";
+const MEMBER_PUBLIC_KEY_FILE: &str = "member_public_key.pk";
+
+/// Committee of private vote plans. Election key is derived from public keys of all members.
+/// Secret keys are known only for members which keys were generated or read from disk
+pub struct PrivateCommittee {
+    threshold: usize,
+    public_keys: Vec<MemberPublicKey>,
+    secret_keys: Vec<Option<MemberSecretKey>>,
+    communication_keys: Vec<Option<MemberCommunicationKey>>,
+}
+
+/// Public part of committee, written next to members keys
+#[derive(Debug, Deserialize, Serialize)]
+struct CommitteeFile {
+    threshold: usize,
+    members: Vec<String>,
+    election_key: String,
+}
+
+/// Decrypted results of single proposal
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ProposalResult {
+    pub index: usize,
+    pub results: Vec<u64>,
+}
+
+impl PrivateCommittee {
+    /// Generates communication keys of all members, then member keys which depend on
+    /// communication keys of whole committee. Shares of all members are required to decrypt
+    /// tally, so threshold is equal to members count
+    pub fn generate<R: RngCore + CryptoRng>(rng: &mut R, crs_seed: &[u8], members: usize) -> Self {
+        let threshold = members;
+        let crs = Crs::from_hash(crs_seed);
+        let communication_keys: Vec<MemberCommunicationKey> = (0..members)
+            .map(|_| MemberCommunicationKey::new(rng))
+            .collect();
+        let communication_public_keys: Vec<_> = communication_keys
+            .iter()
+            .map(|key| key.to_public())
+            .collect();
+        let states: Vec<MemberState> = (0..members)
+            .map(|index| MemberState::new(rng, threshold, &crs, &communication_public_keys, index))
+            .collect();
+
+        Self {
+            threshold,
+            public_keys: states.iter().map(|state| state.public_key()).collect(),
+            secret_keys: states
+                .iter()
+                .map(|state| Some(state.secret_key().clone()))
+                .collect(),
+            communication_keys: communication_keys.into_iter().map(Some).collect(),
+        }
+    }
+
+    pub fn member_public_keys(&self) -> Vec<MemberPublicKey> {
+        self.public_keys.clone()
+    }
+
+    pub fn election_public_key(&self) -> ElectionPublicKey {
+        ElectionPublicKey::from_participants(&self.public_keys)
+    }
+
+    /// Writes `committee.json` with threshold and public keys and `member_{i}` directory
+    /// with communication and member keys (hex encoded) for every member
+    pub fn write_to<P: AsRef<Path>>(&self, directory: P) -> Result<(), Error> {
+        let directory = directory.as_ref();
+        std::fs::create_dir_all(directory)?;
+
+        let committee = CommitteeFile {
+            threshold: self.threshold,
+            members: self
+                .public_keys
+                .iter()
+                .map(|key| hex::encode(key.to_bytes()))
+                .collect(),
+            election_key: self.election_public_key().to_base32().unwrap(),
+        };
+        std::fs::write(
+            directory.join(COMMITTEE_FILE),
+            serde_json::to_string_pretty(&committee)?,
+        )?;
+
+        for (index, public_key) in self.public_keys.iter().enumerate() {
+            let member_directory = directory.join(format!("member_{}", index));
+            std::fs::create_dir_all(&member_directory)?;
+            std::fs::write(
+                member_directory.join(MEMBER_PUBLIC_KEY_FILE),
+                hex::encode(public_key.to_bytes()),
+            )?;
+            if let Some(secret_key) = &self.secret_keys[index] {
+                std::fs::write(
+                    member_directory.join(MEMBER_SECRET_KEY_FILE),
+                    hex::encode(secret_key.to_bytes()),
+                )?;
+            }
+            if let Some(communication_key) = &self.communication_keys[index] {
+                std::fs::write(
+                    member_directory.join(COMMUNICATION_KEY_FILE),
+                    hex::encode(communication_key.to_bytes()),
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads committee written with [`PrivateCommittee::write_to`]. Member directories
+    /// without secret key are allowed, but such committee cannot decrypt tally
+    pub fn read_from<P: AsRef<Path>>(directory: P) -> Result<Self, Error> {
+        let directory = directory.as_ref();
+        let committee: CommitteeFile =
+            serde_json::from_str(&std::fs::read_to_string(directory.join(COMMITTEE_FILE))?)?;
+
+        let public_keys = committee
+            .members
+            .iter()
+            .map(|key| {
+                hex::decode(key)
+                    .ok()
+                    .and_then(|bytes| MemberPublicKey::from_bytes(&bytes))
+                    .ok_or_else(|| Error::InvalidKey(key.clone()))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let secret_keys = (0..public_keys.len())
+            .map(|index| {
+                let path = directory
+                    .join(format!("member_{}", index))
+                    .join(MEMBER_SECRET_KEY_FILE);
+                if !path.exists() {
+                    return Ok(None);
+                }
+                let content = std::fs::read_to_string(&path)?;
+                hex::decode(content.trim())
+                    .ok()
+                    .and_then(|bytes| MemberSecretKey::from_bytes(&bytes))
+                    .map(Some)
+                    .ok_or_else(|| Error::InvalidKey(path.display().to_string()))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        Ok(Self {
+            threshold: committee.threshold,
+            communication_keys: public_keys.iter().map(|_| None).collect(),
+            public_keys,
+            secret_keys,
+        })
+    }
+
+    /// Decrypts tally of vote plan status fetched from node. Decrypted tally covers all
+    /// proposals of vote plan, so tally of every proposal has to be encrypted already
+    pub fn decrypt_vote_plan<R: RngCore + CryptoRng>(
+        &self,
+        rng: &mut R,
+        vote_plan_status: &VotePlanStatus,
+    ) -> Result<(DecryptedPrivateTally, Vec<ProposalResult>), Error> {
+        let mut proposals: Vec<_> = vote_plan_status.proposals.iter().collect();
+        proposals.sort_by_key(|proposal| proposal.index);
+
+        let encrypted_tallies = proposals
+            .into_iter()
+            .map(|proposal| match &proposal.tally {
+                Some(Tally::Private {
+                    state:
+                        PrivateTallyState::Encrypted {
+                            encrypted_tally,
+                            total_stake,
+                        },
+                }) => Ok((
+                    encrypted_tally.clone().into_encrypted_tally(),
+                    (*total_stake).into(),
+                )),
+                _ => Err(Error::NotEncrypted {
+                    vote_plan: vote_plan_status.id.to_string(),
+                    index: proposal.index as usize,
+                }),
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        self.decrypt_tally(rng, encrypted_tallies)
+    }
+
+    /// Collects decryption shares of all members and decrypts tally of every proposal
+    /// of vote plan. Encrypted tallies of all proposals are given in proposals order
+    /// (position is proposal index), together with total stake which bounds results.
+    /// Note that chain-vote requires shares of all members to decrypt tally
+    pub fn decrypt_tally<R: RngCore + CryptoRng>(
+        &self,
+        rng: &mut R,
+        encrypted_tallies: Vec<(EncryptedTally, u64)>,
+    ) -> Result<(DecryptedPrivateTally, Vec<ProposalResult>), Error> {
+        let secret_keys: Vec<&MemberSecretKey> = self.secret_keys.iter().flatten().collect();
+        if secret_keys.len() < self.public_keys.len() {
+            return Err(Error::MissingMemberKeys {
+                available: secret_keys.len(),
+                members: self.public_keys.len(),
+            });
+        }
+
+        let mut proposals = Vec::new();
+        let mut results = Vec::new();
+        for (index, (encrypted_tally, total_stake)) in encrypted_tallies.into_iter().enumerate() {
+            let shares: Vec<_> = secret_keys
+                .iter()
+                .map(|secret_key| encrypted_tally.partial_decrypt(rng, secret_key))
+                .collect();
+            let table = TallyOptimizationTable::generate_with_balance(total_stake, 1);
+            let tally = encrypted_tally
+                .validate_partial_decryptions(&self.public_keys, &shares)
+                .and_then(|validated| validated.decrypt_tally(total_stake, &table))
+                .map_err(|err| Error::Decryption(format!("{:?}", err)))?;
+
+            results.push(ProposalResult {
+                index,
+                results: tally.votes.clone(),
+            });
+            proposals.push(DecryptedPrivateTallyProposal {
+                decrypt_shares: shares.into_boxed_slice(),
+                tally_result: tally.votes.into_boxed_slice(),
+            });
+        }
+
+        let decrypted = DecryptedPrivateTally::new(proposals)
+            .map_err(|err| Error::Decryption(format!("{:?}", err)))?;
+        Ok((decrypted, results))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("cannot read or write committee keys")]
+    Io(#[from] std::io::Error),
+    #[error("cannot serialize committee")]
+    Serde(#[from] serde_json::Error),
+    #[error("invalid committee key: {0}")]
+    InvalidKey(String),
+    #[error("secret keys of {available} out of {members} committee members are available, all are required to decrypt tally")]
+    MissingMemberKeys { available: usize, members: usize },
+    #[error("cannot decrypt tally: {0}")]
+    Decryption(String),
+    #[error("tally of proposal #{index} in vote plan {vote_plan} is not encrypted")]
+    NotEncrypted { vote_plan: String, index: usize },
+}
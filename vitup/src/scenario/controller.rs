@@ -1,15 +1,23 @@
 use crate::scenario::{
+    manifest::Manifest,
     settings::VitSettings,
-    vit_station::{VitStation, VitStationController, VitStationControllerError},
+    vit_station::{
+        DbGenerator, VitStation, VitStationController, VitStationControllerError,
+        VoteOptionsTemplateGenerator, VotePlanDetails,
+    },
     wallet::{
         Error as WalletProxyError, WalletProxy, WalletProxyController, WalletProxySpawnParams,
     },
 };
-use crate::{error::ErrorKind, Result};
+use crate::{
+    config::{TopologyConfig, VitStartParameters},
+    error::ErrorKind,
+    Result,
+};
 use iapyx::WalletBackend;
 use indicatif::ProgressBar;
 use jormungandr_scenario_tests::scenario::{ContextChaCha, Controller, ControllerBuilder};
-use jormungandr_testing_utils::testing::network_builder::{Blockchain, Topology};
+use jormungandr_testing_utils::testing::network_builder::Blockchain;
 use std::path::Path;
 use vit_servicing_station_tests::common::data::ValidVotePlanParameters;
 use vit_servicing_station_tests::common::data::ValidVotingTemplateGenerator;
@@ -17,10 +25,16 @@ use vit_servicing_station_tests::common::data::ValidVotingTemplateGenerator;
 pub struct VitControllerBuilder {
     controller_builder: ControllerBuilder,
     vit_settings: Option<VitSettings>,
+    topology: TopologyConfig,
+    parameters: VitStartParameters,
 }
 
 pub struct VitController {
     vit_settings: VitSettings,
+    topology: TopologyConfig,
+    parameters: VitStartParameters,
+    vote_plans: Vec<VotePlanDetails>,
+    manifest: Manifest,
 }
 
 impl VitControllerBuilder {
@@ -28,11 +42,19 @@ impl VitControllerBuilder {
         Self {
             controller_builder: ControllerBuilder::new(title),
             vit_settings: None,
+            topology: Default::default(),
+            parameters: Default::default(),
         }
     }
 
-    pub fn set_topology(&mut self, topology: Topology) {
-        self.controller_builder.set_topology(topology);
+    pub fn set_topology(&mut self, topology: TopologyConfig) {
+        self.controller_builder.set_topology(topology.build());
+        self.topology = topology;
+    }
+
+    /// Parameters used to build vote plans, needed to put matching proposals into vit station
+    pub fn set_parameters(&mut self, parameters: VitStartParameters) {
+        self.parameters = parameters;
     }
 
     pub fn set_blockchain(&mut self, blockchain: Blockchain) {
@@ -46,20 +68,48 @@ impl VitControllerBuilder {
 
     pub fn build_controllers(self, context: ContextChaCha) -> Result<(VitController, Controller)> {
         let controller = self.controller_builder.build(context)?;
-        let vit_controller = VitController::new(self.vit_settings.unwrap());
+        let mut vit_controller = VitController::new(self.vit_settings.unwrap(), self.topology);
+        vit_controller.parameters = self.parameters;
         Ok((vit_controller, controller))
     }
 }
 
 impl VitController {
-    pub fn new(vit_settings: VitSettings) -> Self {
-        Self { vit_settings }
+    pub fn new(vit_settings: VitSettings, topology: TopologyConfig) -> Self {
+        Self {
+            vit_settings,
+            topology,
+            parameters: Default::default(),
+            vote_plans: Vec::new(),
+            manifest: Default::default(),
+        }
     }
 
     pub fn vit_settings(&self) -> &VitSettings {
         &self.vit_settings
     }
 
+    pub fn topology(&self) -> &TopologyConfig {
+        &self.topology
+    }
+
+    /// Vote plans which phases or payload type differ from fund settings
+    pub fn set_vote_plans_details(&mut self, vote_plans: Vec<VotePlanDetails>) {
+        self.vote_plans = vote_plans;
+    }
+
+    pub fn set_manifest(&mut self, manifest: Manifest) {
+        self.manifest = manifest;
+    }
+
+    pub fn manifest(&self) -> &Manifest {
+        &self.manifest
+    }
+
+    pub fn manifest_mut(&mut self) -> &mut Manifest {
+        &mut self.manifest
+    }
+
     /// iapyx wallet is a mock mobile wallet
     /// it uses some production code while handling wallet operation
     // therefore controller has separate method to build such wallet
@@ -128,10 +178,15 @@ impl VitController {
         let block0_file = controller.block0_file();
         let working_directory = controller.working_directory().path();
 
+        let mut template_generator =
+            VoteOptionsTemplateGenerator::new(template_generator, &self.parameters);
+        let db_generator =
+            DbGenerator::new(vote_plan_parameters).with_vote_plans(self.vote_plans.clone());
+
         let vit_station = VitStation::spawn(
             controller.context(),
-            vote_plan_parameters,
-            template_generator,
+            db_generator,
+            &mut template_generator,
             pb,
             alias,
             settings.clone(),
@@ -0,0 +1,100 @@
+use crate::scenario::vit_station::VotePlanDetails;
+use chain_core::property::Block as _;
+use jormungandr_lib::interfaces::{Block0Configuration, CommitteeIdDef};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+use walkdir::WalkDir;
+
+pub const MANIFEST_FILE: &str = "manifest.json";
+
+/// Machine readable description of generated environment. It is written to `manifest.json`
+/// in environment directory and all paths are relative to that directory
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Manifest {
+    /// hex encoded seed used for wallets and keys generation
+    pub seed: String,
+    pub block0: Block0Manifest,
+    pub genesis: PathBuf,
+    pub fund_id: i32,
+    pub vote_plans: Vec<VotePlanDetails>,
+    pub committees: Vec<CommitteeIdDef>,
+    pub wallets: Vec<WalletManifest>,
+    pub nodes: Vec<NodeManifest>,
+    /// wallet proxy endpoint, defined only when backend is started
+    pub proxy: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Block0Manifest {
+    pub path: PathBuf,
+    pub hash: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct WalletManifest {
+    pub alias: String,
+    /// empty for committee wallets, which have no qr code
+    pub pin: String,
+    /// qr code image, not defined when qr generation is skipped
+    pub qr: Option<PathBuf>,
+    /// pin encrypted secret key, the same as encoded in qr code
+    pub qr_hash: Option<PathBuf>,
+    /// secret key file dumped by scenario
+    pub secret: Option<PathBuf>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct NodeManifest {
+    pub alias: String,
+    pub rest: String,
+    /// defined only for nodes with explorer enabled
+    pub explorer: Option<String>,
+}
+
+impl Manifest {
+    pub fn read<P: AsRef<Path>>(directory: P) -> Result<Self, Error> {
+        let content = std::fs::read_to_string(directory.as_ref().join(MANIFEST_FILE))?;
+        serde_json::from_str(&content).map_err(Into::into)
+    }
+
+    pub fn write<P: AsRef<Path>>(&self, directory: P) -> Result<PathBuf, Error> {
+        let path = directory.as_ref().join(MANIFEST_FILE);
+        std::fs::write(&path, serde_json::to_string_pretty(self)?)?;
+        Ok(path)
+    }
+
+    /// Block0 has to be updated when genesis is modified after environment is built
+    pub fn update_block0_hash(&mut self, block0: &Block0Configuration) {
+        self.block0.hash = block0.to_block().id().to_string();
+    }
+
+    /// Looks for wallet secret files (named by wallet alias) in environment directory.
+    /// Should be called again whenever secrets are moved
+    pub fn locate_secrets<P: AsRef<Path>>(&mut self, directory: P) {
+        let directory = directory.as_ref();
+        let files: Vec<PathBuf> = WalkDir::new(directory)
+            .into_iter()
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.file_type().is_file())
+            .filter_map(|entry| entry.path().strip_prefix(directory).ok().map(PathBuf::from))
+            .collect();
+
+        for wallet in self.wallets.iter_mut() {
+            wallet.secret = files
+                .iter()
+                .find(|file| {
+                    file.file_stem().and_then(|stem| stem.to_str()) == Some(wallet.alias.as_str())
+                })
+                .cloned();
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("cannot read or write manifest")]
+    Io(#[from] std::io::Error),
+    #[error("cannot serialize manifest")]
+    Serde(#[from] serde_json::Error),
+}
@@ -1,4 +1,6 @@
+pub mod committee;
 pub mod controller;
+pub mod manifest;
 pub mod network;
 pub mod settings;
 pub mod vit_station;
@@ -1,10 +1,10 @@
+use crate::config::TopologyConfig;
 use crate::interactive::VitInteractiveCommandExec;
 use crate::interactive::VitUserInteractionController;
 use crate::manager::{ControlContext, ControlContextLock, ManagerService, State};
 use crate::scenario::controller::VitController;
-use crate::setup::start::quick::{
-    QuickVitBackendSettingsBuilder, LEADER_1, LEADER_2, LEADER_3, LEADER_4, WALLET_NODE,
-};
+use crate::scenario::vit_station::SeededValidVotingTemplateGenerator;
+use crate::setup::start::quick::{QuickVitBackendSettingsBuilder, WALLET_NODE};
 use crate::vit_station::VitStationController;
 use crate::wallet::WalletProxyController;
 use crate::wallet::WalletProxySpawnParams;
@@ -14,20 +14,17 @@ use jormungandr_lib::interfaces::Explorer;
 use jormungandr_scenario_tests::interactive::UserInteractionController;
 use jormungandr_scenario_tests::scenario::Controller;
 use jormungandr_scenario_tests::NodeController;
-use jormungandr_scenario_tests::{
-    node::{LeadershipMode, PersistenceMode},
-    scenario::Context,
-};
-use jormungandr_testing_utils::testing::network_builder::SpawnParams;
+use jormungandr_scenario_tests::{node::PersistenceMode, scenario::Context};
+use jormungandr_testing_utils::testing::network_builder::{Seed, SpawnParams};
 use jortestkit::prelude::UserInteraction;
 use rand_chacha::ChaChaRng;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::sync::Mutex;
 use vit_servicing_station_tests::common::data::ValidVotePlanParameters;
 use vit_servicing_station_tests::common::data::ValidVotingTemplateGenerator;
 use vit_servicing_station_tests::common::data::{
-    ArbitraryValidVotingTemplateGenerator, ExternalValidVotingTemplateGenerator,
+    ExternalValidVotingTemplateGenerator, TemplateLoadError,
 };
 
 pub fn setup_network(
@@ -43,59 +40,36 @@ pub fn setup_network(
     VitStationController,
     WalletProxyController,
 )> {
-    println!("Spawning leader 1..");
-
-    // bootstrap network
-    let leader_1 = controller.spawn_node_custom(
-        SpawnParams::new(LEADER_1)
-            .leader()
-            .persistence_mode(PersistenceMode::Persistent)
-            .explorer(Explorer { enabled: true }),
-    )?;
-    leader_1.wait_for_bootstrap()?;
-    controller.monitor_nodes();
-
-    println!("Spawning leader 2..");
-
-    //start bft node 2
-    let leader_2 = controller.spawn_node(
-        LEADER_2,
-        LeadershipMode::Leader,
-        PersistenceMode::Persistent,
-    )?;
-    leader_2.wait_for_bootstrap()?;
-
-    println!("Spawning leader 3..");
-
-    //start bft node 3
-    let leader_3 = controller.spawn_node(
-        LEADER_3,
-        LeadershipMode::Leader,
-        PersistenceMode::Persistent,
-    )?;
-    leader_3.wait_for_bootstrap()?;
-
-    println!("Spawning leader 4..");
-
-    //start bft node 4
-    let leader_4 = controller.spawn_node(
-        LEADER_4,
-        LeadershipMode::Leader,
-        PersistenceMode::Persistent,
-    )?;
-    leader_4.wait_for_bootstrap()?;
-
-    println!("Spawning wallet node..");
+    let topology = vit_controller.topology().clone();
+    let mut nodes = Vec::new();
+
+    // bootstrap network, first leader is monitored
+    for (i, alias) in topology.leader_aliases().iter().enumerate() {
+        println!("Spawning {}..", alias);
+        let node = controller.spawn_node_custom(spawn_params(&topology, alias).leader())?;
+        node.wait_for_bootstrap()?;
+        if i == 0 {
+            controller.monitor_nodes();
+        }
+        nodes.push(node);
+    }
 
-    // start passive node
-    let wallet_node = controller.spawn_node_custom(
-        SpawnParams::new(WALLET_NODE)
-            .passive()
-            .persistence_mode(PersistenceMode::Persistent)
-            .explorer(Explorer { enabled: true })
-            .persistent_fragment_log(controller.working_directory().path().join("persistent_log")),
-    )?;
-    wallet_node.wait_for_bootstrap()?;
+    for alias in topology.wallet_node_aliases() {
+        println!("Spawning {}..", alias);
+        let persistent_log = match alias.as_str() {
+            WALLET_NODE => "persistent_log".to_string(),
+            alias => format!("persistent_log_{}", alias),
+        };
+        let node = controller.spawn_node_custom(
+            spawn_params(&topology, &alias)
+                .passive()
+                .persistent_fragment_log(
+                    controller.working_directory().path().join(persistent_log),
+                ),
+        )?;
+        node.wait_for_bootstrap()?;
+        nodes.push(node);
+    }
 
     println!("Spawning vit station..");
 
@@ -108,18 +82,35 @@ pub fn setup_network(
     )?;
     let wallet_proxy = vit_controller.spawn_wallet_proxy_custom(
         controller,
-        WalletProxySpawnParams::new(WALLET_NODE)
+        WalletProxySpawnParams::new(topology.proxy_node()?)
             .with_base_address(endpoint)
             .with_protocol(protocol.clone()),
     )?;
 
+    let manifest = vit_controller.manifest_mut();
+    manifest.proxy = Some(wallet_proxy.settings().base_address().to_string());
+    manifest.write(controller.working_directory().path())?;
+
     println!("Backend network is up");
 
-    Ok((
-        vec![leader_1, leader_2, leader_3, leader_4, wallet_node],
-        vit_station,
-        wallet_proxy,
-    ))
+    Ok((nodes, vit_station, wallet_proxy))
+}
+
+fn spawn_params(topology: &TopologyConfig, alias: &str) -> SpawnParams {
+    let settings = topology.node_settings(alias);
+    let persistence_mode = if settings.persistent {
+        PersistenceMode::Persistent
+    } else {
+        PersistenceMode::InMemory
+    };
+
+    let mut params = SpawnParams::new(alias);
+    params
+        .persistence_mode(persistence_mode)
+        .explorer(Explorer {
+            enabled: settings.explorer,
+        });
+    params
 }
 
 pub fn interactive_mode(
@@ -199,10 +190,15 @@ pub fn service_mode<P: AsRef<Path> + Clone>(
                 std::fs::remove_dir_all(testing_directory)?;
             }
 
-            let template_generator = Box::leak(build_template_generator(ideascale));
+            let template_generator =
+                Box::leak(build_template_generator(ideascale, context.seed())?);
 
             let parameters = manager.setup();
             quick_setup.upload_parameters(parameters);
+            if ideascale {
+                quick_setup.import_proposals(ideascale_proposals())?;
+                quick_setup.validate_imported_fund(ideascale_funds(), ideascale_challenges())?;
+            }
             manager.clear_requests();
             single_run(
                 control_context.clone(),
@@ -219,16 +215,42 @@ pub fn service_mode<P: AsRef<Path> + Clone>(
     Ok(())
 }
 
-pub fn build_template_generator(ideascale: bool) -> Box<dyn ValidVotingTemplateGenerator> {
+/// Proposals file used when ideascale data is requested without explicit files
+pub fn ideascale_proposals() -> PathBuf {
+    Path::new("../").join("resources/external/proposals.json")
+}
+
+pub fn ideascale_challenges() -> PathBuf {
+    Path::new("../").join("resources/external/challenges.json")
+}
+
+pub fn ideascale_funds() -> PathBuf {
+    Path::new("../").join("resources/external/funds.json")
+}
+
+/// Vit station data is taken from ideascale files or drawn from rng derived from seed
+pub fn build_template_generator(
+    ideascale: bool,
+    seed: &Seed,
+) -> std::result::Result<Box<dyn ValidVotingTemplateGenerator>, TemplateLoadError> {
     if ideascale {
-        let proposals = Path::new("../").join("resources/external/proposals.json");
-        let challenges = Path::new("../").join("resources/external/challenges.json");
-        let funds = Path::new("../").join("resources/external/funds.json");
-        return Box::new(
-            ExternalValidVotingTemplateGenerator::new(proposals, challenges, funds).unwrap(),
+        return build_external_template_generator(
+            ideascale_proposals(),
+            ideascale_challenges(),
+            ideascale_funds(),
         );
     }
-    Box::new(ArbitraryValidVotingTemplateGenerator::new())
+    Ok(Box::new(SeededValidVotingTemplateGenerator::new(seed)))
+}
+
+pub fn build_external_template_generator(
+    proposals: PathBuf,
+    challenges: PathBuf,
+    funds: PathBuf,
+) -> std::result::Result<Box<dyn ValidVotingTemplateGenerator>, TemplateLoadError> {
+    Ok(Box::new(ExternalValidVotingTemplateGenerator::new(
+        proposals, challenges, funds,
+    )?))
 }
 
 pub fn single_run(
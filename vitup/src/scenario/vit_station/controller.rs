@@ -17,7 +17,6 @@ pub use jormungandr_testing_utils::testing::{
 };
 use std::net::SocketAddr;
 use vit_servicing_station_lib::db::models::proposals::Proposal;
-use vit_servicing_station_tests::common::data::ValidVotePlanParameters;
 use vit_servicing_station_tests::common::data::ValidVotingTemplateGenerator;
 use vit_servicing_station_tests::common::{
     clients::RestClient, startup::server::BootstrapCommandBuilder,
@@ -173,7 +172,7 @@ impl VitStation {
     #[allow(clippy::too_many_arguments)]
     pub fn spawn<R: RngCore>(
         context: &Context<R>,
-        parameters: ValidVotePlanParameters,
+        db_generator: DbGenerator,
         template_generator: &mut dyn ValidVotingTemplateGenerator,
         progress_bar: ProgressBar,
         alias: &str,
@@ -195,7 +194,7 @@ impl VitStation {
         let db_file = dir.join(STORAGE);
         dump_settings_to_file(&config_file.to_str().unwrap(), &settings).unwrap();
 
-        DbGenerator::new(parameters).build(&db_file, template_generator);
+        db_generator.build(&db_file, template_generator);
 
         let mut command_builder =
             BootstrapCommandBuilder::new(PathBuf::from("vit-servicing-station-server"));
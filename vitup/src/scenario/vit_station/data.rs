@@ -1,24 +1,36 @@
 use assert_fs::TempDir;
+use serde::{Deserialize, Serialize};
 use std::path::Path;
-use vit_servicing_station_tests::common::data::ValidVotePlanParameters;
+use vit_servicing_station_lib::db::models::voteplans::Voteplan;
 use vit_servicing_station_tests::common::data::{
-    ValidVotePlanGenerator, ValidVotingTemplateGenerator,
+    Snapshot, ValidVotePlanGenerator, ValidVotePlanParameters, ValidVotingTemplateGenerator,
 };
 use vit_servicing_station_tests::common::startup::db::DbBuilder;
 pub struct DbGenerator {
     parameters: ValidVotePlanParameters,
+    vote_plans: Vec<VotePlanDetails>,
 }
 
 impl DbGenerator {
     pub fn new(parameters: ValidVotePlanParameters) -> Self {
-        Self { parameters }
+        Self {
+            parameters,
+            vote_plans: Vec::new(),
+        }
+    }
+
+    /// Vote plans which timing or payload type differ from fund settings
+    pub fn with_vote_plans(mut self, vote_plans: Vec<VotePlanDetails>) -> Self {
+        self.vote_plans = vote_plans;
+        self
     }
 
     pub fn build(self, db_file: &Path, template_generator: &mut dyn ValidVotingTemplateGenerator) {
         std::fs::File::create(&db_file).unwrap();
 
         let mut generator = ValidVotePlanGenerator::new(self.parameters);
-        let snapshot = generator.build(template_generator);
+        let mut snapshot = generator.build(template_generator);
+        VotePlanDetails::apply_all(&self.vote_plans, &mut snapshot);
 
         let path = std::path::Path::new("../").join("resources/vit_station/migration");
 
@@ -32,3 +44,86 @@ impl DbGenerator {
         jortestkit::file::copy_file(temp_db_path, db_file, true);
     }
 }
+
+/// Chain data of single vote plan. Vit station generator uses the same phases and payload type
+/// for all vote plans of fund, so vote plans with own settings are corrected after generation
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct VotePlanDetails {
+    pub id: String,
+    pub vote_start: i64,
+    pub vote_end: i64,
+    pub committee_end: i64,
+    pub payload: String,
+    pub encryption_key: String,
+    /// alias of committee wallet which owns vote plan
+    #[serde(default)]
+    pub owner: String,
+}
+
+impl VotePlanDetails {
+    /// Updates vote plans and their proposals. Fund start and end are extended to cover
+    /// all its vote plans
+    pub fn apply_all(vote_plans: &[VotePlanDetails], snapshot: &mut Snapshot) {
+        if vote_plans.is_empty() {
+            return;
+        }
+
+        for vote_plan in vote_plans {
+            vote_plan.apply(snapshot);
+        }
+
+        for fund in snapshot.funds_mut() {
+            if let Some(start) = fund
+                .chain_vote_plans
+                .iter()
+                .map(|vote_plan| vote_plan.chain_vote_start_time)
+                .min()
+            {
+                fund.fund_start_time = fund.fund_start_time.min(start);
+            }
+            if let Some(end) = fund
+                .chain_vote_plans
+                .iter()
+                .map(|vote_plan| vote_plan.chain_vote_end_time)
+                .max()
+            {
+                fund.fund_end_time = fund.fund_end_time.max(end);
+            }
+        }
+    }
+
+    fn apply(&self, snapshot: &mut Snapshot) {
+        for vote_plan in snapshot.voteplans_mut() {
+            self.update_vote_plan(vote_plan);
+        }
+        for fund in snapshot.funds_mut() {
+            for vote_plan in fund.chain_vote_plans.iter_mut() {
+                self.update_vote_plan(vote_plan);
+            }
+        }
+
+        for proposal in snapshot
+            .proposals_mut()
+            .iter_mut()
+            .map(|proposal| &mut proposal.proposal)
+            .filter(|proposal| proposal.chain_voteplan_id == self.id)
+        {
+            proposal.chain_vote_start_time = self.vote_start;
+            proposal.chain_vote_end_time = self.vote_end;
+            proposal.chain_committee_end_time = self.committee_end;
+            proposal.chain_voteplan_payload = self.payload.clone();
+            proposal.chain_vote_encryption_key = self.encryption_key.clone();
+        }
+    }
+
+    fn update_vote_plan(&self, vote_plan: &mut Voteplan) {
+        if vote_plan.chain_voteplan_id != self.id {
+            return;
+        }
+        vote_plan.chain_vote_start_time = self.vote_start;
+        vote_plan.chain_vote_end_time = self.vote_end;
+        vote_plan.chain_committee_end_time = self.committee_end;
+        vote_plan.chain_voteplan_payload = self.payload.clone();
+        vote_plan.chain_vote_encryption_key = self.encryption_key.clone();
+    }
+}
@@ -1,7 +1,11 @@
 mod controller;
 mod data;
+mod seeded;
+mod template;
 
 pub use controller::{
     Error as VitStationControllerError, VitStation, VitStationController, VitStationSettings,
 };
-pub use data::DbGenerator;
+pub use data::{DbGenerator, VotePlanDetails};
+pub use seeded::SeededValidVotingTemplateGenerator;
+pub use template::VoteOptionsTemplateGenerator;
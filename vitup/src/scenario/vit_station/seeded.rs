@@ -0,0 +1,125 @@
+use jormungandr_testing_utils::testing::network_builder::Seed;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use rand_chacha::ChaChaRng;
+use rand_core::SeedableRng;
+use serde_json::json;
+use vit_servicing_station_tests::common::data::{
+    ChallengeTemplate, FundTemplate, ProposalTemplate, ValidVotingTemplateGenerator,
+};
+
+const WORDS: &[&str] = &[
+    "cardano",
+    "catalyst",
+    "voting",
+    "wallet",
+    "community",
+    "developer",
+    "education",
+    "dapp",
+    "marketplace",
+    "identity",
+    "governance",
+    "tooling",
+    "africa",
+    "defi",
+    "nft",
+    "oracle",
+    "bridge",
+    "explorer",
+    "mobile",
+    "library",
+    "research",
+    "onboarding",
+    "treasury",
+    "stake",
+];
+
+/// Template generator which draws all vit station data from rng derived from seed, so the
+/// same seed gives the same funds, challenges and proposals. Templates have the same shape as
+/// in external json files (simple challenges only), fund and challenges ids are adjusted later
+/// by vote plan generator
+pub struct SeededValidVotingTemplateGenerator {
+    rng: ChaChaRng,
+    next_fund_id: i32,
+    next_challenge_id: usize,
+    next_proposal_id: usize,
+}
+
+impl SeededValidVotingTemplateGenerator {
+    pub fn new(seed: &Seed) -> Self {
+        let mut bytes = [0u8; 32];
+        bytes.copy_from_slice(seed.as_ref());
+        Self {
+            rng: ChaChaRng::from_seed(bytes),
+            next_fund_id: 1,
+            next_challenge_id: 1,
+            next_proposal_id: 1,
+        }
+    }
+
+    fn sentence(&mut self, words: usize) -> String {
+        (0..words)
+            .map(|_| *WORDS.choose(&mut self.rng).expect("words are defined"))
+            .collect::<Vec<&str>>()
+            .join(" ")
+    }
+}
+
+impl ValidVotingTemplateGenerator for SeededValidVotingTemplateGenerator {
+    fn next_proposal(&mut self) -> ProposalTemplate {
+        let id = self.next_proposal_id;
+        self.next_proposal_id += 1;
+
+        let proposal = json!({
+            "category_name": self.sentence(1),
+            "chain_vote_options": "blank,yes,no",
+            "chain_vote_type": "public",
+            "challenge_id": "1",
+            "challenge_type": "simple",
+            "internal_id": id.to_string(),
+            "proposal_funds": self.rng.gen_range(1_000..=100_000u64).to_string(),
+            "proposal_id": self.rng.gen_range(100_000..=999_999u64).to_string(),
+            "proposal_impact_score": self.rng.gen_range(100..=500u32).to_string(),
+            "proposal_solution": self.sentence(12),
+            "proposal_summary": self.sentence(12),
+            "proposal_title": self.sentence(4),
+            "proposal_url": format!("http://ideascale.com/t/{}", id),
+            "proposer_email": format!("proposer{}@mail.com", id),
+            "proposer_name": self.sentence(2),
+            "proposer_relevant_experience": self.sentence(8),
+            "proposer_url": format!("http://proposer.com/{}", id),
+        });
+        serde_json::from_value(proposal).expect("proposal template in external format")
+    }
+
+    fn next_challenge(&mut self) -> ChallengeTemplate {
+        let id = self.next_challenge_id;
+        self.next_challenge_id += 1;
+
+        let challenge = json!({
+            "challenge_type": "simple",
+            "challenge_url": format!("https://cardano.ideascale.com/a/campaign-home/{}", id),
+            "description": self.sentence(12),
+            "fund_id": self.next_fund_id.to_string(),
+            "id": id.to_string(),
+            "proposers_rewards": self.rng.gen_range(10_000..=100_000u64).to_string(),
+            "rewards_total": self.rng.gen_range(100_000..=1_000_000u64).to_string(),
+            "title": self.sentence(3),
+        });
+        serde_json::from_value(challenge).expect("challenge template in external format")
+    }
+
+    fn next_fund(&mut self) -> FundTemplate {
+        let id = self.next_fund_id;
+        self.next_fund_id += 1;
+
+        let fund = json!({
+            "id": id,
+            "goal": self.sentence(8),
+            "rewards_info": self.sentence(8),
+            "threshold": self.rng.gen_range(100..=1_000u32),
+        });
+        serde_json::from_value(fund).expect("fund template in external format")
+    }
+}
@@ -0,0 +1,74 @@
+use crate::config::{VitStartParameters, VoteOptionsConfig};
+use vit_servicing_station_tests::common::data::{
+    ChallengeTemplate, FundTemplate, ProposalTemplate, ValidVotingTemplateGenerator,
+};
+
+/// Template generator which sets vote options and challenges of proposals, so vit station
+/// data matches proposals put into vote plans. Proposals are requested in vote plans order,
+/// which differs from generation order when proposals are grouped into vote plans by challenge,
+/// so templates are buffered and returned in vote plans order. Challenges get sequential ids unless proposals are imported, in which case challenge ids
+/// from imported data are kept
+pub struct VoteOptionsTemplateGenerator<'a> {
+    inner: &'a mut dyn ValidVotingTemplateGenerator,
+    vote_options: VoteOptionsConfig,
+    challenges: usize,
+    proposals_order: Vec<usize>,
+    proposals: Vec<Option<ProposalTemplate>>,
+    next_proposal_index: usize,
+    next_challenge_index: usize,
+}
+
+impl<'a> VoteOptionsTemplateGenerator<'a> {
+    pub fn new(
+        inner: &'a mut dyn ValidVotingTemplateGenerator,
+        parameters: &VitStartParameters,
+    ) -> Self {
+        Self {
+            inner,
+            vote_options: parameters.vote_options.clone(),
+            challenges: parameters.challenges,
+            proposals_order: parameters.proposals_order(),
+            proposals: Vec::new(),
+            next_proposal_index: 0,
+            next_challenge_index: 0,
+        }
+    }
+}
+
+impl<'a> ValidVotingTemplateGenerator for VoteOptionsTemplateGenerator<'a> {
+    fn next_proposal(&mut self) -> ProposalTemplate {
+        let position = self.next_proposal_index;
+        self.next_proposal_index += 1;
+        let index = self
+            .proposals_order
+            .get(position)
+            .copied()
+            .unwrap_or(position);
+
+        while self.proposals.len() <= index {
+            self.proposals.push(Some(self.inner.next_proposal()));
+        }
+        let mut proposal = self.proposals[index]
+            .take()
+            .expect("proposal template is taken only once");
+        if !self.vote_options.is_imported() {
+            proposal.challenge_id = self.vote_options.challenge_id(index, self.challenges);
+        }
+        proposal.chain_vote_options = self.vote_options.options(index, self.challenges).join(",");
+        proposal
+    }
+
+    fn next_challenge(&mut self) -> ChallengeTemplate {
+        self.next_challenge_index += 1;
+
+        let mut challenge = self.inner.next_challenge();
+        if !self.vote_options.is_imported() {
+            challenge.id = self.next_challenge_index.to_string();
+        }
+        challenge
+    }
+
+    fn next_fund(&mut self) -> FundTemplate {
+        self.inner.next_fund()
+    }
+}
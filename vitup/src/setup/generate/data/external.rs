@@ -41,6 +41,10 @@ pub struct ExternalDataCommandArgs {
 
     #[structopt(long = "skip-qr-generation")]
     pub skip_qr_generation: bool,
+
+    /// seed for generated wallets and keys. The same seed produces the same environment
+    #[structopt(long = "seed")]
+    pub seed: Option<Seed>,
 }
 
 impl ExternalDataCommandArgs {
@@ -48,7 +52,8 @@ impl ExternalDataCommandArgs {
         std::env::set_var("RUST_BACKTRACE", "full");
 
         let context = Context::new(
-            Seed::generate(rand::rngs::OsRng),
+            self.seed
+                .unwrap_or_else(|| Seed::generate(rand::rngs::OsRng)),
             PathBuf::new(),
             PathBuf::new(),
             Some(self.output_directory.clone()),
@@ -59,6 +64,7 @@ impl ExternalDataCommandArgs {
 
         let mut quick_setup = QuickVitBackendSettingsBuilder::new();
         let config = read_config(&self.config)?;
+        config.validate()?;
 
         if self.skip_qr_generation {
             quick_setup.skip_qr_generation();
@@ -66,6 +72,7 @@ impl ExternalDataCommandArgs {
         quick_setup.upload_parameters(config.params.clone());
         quick_setup.fees(config.linear_fees);
         quick_setup.set_external_committees(config.committees);
+        quick_setup.validate_imported_fund(&self.funds, &self.challenges)?;
 
         if !self.output_directory.exists() {
             std::fs::create_dir_all(&self.output_directory)?;
@@ -94,7 +101,7 @@ impl ExternalDataCommandArgs {
         let mut genesis = root_directory.clone();
         genesis.push("genesis.yaml");
 
-        let mut block0 = root_directory;
+        let mut block0 = root_directory.clone();
         block0.push("block0.bin");
 
         let mut block0_configuration = read_genesis_yaml(&genesis)?;
@@ -110,10 +117,15 @@ impl ExternalDataCommandArgs {
             block0_configuration.initial.extend(snapshot);
         }
 
+        let mut manifest = vit_controller.manifest().clone();
+        manifest.update_block0_hash(&block0_configuration);
+
         write_genesis_yaml(block0_configuration, &genesis)?;
         println!("genesis.yaml: {:?}", std::fs::canonicalize(&genesis)?);
         encode(&genesis, &block0)?;
         println!("block0: {:?}", std::fs::canonicalize(&block0)?);
+        let manifest = manifest.write(&root_directory)?;
+        println!("manifest: {:?}", std::fs::canonicalize(&manifest)?);
 
         println!("Fund id: {}", quick_setup.parameters().fund_id);
         println!(
@@ -46,6 +46,10 @@ pub struct PerfDataCommandArgs {
 
     #[structopt(short = "s", long = "single", default_value = "0")]
     pub single: usize,
+
+    /// seed for generated wallets and keys. The same seed produces the same environment
+    #[structopt(long = "seed")]
+    pub seed: Option<Seed>,
 }
 
 impl PerfDataCommandArgs {
@@ -53,7 +57,8 @@ impl PerfDataCommandArgs {
         std::env::set_var("RUST_BACKTRACE", "full");
 
         let context = Context::new(
-            Seed::generate(rand::rngs::OsRng),
+            self.seed
+                .unwrap_or_else(|| Seed::generate(rand::rngs::OsRng)),
             PathBuf::new(),
             PathBuf::new(),
             Some(self.output_directory.clone()),
@@ -64,6 +69,7 @@ impl PerfDataCommandArgs {
 
         let mut quick_setup = QuickVitBackendSettingsBuilder::new();
         let config = read_config(&self.config)?;
+        config.validate()?;
         quick_setup.skip_qr_generation();
         quick_setup.upload_parameters(config.params.clone());
         quick_setup.fees(config.linear_fees);
@@ -117,12 +123,18 @@ impl PerfDataCommandArgs {
         let mut single_directory = root_directory.clone();
         single_directory.push("single");
         self.move_single_user_secrets(&root_directory, &single_directory)?;
-        self.split_secrets(root_directory)?;
+        self.split_secrets(&root_directory)?;
+
+        let mut manifest = vit_controller.manifest().clone();
+        manifest.update_block0_hash(&block0_configuration);
+        manifest.locate_secrets(&root_directory);
 
         write_genesis_yaml(block0_configuration, &genesis)?;
         println!("genesis.yaml: {:?}", std::fs::canonicalize(&genesis)?);
         encode(&genesis, &block0)?;
         println!("block0: {:?}", std::fs::canonicalize(&block0)?);
+        let manifest = manifest.write(&root_directory)?;
+        println!("manifest: {:?}", std::fs::canonicalize(&manifest)?);
         println!("Fund id: {}", quick_setup.parameters().fund_id);
         println!(
             "voteplan ids: {:?}",
@@ -1,3 +1,4 @@
+use crate::scenario::vit_station::SeededValidVotingTemplateGenerator;
 use crate::setup::start::QuickVitBackendSettingsBuilder;
 use crate::Result;
 
@@ -6,7 +7,6 @@ use jormungandr_scenario_tests::ProgressBarMode as ScenarioProgressBarMode;
 use jormungandr_scenario_tests::{Context, Seed};
 use std::path::PathBuf;
 use structopt::StructOpt;
-use vit_servicing_station_tests::common::data::ArbitraryValidVotingTemplateGenerator;
 #[derive(StructOpt, Debug)]
 #[structopt(setting = structopt::clap::AppSettings::ColoredHelp)]
 pub struct RandomDataCommandArgs {
@@ -17,6 +17,10 @@ pub struct RandomDataCommandArgs {
     /// how many qr to generate
     #[structopt(long = "config")]
     pub config: PathBuf,
+
+    /// seed for generated wallets and keys. The same seed produces the same environment
+    #[structopt(long = "seed")]
+    pub seed: Option<Seed>,
 }
 
 impl RandomDataCommandArgs {
@@ -24,7 +28,8 @@ impl RandomDataCommandArgs {
         std::env::set_var("RUST_BACKTRACE", "full");
 
         let context = Context::new(
-            Seed::generate(rand::rngs::OsRng),
+            self.seed
+                .unwrap_or_else(|| Seed::generate(rand::rngs::OsRng)),
             PathBuf::new(),
             PathBuf::new(),
             Some(self.output_directory.clone()),
@@ -35,6 +40,7 @@ impl RandomDataCommandArgs {
 
         let mut quick_setup = QuickVitBackendSettingsBuilder::new();
         let config = read_config(&self.config)?;
+        config.validate()?;
 
         quick_setup.upload_parameters(config.params.clone());
         quick_setup.fees(config.linear_fees);
@@ -45,10 +51,10 @@ impl RandomDataCommandArgs {
         }
 
         let title = quick_setup.title();
+        let mut template_generator = SeededValidVotingTemplateGenerator::new(context.seed());
 
         let (vit_controller, mut controller, vit_parameters, version) =
             quick_setup.build(context)?;
-        let mut template_generator = ArbitraryValidVotingTemplateGenerator::new();
 
         // generate vit station data
         let vit_station = vit_controller.spawn_vit_station(
@@ -65,7 +71,7 @@ impl RandomDataCommandArgs {
         let mut genesis = root_directory.clone();
         genesis.push("genesis.yaml");
 
-        let mut block0 = root_directory;
+        let mut block0 = root_directory.clone();
         block0.push("block0.bin");
 
         let mut block0_configuration = read_genesis_yaml(&genesis)?;
@@ -78,7 +84,12 @@ impl RandomDataCommandArgs {
 
         println!("{:?}", block0_configuration);
 
+        let mut manifest = vit_controller.manifest().clone();
+        manifest.update_block0_hash(&block0_configuration);
+
         write_genesis_yaml(block0_configuration, &genesis)?;
-        encode(&genesis, &block0)
+        encode(&genesis, &block0)?;
+        manifest.write(&root_directory)?;
+        Ok(())
     }
 }
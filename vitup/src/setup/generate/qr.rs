@@ -23,6 +23,10 @@ pub struct QrCommandArgs {
 
     #[structopt(long = "global-pin", default_value = "1234")]
     pub global_pin: String,
+
+    /// seed for generated wallets and keys. The same seed produces the same environment
+    #[structopt(long = "seed")]
+    pub seed: Option<Seed>,
 }
 
 impl QrCommandArgs {
@@ -30,7 +34,8 @@ impl QrCommandArgs {
         std::env::set_var("RUST_BACKTRACE", "full");
 
         let context = Context::new(
-            Seed::generate(rand::rngs::OsRng),
+            self.seed
+                .unwrap_or_else(|| Seed::generate(rand::rngs::OsRng)),
             PathBuf::new(),
             PathBuf::new(),
             Some(self.output_directory.clone()),
@@ -49,6 +54,7 @@ impl QrCommandArgs {
         } else if let Some(initials_count) = self.initials {
             quick_setup.initials_count(initials_count, &self.global_pin);
         }
+        quick_setup.parameters().validate()?;
 
         if !self.output_directory.exists() {
             std::fs::create_dir_all(&self.output_directory)?;
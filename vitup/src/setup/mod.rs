@@ -2,6 +2,7 @@ pub mod convert;
 pub mod diff;
 pub mod generate;
 pub mod start;
+pub mod tally;
 pub mod validate;
 
 use crate::error::Result;
@@ -14,6 +15,7 @@ use diff::DiffCommand;
 use generate::DataCommandArgs;
 use start::QuickStartCommandArgs;
 use structopt::StructOpt;
+use tally::TallyCommand;
 use validate::ValidateCommand;
 
 #[derive(StructOpt, Debug)]
@@ -28,6 +30,8 @@ pub enum VitCliCommand {
     Validate(ValidateCommand),
     // convert data
     Convert(ConvertCommand),
+    /// tally vote plans
+    Tally(TallyCommand),
 }
 
 impl VitCliCommand {
@@ -38,6 +42,7 @@ impl VitCliCommand {
             Self::Diff(diff_command) => diff_command.exec(),
             Self::Validate(validate_command) => validate_command.exec(),
             Self::Convert(convert_command) => convert_command.exec(),
+            Self::Tally(tally_command) => tally_command.exec(),
         }
     }
 }
@@ -142,16 +142,26 @@ impl AdvancedStartCommandArgs {
                 .extend(&Initials::new_from_external(initials));
         }
 
+        config
+            .params
+            .vote_options
+            .import_proposals(&self.proposals)?;
+        config.validate()?;
+
         println!("{:?}", config.params);
 
         let mut quick_setup = QuickVitBackendSettingsBuilder::new();
         quick_setup.upload_parameters(config.params.clone());
         quick_setup.fees(config.linear_fees);
         quick_setup.set_external_committees(config.committees);
+        quick_setup.validate_imported_fund(&self.funds, &self.challenges)?;
 
-        let mut template_generator =
-            ExternalValidVotingTemplateGenerator::new(self.proposals, self.challenges, self.funds)
-                .unwrap();
+        let mut template_generator = ExternalValidVotingTemplateGenerator::new(
+            self.proposals.clone(),
+            self.challenges.clone(),
+            self.funds.clone(),
+        )
+        .unwrap();
 
         testing_directory.push(quick_setup.title());
         if testing_directory.exists() {
@@ -180,6 +190,8 @@ impl AdvancedStartCommandArgs {
 
                         let parameters = manager.setup();
                         quick_setup.upload_parameters(parameters);
+                        quick_setup.import_proposals(&self.proposals)?;
+                        quick_setup.validate_imported_fund(&self.funds, &self.challenges)?;
                         manager.clear_requests();
                         single_run(
                             control_context.clone(),
@@ -2,7 +2,4 @@ mod advanced;
 pub mod quick;
 
 pub use advanced::AdvancedStartCommandArgs;
-pub use quick::{
-    QuickStartCommandArgs, QuickVitBackendSettingsBuilder, LEADER_1, LEADER_2, LEADER_3, LEADER_4,
-    WALLET_NODE,
-};
+pub use quick::{QuickStartCommandArgs, QuickVitBackendSettingsBuilder, WALLET_NODE};
@@ -1,8 +1,13 @@
 use super::mode::{parse_mode_from_str, Mode};
 use super::QuickVitBackendSettingsBuilder;
-use crate::config::Initials;
-use crate::scenario::network::build_template_generator;
+use crate::config::{
+    parse_consensus_from_str, parse_layout_from_str, timestamps_problems, Consensus,
+    ConsensusConfig, Initials, TopologyConfig, TrustedPeersLayout, ValidationError,
+};
 use crate::scenario::network::service_mode;
+use crate::scenario::network::{
+    build_template_generator, ideascale_challenges, ideascale_funds, ideascale_proposals,
+};
 use crate::scenario::network::{endless_mode, interactive_mode, setup_network};
 use crate::Result;
 use iapyx::Protocol;
@@ -96,6 +101,10 @@ pub struct QuickStartCommandArgs {
     #[structopt(long = "snapshot-timestamp")]
     pub snapshot_timestamp: Option<String>,
 
+    /// block0 date, current time is used if not defined
+    #[structopt(long = "block0-timestamp")]
+    pub block0_timestamp: Option<String>,
+
     /// slot duration
     #[structopt(long = "slot-duration", default_value = "20")]
     pub slot_duration: u8,
@@ -143,6 +152,30 @@ pub struct QuickStartCommandArgs {
     /// token, only applicable if service mode is used
     #[structopt(long = "token")]
     pub token: Option<String>,
+
+    /// number of bft leaders
+    #[structopt(long = "leaders", default_value = "4")]
+    pub leaders: usize,
+
+    /// number of passive nodes, wallet proxy is connected to the first one
+    #[structopt(long = "wallet-nodes", default_value = "1")]
+    pub wallet_nodes: usize,
+
+    /// trusted peers layout: full-mesh, star or chain
+    #[structopt(
+        long = "topology",
+        default_value = "full-mesh",
+        parse(try_from_str = parse_layout_from_str)
+    )]
+    pub layout: TrustedPeersLayout,
+
+    /// consensus: bft or genesis-praos
+    #[structopt(
+        long = "consensus",
+        default_value = "bft",
+        parse(try_from_str = parse_consensus_from_str)
+    )]
+    pub consensus: Consensus,
 }
 
 impl QuickStartCommandArgs {
@@ -195,36 +228,54 @@ impl QuickStartCommandArgs {
             });
         }
 
-        let vote_timestamps = vec![
-            self.vote_start_timestamp.clone(),
-            self.tally_start_timestamp.clone(),
-            self.tally_end_timestamp.clone(),
-        ];
-
-        let vote_timestamps_defined = vote_timestamps.iter().filter(|x| x.is_some()).count();
-        if vote_timestamps_defined < 3 && vote_timestamps_defined > 0 {
-            panic!("either define all voting dates or none");
+        let problems = timestamps_problems(&[
+            ("vote-start-timestamp", &self.vote_start_timestamp),
+            ("tally-start-timestamp", &self.tally_start_timestamp),
+            ("tally-end-timestamp", &self.tally_end_timestamp),
+            ("next-vote-timestamp", &self.next_vote_timestamp),
+            ("snapshot-timestamp", &self.snapshot_timestamp),
+            ("block0-timestamp", &self.block0_timestamp),
+        ]);
+        if !problems.is_empty() {
+            return Err(ValidationError(problems).into());
         }
 
         quick_setup
+            .vote_start_timestamp(self.vote_start_timestamp)?
+            .tally_start_timestamp(self.tally_start_timestamp)?
+            .tally_end_timestamp(self.tally_end_timestamp)?
+            .next_vote_timestamp(self.next_vote_timestamp)?
+            .refresh_timestamp(self.snapshot_timestamp)?
+            .block0_timestamp(self.block0_timestamp)?
             .vote_start_epoch(self.vote_start_epoch)
             .tally_start_epoch(self.tally_start_epoch)
             .tally_end_epoch(self.tally_end_epoch)
-            .vote_start_timestamp(self.vote_start_timestamp)
-            .tally_start_timestamp(self.tally_start_timestamp)
-            .tally_end_timestamp(self.tally_end_timestamp)
-            .next_vote_timestamp(self.next_vote_timestamp)
-            .refresh_timestamp(self.snapshot_timestamp)
             .slot_duration_in_seconds(self.slot_duration)
             .slots_in_epoch_count(self.slots_in_epoch)
             .proposals_count(self.proposals)
             .voting_power(self.voting_power)
             .private(self.private)
-            .version(self.version);
+            .version(self.version)
+            .topology(TopologyConfig {
+                leaders: self.leaders,
+                wallet_nodes: self.wallet_nodes,
+                layout: self.layout,
+                nodes: Default::default(),
+            })
+            .consensus(ConsensusConfig {
+                version: self.consensus,
+                ..Default::default()
+            });
 
         jormungandr_scenario_tests::introduction::print(&context, "VOTING BACKEND");
 
-        let template_generator = Box::leak(build_template_generator(ideascale));
+        if ideascale {
+            quick_setup.import_proposals(ideascale_proposals())?;
+            quick_setup.validate_imported_fund(ideascale_funds(), ideascale_challenges())?;
+        }
+        quick_setup.parameters().validate()?;
+
+        let template_generator = Box::leak(build_template_generator(ideascale, context.seed())?);
 
         testing_directory.push(quick_setup.title());
         if testing_directory.exists() {
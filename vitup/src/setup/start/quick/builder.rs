@@ -1,50 +1,60 @@
-use crate::config::VitStartParameters;
+use crate::config::{
+    imported_fund_problems, parse_timestamp, ConsensusConfig, ProposalAction, TopologyConfig,
+    ValidationError, VitStartParameters, VoteOptionsConfig, VotePlanSettings, DELEGATOR_PREFIX,
+};
+use crate::scenario::committee::{PrivateCommittee, COMMITTEE_DIRECTORY};
 use crate::scenario::controller::VitController;
 use crate::scenario::controller::VitControllerBuilder;
+use crate::scenario::manifest::{Block0Manifest, Manifest, NodeManifest, WalletManifest};
+use crate::scenario::vit_station::VotePlanDetails;
 use crate::{config::Initials, Result};
 use assert_fs::fixture::{ChildPath, PathChild};
 use chain_crypto::SecretKey;
+use chain_impl_mockchain::certificate::VotePlan;
 use chain_impl_mockchain::testing::scenario::template::VotePlanDef;
 use chain_impl_mockchain::vote::PayloadType;
 use chain_impl_mockchain::{
     testing::scenario::template::{ProposalDefBuilder, VotePlanDefBuilder},
     value::Value,
 };
-use chain_vote::committee::ElectionPublicKey;
 use chrono::naive::NaiveDateTime;
 use iapyx::Protocol;
 use jormungandr_lib::interfaces::CommitteeIdDef;
 use jormungandr_lib::time::SecondsSinceUnixEpoch;
-use jormungandr_scenario_tests::scenario::settings::Settings;
 use jormungandr_scenario_tests::scenario::{
-    ActiveSlotCoefficient, ConsensusVersion, ContextChaCha, Controller, KesUpdateSpeed, Milli,
-    NumberOfSlotsPerEpoch, SlotDuration, Topology, TopologyBuilder,
+    ActiveSlotCoefficient, ContextChaCha, Controller, KesUpdateSpeed, Milli, NumberOfSlotsPerEpoch,
+    SlotDuration, Topology,
 };
-use jormungandr_testing_utils::testing::network_builder::{Blockchain, Node, WalletTemplate};
+use jormungandr_testing_utils::testing::network_builder::{Blockchain, Seed, WalletTemplate};
 use jormungandr_testing_utils::wallet::LinearFee;
 use jormungandr_testing_utils::{
     qr_code::{generate, KeyQrCode},
     wallet::ElectionPublicKeyExtension,
 };
 use jortestkit::prelude::append;
-use std::{collections::HashMap, iter};
+use rand_chacha::ChaChaRng;
+use rand_core::{RngCore, SeedableRng};
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::path::{Path, PathBuf};
 use vit_servicing_station_tests::common::data::ValidVotePlanParameters;
 
-pub const LEADER_1: &str = "Leader1";
-pub const LEADER_2: &str = "Leader2";
-pub const LEADER_3: &str = "Leader3";
-pub const LEADER_4: &str = "Leader4";
-pub const WALLET_NODE: &str = "Wallet_Node";
+pub use crate::config::WALLET_NODE;
+
+/// Funds of every committee wallet, enough to pay fees of all tally fragments
+pub const COMMITTEE_WALLET_VALUE: u64 = 1_000_000_000;
 
 #[derive(Clone)]
 pub struct QuickVitBackendSettingsBuilder {
     parameters: VitStartParameters,
     committe_wallet: String,
+    faucet_wallet: Option<(String, u64)>,
     external_committees: Vec<CommitteeIdDef>,
     fees: LinearFee,
     title: String,
     //needed for load tests when we relay on secret keys instead of qrs
     skip_qr_generation: bool,
+    election_key: Option<String>,
 }
 
 impl Default for QuickVitBackendSettingsBuilder {
@@ -53,17 +63,17 @@ impl Default for QuickVitBackendSettingsBuilder {
     }
 }
 
-const FORMAT: &str = "%Y-%m-%d %H:%M:%S";
-
 impl QuickVitBackendSettingsBuilder {
     pub fn new() -> Self {
         Self {
             parameters: Default::default(),
             title: "vit_backend".to_owned(),
             committe_wallet: "committee_1".to_owned(),
+            faucet_wallet: None,
             fees: LinearFee::new(0, 0, 0),
             external_committees: Vec::new(),
             skip_qr_generation: false,
+            election_key: None,
         }
     }
 
@@ -92,10 +102,59 @@ impl QuickVitBackendSettingsBuilder {
         &self.parameters.protocol
     }
 
+    pub fn topology(&mut self, topology: TopologyConfig) -> &mut Self {
+        self.parameters.topology = topology;
+        self
+    }
+
+    pub fn consensus(&mut self, consensus: ConsensusConfig) -> &mut Self {
+        self.parameters.consensus = consensus;
+        self
+    }
+
+    pub fn vote_options(&mut self, vote_options: VoteOptionsConfig) -> &mut Self {
+        self.parameters.vote_options = vote_options;
+        self
+    }
+
+    /// Takes vote options and challenges of proposals from imported proposals file
+    pub fn import_proposals<P: AsRef<Path>>(&mut self, proposals: P) -> Result<&mut Self> {
+        self.parameters.vote_options.import_proposals(proposals)?;
+        Ok(self)
+    }
+
+    /// Checks that fund id matches imported funds and challenges files
+    pub fn validate_imported_fund<P: AsRef<Path>, Q: AsRef<Path>>(
+        &self,
+        funds: P,
+        challenges: Q,
+    ) -> Result<()> {
+        let problems = imported_fund_problems(self.parameters.fund_id, funds, challenges)?;
+        if !problems.is_empty() {
+            return Err(ValidationError(problems).into());
+        }
+        Ok(())
+    }
+
     pub fn title(&self) -> String {
         self.title.clone()
     }
 
+    pub fn committee_wallet_alias(&self) -> String {
+        self.committe_wallet.clone()
+    }
+
+    /// Adds account with given funds to block0, which is not bound to any initial
+    /// and can be used to transfer funds to other accounts
+    pub fn faucet_wallet(&mut self, alias: &str, value: u64) -> &mut Self {
+        self.faucet_wallet = Some((alias.to_string(), value));
+        self
+    }
+
+    pub fn faucet_wallet_alias(&self) -> Option<String> {
+        self.faucet_wallet.as_ref().map(|(alias, _)| alias.clone())
+    }
+
     pub fn initials(&mut self, initials: Initials) -> &mut Self {
         self.parameters.initials = Some(initials);
         self
@@ -152,44 +211,72 @@ impl QuickVitBackendSettingsBuilder {
         self
     }
 
-    pub fn next_vote_timestamp(&mut self, next_vote_timestamp: Option<String>) -> &mut Self {
+    pub fn next_vote_timestamp(
+        &mut self,
+        next_vote_timestamp: Option<String>,
+    ) -> Result<&mut Self> {
         if let Some(timestamp) = next_vote_timestamp {
-            self.parameters.next_vote_start_time =
-                Some(NaiveDateTime::parse_from_str(&timestamp, FORMAT).unwrap());
+            self.parameters.next_vote_start_time = Some(parse_timestamp_parameter(
+                "next-vote-timestamp",
+                &timestamp,
+            )?);
         }
-        self
+        Ok(self)
     }
 
-    pub fn refresh_timestamp(&mut self, refresh_timestamp: Option<String>) -> &mut Self {
+    pub fn block0_timestamp(&mut self, block0_timestamp: Option<String>) -> Result<&mut Self> {
+        if let Some(timestamp) = block0_timestamp {
+            self.parameters.block0_time =
+                Some(parse_timestamp_parameter("block0-timestamp", &timestamp)?);
+        }
+        Ok(self)
+    }
+
+    pub fn refresh_timestamp(&mut self, refresh_timestamp: Option<String>) -> Result<&mut Self> {
         if let Some(timestamp) = refresh_timestamp {
             self.parameters.refresh_time =
-                Some(NaiveDateTime::parse_from_str(&timestamp, FORMAT).unwrap());
+                Some(parse_timestamp_parameter("snapshot-timestamp", &timestamp)?);
         }
-        self
+        Ok(self)
     }
 
-    pub fn vote_start_timestamp(&mut self, vote_start_timestamp: Option<String>) -> &mut Self {
+    pub fn vote_start_timestamp(
+        &mut self,
+        vote_start_timestamp: Option<String>,
+    ) -> Result<&mut Self> {
         if let Some(timestamp) = vote_start_timestamp {
-            self.parameters.vote_start_timestamp =
-                Some(NaiveDateTime::parse_from_str(&timestamp, FORMAT).unwrap());
+            self.parameters.vote_start_timestamp = Some(parse_timestamp_parameter(
+                "vote-start-timestamp",
+                &timestamp,
+            )?);
         }
-        self
+        Ok(self)
     }
 
-    pub fn tally_start_timestamp(&mut self, tally_start_timestamp: Option<String>) -> &mut Self {
+    pub fn tally_start_timestamp(
+        &mut self,
+        tally_start_timestamp: Option<String>,
+    ) -> Result<&mut Self> {
         if let Some(timestamp) = tally_start_timestamp {
-            self.parameters.tally_start_timestamp =
-                Some(NaiveDateTime::parse_from_str(&timestamp, FORMAT).unwrap());
+            self.parameters.tally_start_timestamp = Some(parse_timestamp_parameter(
+                "tally-start-timestamp",
+                &timestamp,
+            )?);
         }
-        self
+        Ok(self)
     }
 
-    pub fn tally_end_timestamp(&mut self, tally_end_timestamp: Option<String>) -> &mut Self {
+    pub fn tally_end_timestamp(
+        &mut self,
+        tally_end_timestamp: Option<String>,
+    ) -> Result<&mut Self> {
         if let Some(timestamp) = tally_end_timestamp {
-            self.parameters.tally_end_timestamp =
-                Some(NaiveDateTime::parse_from_str(&timestamp, FORMAT).unwrap());
+            self.parameters.tally_end_timestamp = Some(parse_timestamp_parameter(
+                "tally-end-timestamp",
+                &timestamp,
+            )?);
         }
-        self
+        Ok(self)
     }
 
     pub fn fund_name(&self) -> String {
@@ -233,23 +320,26 @@ impl QuickVitBackendSettingsBuilder {
         }
 
         if self.parameters.next_vote_start_time.is_none() {
-            let timestamp = SecondsSinceUnixEpoch::now().to_secs()
-                + epoch_duration * self.parameters.tally_end
-                + 10_000;
+            let timestamp =
+                block0_date.to_secs() + epoch_duration * self.parameters.tally_end + 10_000;
             self.parameters.next_vote_start_time =
                 Some(NaiveDateTime::from_timestamp(timestamp as i64, 0));
         }
     }
 
+    /// Block0 date defined in parameters or current time
+    pub fn block0_date(&self) -> SecondsSinceUnixEpoch {
+        self.parameters
+            .block0_time
+            .map(|time| SecondsSinceUnixEpoch::from_secs(time.timestamp() as u64))
+            .unwrap_or_else(SecondsSinceUnixEpoch::now)
+    }
+
     pub fn upload_parameters(&mut self, parameters: VitStartParameters) {
         self.parameters = parameters;
     }
 
-    pub fn vote_plan_parameters(
-        &self,
-        vote_plans: Vec<VotePlanDef>,
-        settings: &Settings,
-    ) -> ValidVotePlanParameters {
+    pub fn vote_plan_parameters(&self, vote_plans: Vec<VotePlanDef>) -> ValidVotePlanParameters {
         let mut parameters = ValidVotePlanParameters::new(vote_plans, self.fund_name());
         parameters.set_voting_power_threshold((self.parameters.voting_power * 1_000_000) as i64);
         parameters.set_challenges_count(self.parameters.challenges);
@@ -267,91 +357,165 @@ impl QuickVitBackendSettingsBuilder {
         parameters.set_fund_id(self.parameters.fund_id);
         parameters.calculate_challenges_total_funds = false;
 
-        if self.parameters.private {
-            let private_key_data = settings.private_vote_plans.get(&self.fund_name()).unwrap();
-            let key: ElectionPublicKey = private_key_data.election_public_key();
-            parameters.set_vote_encryption_key(key.to_base32().unwrap());
+        // vit station supports single encryption key per fund,
+        // all private vote plans share the same committee
+        if let Some(election_key) = &self.election_key {
+            parameters.set_vote_encryption_key(election_key.clone());
         }
         parameters
     }
 
     pub fn build_topology(&mut self) -> Topology {
-        let mut topology_builder = TopologyBuilder::new();
-
-        // Leader 1
-        let leader_1 = Node::new(LEADER_1);
-        topology_builder.register_node(leader_1);
-
-        // leader 2
-        let mut leader_2 = Node::new(LEADER_2);
-        leader_2.add_trusted_peer(LEADER_1);
-        topology_builder.register_node(leader_2);
-
-        // leader 3
-        let mut leader_3 = Node::new(LEADER_3);
-        leader_3.add_trusted_peer(LEADER_1);
-        leader_3.add_trusted_peer(LEADER_2);
-        topology_builder.register_node(leader_3);
-
-        // leader 4
-        let mut leader_4 = Node::new(LEADER_4);
-        leader_4.add_trusted_peer(LEADER_1);
-        leader_4.add_trusted_peer(LEADER_2);
-        leader_4.add_trusted_peer(LEADER_3);
-        topology_builder.register_node(leader_4);
-
-        // passive
-        let mut passive = Node::new(WALLET_NODE);
-        passive.add_trusted_peer(LEADER_1);
-        passive.add_trusted_peer(LEADER_2);
-        passive.add_trusted_peer(LEADER_3);
-        passive.add_trusted_peer(LEADER_4);
-
-        topology_builder.register_node(passive);
-
-        topology_builder.build()
-    }
-
-    pub fn build_vote_plans(&mut self) -> Vec<VotePlanDef> {
-        iter::from_fn(|| {
-            let mut proposal_builder = ProposalDefBuilder::new(
-                chain_impl_mockchain::testing::VoteTestGen::external_proposal_id(),
-            );
-            proposal_builder.options(3);
-            proposal_builder.action_off_chain();
-            Some(proposal_builder)
-        })
-        .take(self.parameters.proposals as usize)
-        .collect::<Vec<ProposalDefBuilder>>()
-        .chunks(255)
-        .into_iter()
-        .enumerate()
-        .map(|(index, x)| {
-            let vote_plan_name = {
-                if index == 0 {
-                    self.fund_name()
-                } else {
-                    format!("{}_{}", &self.fund_name(), index)
-                }
-            };
+        self.parameters.topology.build()
+    }
 
-            let mut vote_plan_builder = VotePlanDefBuilder::new(&vote_plan_name);
-            vote_plan_builder.owner(&self.committe_wallet);
+    pub fn build_vote_plans(
+        &mut self,
+        rng: &mut ChaChaRng,
+        committee: Option<&PrivateCommittee>,
+    ) -> Result<Vec<VotePlanDef>> {
+        self.parameters
+            .proposals_by_vote_plan()
+            .into_iter()
+            .flat_map(|(settings, indexes)| {
+                let settings = settings.unwrap_or_default();
+                indexes
+                    .chunks(255)
+                    .map(|chunk| (settings.clone(), chunk.to_vec()))
+                    .collect::<Vec<(VotePlanSettings, Vec<usize>)>>()
+            })
+            .enumerate()
+            .map(|(index, (settings, proposals))| {
+                let vote_plan_name = {
+                    if index == 0 {
+                        self.fund_name()
+                    } else {
+                        format!("{}_{}", &self.fund_name(), index)
+                    }
+                };
+
+                let mut vote_plan_builder = VotePlanDefBuilder::new(&vote_plan_name);
+                vote_plan_builder
+                    .owner(settings.committee.as_ref().unwrap_or(&self.committe_wallet));
 
-            if self.parameters.private {
-                vote_plan_builder.payload_type(PayloadType::Private);
+                if settings.private.unwrap_or(self.parameters.private) {
+                    vote_plan_builder.payload_type(PayloadType::Private);
+                    if let Some(committee) = committee {
+                        vote_plan_builder.committee_keys(committee.member_public_keys());
+                    }
+                }
+                vote_plan_builder.vote_phases(
+                    settings.vote_start.unwrap_or(self.parameters.vote_start) as u32,
+                    settings.vote_tally.unwrap_or(self.parameters.vote_tally) as u32,
+                    settings.tally_end.unwrap_or(self.parameters.tally_end) as u32,
+                );
+                for index in proposals {
+                    vote_plan_builder.with_proposal(&mut self.build_proposal(index, rng)?);
+                }
+                Ok(vote_plan_builder.build())
+            })
+            .collect()
+    }
+
+    fn build_proposal(&self, index: usize, rng: &mut ChaChaRng) -> Result<ProposalDefBuilder> {
+        let vote_options = &self.parameters.vote_options;
+        let mut external_id = [0u8; 32];
+        rng.fill_bytes(&mut external_id);
+        let mut proposal_builder = ProposalDefBuilder::new(
+            hex::encode(external_id)
+                .parse()
+                .expect("valid external proposal id"),
+        );
+        let options = vote_options.options(index, self.parameters.challenges);
+        let options_count = u8::try_from(options.len())
+            .ok()
+            .filter(|count| *count > 0)
+            .ok_or_else(|| {
+                ValidationError(vec![format!(
+                    "proposal #{} vote options count ({}) should be in range 1..={}",
+                    index,
+                    options.len(),
+                    u8::MAX
+                )])
+            })?;
+        proposal_builder.options(options_count);
+        match vote_options.action(index) {
+            ProposalAction::OffChain => proposal_builder.action_off_chain(),
+            ProposalAction::Treasury { value } => proposal_builder.action_trasfer_to_rewards(value),
+            ProposalAction::Parameters { value } => proposal_builder.action_rewards_add(value),
+        };
+        Ok(proposal_builder)
+    }
+
+    /// Main committee wallet followed by owners of vote plans defined in parameters
+    pub fn committee_aliases(&self) -> Vec<String> {
+        let mut aliases = vec![self.committe_wallet.clone()];
+        for alias in self
+            .parameters
+            .vote_plans
+            .iter()
+            .filter_map(|settings| settings.committee.clone())
+        {
+            if !aliases.contains(&alias) {
+                aliases.push(alias);
             }
-            vote_plan_builder.vote_phases(
-                self.parameters.vote_start as u32,
-                self.parameters.vote_tally as u32,
-                self.parameters.tally_end as u32,
-            );
-            x.to_vec().iter_mut().for_each(|proposal| {
-                vote_plan_builder.with_proposal(proposal);
-            });
-            vote_plan_builder.build()
-        })
-        .collect()
+        }
+        aliases
+    }
+
+    /// Chain timing and payload type of all vote plans, expressed as in vit station.
+    /// Vote plans phases are shifted relatively to fund timestamps. Empty if there are
+    /// no vote plans settings, since then all vote plans share fund settings
+    pub fn vote_plans_details(&self, controller: &Controller) -> Vec<VotePlanDetails> {
+        if self.parameters.vote_plans.is_empty() {
+            return Vec::new();
+        }
+        self.all_vote_plans_details(controller)
+    }
+
+    /// Phases and payload type of all vote plans, as timestamps
+    pub fn all_vote_plans_details(&self, controller: &Controller) -> Vec<VotePlanDetails> {
+        let epoch_duration =
+            self.parameters.slot_duration as i64 * self.parameters.slots_per_epoch as i64;
+        let shift = |timestamp: Option<NaiveDateTime>, fund_epoch: u64, epoch: u32| {
+            timestamp.unwrap().timestamp() + (epoch as i64 - fund_epoch as i64) * epoch_duration
+        };
+
+        controller
+            .vote_plans()
+            .into_iter()
+            .map(|vote_plan_def| {
+                let vote_plan: VotePlan = vote_plan_def.clone().into();
+                let private = vote_plan.payload_type() == PayloadType::Private;
+                let encryption_key = self
+                    .election_key
+                    .clone()
+                    .filter(|_| private)
+                    .unwrap_or_default();
+
+                VotePlanDetails {
+                    id: vote_plan.to_id().to_string(),
+                    vote_start: shift(
+                        self.parameters.vote_start_timestamp,
+                        self.parameters.vote_start,
+                        vote_plan.vote_start().epoch,
+                    ),
+                    vote_end: shift(
+                        self.parameters.tally_start_timestamp,
+                        self.parameters.vote_tally,
+                        vote_plan.vote_end().epoch,
+                    ),
+                    committee_end: shift(
+                        self.parameters.tally_end_timestamp,
+                        self.parameters.tally_end,
+                        vote_plan.committee_end().epoch,
+                    ),
+                    payload: if private { "private" } else { "public" }.to_string(),
+                    encryption_key,
+                    owner: vote_plan_def.owner(),
+                }
+            })
+            .collect()
     }
 
     pub fn dump_qrs(
@@ -359,13 +523,18 @@ impl QuickVitBackendSettingsBuilder {
         controller: &Controller,
         initials: &HashMap<WalletTemplate, String>,
         child: &ChildPath,
+        rng: &mut ChaChaRng,
     ) -> Result<()> {
         let folder = child.child("qr-codes");
         std::fs::create_dir_all(folder.path())?;
 
+        let committee_aliases = self.committee_aliases();
         let wallets: Vec<(_, _)> = controller
             .wallets()
             .filter(|(_, x)| !x.template().alias().starts_with("committee"))
+            .filter(|(_, x)| !committee_aliases.contains(&x.template().alias()))
+            .filter(|(_, x)| Some(x.template().alias()) != self.faucet_wallet_alias())
+            .filter(|(_, x)| !x.template().alias().starts_with(DELEGATOR_PREFIX))
             .collect();
 
         let total = wallets.len();
@@ -404,7 +573,7 @@ impl QuickVitBackendSettingsBuilder {
                 let zero_funds_pin = initials.zero_funds_pin().unwrap();
 
                 for i in 1..zero_funds_initial_counts + 1 {
-                    let sk = SecretKey::generate(rand::thread_rng());
+                    let sk = SecretKey::generate(&mut *rng);
                     let qr = KeyQrCode::generate(sk.clone(), &pin_to_bytes(&zero_funds_pin));
                     let img = qr.to_img();
                     let png = folder.child(format!("zero_funds_{}_{}.png", i, zero_funds_pin));
@@ -418,47 +587,184 @@ impl QuickVitBackendSettingsBuilder {
         Ok(())
     }
 
+    /// Describes generated environment. Proxy endpoint is not known until backend is started
+    pub fn manifest(
+        &self,
+        controller: &Controller,
+        initials: &HashMap<WalletTemplate, String>,
+        seed: String,
+    ) -> Manifest {
+        let root = controller.working_directory().path();
+        let settings = controller.settings();
+        let qr_file = |name: String| {
+            if self.skip_qr_generation {
+                None
+            } else {
+                Some(Path::new("qr-codes").join(name))
+            }
+        };
+
+        let mut wallets: Vec<WalletManifest> = initials
+            .iter()
+            .filter(|(template, _)| *template.value() > Value::zero())
+            .map(|(template, pin)| WalletManifest {
+                alias: template.alias(),
+                pin: pin.clone(),
+                qr: qr_file(format!("{}_{}.png", template.alias(), pin)),
+                qr_hash: qr_file(format!("{}_{}.txt", template.alias(), pin)),
+                secret: None,
+            })
+            .collect();
+        wallets.sort_by(|left, right| left.alias.cmp(&right.alias));
+
+        if let Some(initials) = &self.parameters.initials {
+            if let Some(pin) = initials.zero_funds_pin() {
+                wallets.extend((1..=initials.zero_funds_count()).map(|i| WalletManifest {
+                    alias: format!("zero_funds_{}", i),
+                    pin: pin.clone(),
+                    qr: qr_file(format!("zero_funds_{}_{}.png", i, pin)),
+                    qr_hash: qr_file(format!("zero_funds_{}.txt", i)),
+                    secret: None,
+                }));
+            }
+        }
+
+        // committee wallets have no pin nor qr code, only secret key used for tally
+        wallets.extend(
+            self.committee_aliases()
+                .into_iter()
+                .map(|alias| WalletManifest {
+                    alias,
+                    pin: String::new(),
+                    qr: None,
+                    qr_hash: None,
+                    secret: None,
+                }),
+        );
+
+        let mut nodes: Vec<NodeManifest> = settings
+            .network_settings
+            .nodes
+            .iter()
+            .map(|(alias, node)| {
+                let address = node.config().rest.listen;
+                let explorer = if self.parameters.topology.node_settings(alias).explorer {
+                    Some(format!("http://{}/explorer/graphql", address))
+                } else {
+                    None
+                };
+                NodeManifest {
+                    alias: alias.clone(),
+                    rest: format!("http://{}/api", address),
+                    explorer,
+                }
+            })
+            .collect();
+        nodes.sort_by(|left, right| left.alias.cmp(&right.alias));
+
+        let block0_file = controller.block0_file();
+        let mut manifest = Manifest {
+            seed,
+            block0: Block0Manifest {
+                path: block0_file
+                    .strip_prefix(root)
+                    .map(PathBuf::from)
+                    .unwrap_or_else(|_| block0_file.clone()),
+                hash: String::new(),
+            },
+            genesis: PathBuf::from("genesis.yaml"),
+            fund_id: self.parameters.fund_id,
+            vote_plans: self.all_vote_plans_details(controller),
+            committees: settings
+                .network_settings
+                .block0
+                .blockchain_configuration
+                .committees
+                .clone(),
+            wallets,
+            nodes,
+            proxy: None,
+        };
+        manifest.update_block0_hash(&settings.network_settings.block0);
+        manifest.locate_secrets(root);
+        manifest
+    }
+
     pub fn build(
         &mut self,
         mut context: ContextChaCha,
     ) -> Result<(VitController, Controller, ValidVotePlanParameters, String)> {
+        // parameters might be uploaded without validation (e.g. posted to service mode),
+        // blockchain settings below rely on it
+        self.parameters.validate()?;
+
         let mut builder = VitControllerBuilder::new(&self.title);
+        let mut rng = rng_from_seed(context.seed());
+        let seed = hex::encode(context.seed().as_ref());
 
         println!("building blockchain parameters..");
 
-        builder.set_topology(self.build_topology());
+        builder.set_topology(self.parameters.topology.clone());
+        builder.set_parameters(self.parameters.clone());
 
+        let consensus = self.parameters.consensus.clone();
         let mut blockchain = Blockchain::new(
-            ConsensusVersion::Bft,
+            consensus.consensus_version(),
             NumberOfSlotsPerEpoch::new(self.parameters.slots_per_epoch)
                 .expect("valid number of slots per epoch"),
             SlotDuration::new(self.parameters.slot_duration)
                 .expect("valid slot duration in seconds"),
-            KesUpdateSpeed::new(46800).expect("valid kes update speed in seconds"),
-            ActiveSlotCoefficient::new(Milli::from_millis(700))
+            KesUpdateSpeed::new(consensus.kes_update_speed)
+                .expect("valid kes update speed in seconds"),
+            ActiveSlotCoefficient::new(Milli::from_millis(consensus.active_slot_coefficient))
                 .expect("active slot coefficient in millis"),
         );
 
         println!("building topology..");
 
-        blockchain.add_leader(LEADER_1);
-        blockchain.add_leader(LEADER_2);
-        blockchain.add_leader(LEADER_3);
-        blockchain.add_leader(LEADER_4);
+        for leader in self.parameters.topology.leader_aliases() {
+            blockchain.add_leader(leader);
+        }
         blockchain.set_linear_fee(self.fees);
+        blockchain.set_block0_date(self.block0_date());
         blockchain.set_discrimination(chain_addr::Discrimination::Production);
 
         if !self.external_committees.is_empty() {
             blockchain.set_external_committees(self.external_committees.clone());
         }
 
-        let committe_wallet = WalletTemplate::new_account(
-            self.committe_wallet.clone(),
-            Value(1_000_000_000),
-            blockchain.discrimination(),
-        );
-        blockchain.add_wallet(committe_wallet);
-        blockchain.add_committee(self.committe_wallet.clone());
+        // vote plans owners are committee members, each of them pays for tally
+        // of its own vote plans
+        for alias in self.committee_aliases() {
+            blockchain.add_wallet(WalletTemplate::new_account(
+                alias.clone(),
+                Value(COMMITTEE_WALLET_VALUE),
+                blockchain.discrimination(),
+            ));
+            blockchain.add_committee(alias);
+        }
+
+        // in genesis praos leaders are stake pool operators, pools are registered in block0
+        // and receive stake from delegator accounts
+        if consensus.is_genesis_praos() {
+            for leader in self.parameters.topology.leader_aliases() {
+                let mut delegator = WalletTemplate::new_account(
+                    ConsensusConfig::delegator_alias(&leader),
+                    Value(consensus.pool_stake(&leader)),
+                    blockchain.discrimination(),
+                );
+                *delegator.delegate_mut() = Some(leader);
+                blockchain.add_wallet(delegator);
+            }
+        }
+
+        if let Some((alias, value)) = &self.faucet_wallet {
+            blockchain.add_wallet(WalletTemplate::new_account(
+                alias.clone(),
+                Value(*value),
+                blockchain.discrimination(),
+            ));
+        }
 
         let child = context.child_directory(self.title());
 
@@ -467,15 +773,37 @@ impl QuickVitBackendSettingsBuilder {
         let mut templates = HashMap::new();
         if let Some(initials) = &self.parameters.initials {
             blockchain.set_external_wallets(initials.external_templates());
-            templates =
-                initials.templates(self.parameters.voting_power, blockchain.discrimination());
-            for (wallet, _) in templates.iter().filter(|(x, _)| *x.value() > Value::zero()) {
+            templates = initials.templates(
+                self.parameters.voting_power,
+                blockchain.discrimination(),
+                &mut rng,
+            )?;
+            let mut wallets: Vec<&WalletTemplate> = templates
+                .keys()
+                .filter(|x| *x.value() > Value::zero())
+                .collect();
+            wallets.sort_by_key(|x| x.alias());
+            for wallet in wallets {
                 blockchain.add_wallet(wallet.clone());
             }
         }
+        let private_committee = if self.parameters.has_private_vote_plans() {
+            println!("building private committee..");
+            Some(PrivateCommittee::generate(
+                &mut rng,
+                self.fund_name().as_bytes(),
+                self.parameters.private_committee.members,
+            ))
+        } else {
+            None
+        };
+        self.election_key = private_committee
+            .as_ref()
+            .map(|committee| committee.election_public_key().to_base32().unwrap());
+
         println!("building voteplan..");
 
-        self.build_vote_plans()
+        self.build_vote_plans(&mut rng, private_committee.as_ref())?
             .into_iter()
             .for_each(|vote_plan_def| blockchain.add_vote_plan(vote_plan_def));
         builder.set_blockchain(blockchain);
@@ -483,15 +811,17 @@ impl QuickVitBackendSettingsBuilder {
 
         println!("building controllers..");
 
-        let (vit_controller, controller) = builder.build_controllers(context)?;
+        let (mut vit_controller, controller) = builder.build_controllers(context)?;
 
         if !self.skip_qr_generation {
-            self.dump_qrs(&controller, &templates, &child)?;
+            self.dump_qrs(&controller, &templates, &child, &mut rng)?;
         }
 
         println!("dumping secret keys..");
 
-        controller.settings().dump_private_vote_keys(child);
+        if let Some(committee) = &private_committee {
+            committee.write_to(child.path().join(COMMITTEE_DIRECTORY))?;
+        }
 
         println!("adjusting vote plan timing..");
 
@@ -504,7 +834,15 @@ impl QuickVitBackendSettingsBuilder {
                 .block0_date,
         );
 
-        let parameters = self.vote_plan_parameters(controller.vote_plans(), &controller.settings());
+        vit_controller.set_vote_plans_details(self.vote_plans_details(&controller));
+
+        println!("dumping manifest..");
+
+        let manifest = self.manifest(&controller, &templates, seed);
+        manifest.write(controller.working_directory().path())?;
+        vit_controller.set_manifest(manifest);
+
+        let parameters = self.vote_plan_parameters(controller.vote_plans());
         Ok((
             vit_controller,
             controller,
@@ -514,6 +852,18 @@ impl QuickVitBackendSettingsBuilder {
     }
 }
 
+fn parse_timestamp_parameter(name: &str, timestamp: &str) -> Result<NaiveDateTime> {
+    parse_timestamp(name, timestamp).map_err(|problem| ValidationError(vec![problem]).into())
+}
+
+/// Rng for data generated by builder itself (initials values, external proposal ids,
+/// zero funds keys), derived from the same seed as scenario context
+pub fn rng_from_seed(seed: &Seed) -> ChaChaRng {
+    let mut bytes = [0u8; 32];
+    bytes.copy_from_slice(seed.as_ref());
+    ChaChaRng::from_seed(bytes)
+}
+
 pub fn pin_to_bytes(pin: &str) -> Vec<u8> {
     pin.chars().map(|x| x.to_digit(10).unwrap() as u8).collect()
 }
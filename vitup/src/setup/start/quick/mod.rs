@@ -3,7 +3,5 @@ mod builder;
 mod mode;
 
 pub use args::QuickStartCommandArgs;
-pub use builder::{
-    QuickVitBackendSettingsBuilder, LEADER_1, LEADER_2, LEADER_3, LEADER_4, WALLET_NODE,
-};
+pub use builder::{QuickVitBackendSettingsBuilder, WALLET_NODE};
 pub use mode::{parse_mode_from_str, Mode};
@@ -0,0 +1,21 @@
+mod private;
+
+pub use private::{Error, PrivateTallyCommand};
+
+use crate::Result;
+use structopt::StructOpt;
+
+#[derive(StructOpt, Debug)]
+#[structopt(setting = structopt::clap::AppSettings::ColoredHelp)]
+pub enum TallyCommand {
+    /// decrypt and publish results of private vote plans
+    Private(PrivateTallyCommand),
+}
+
+impl TallyCommand {
+    pub fn exec(self) -> Result<()> {
+        match self {
+            Self::Private(private) => private.exec().map_err(Into::into),
+        }
+    }
+}
@@ -0,0 +1,278 @@
+use crate::scenario::committee::{self, PrivateCommittee, ProposalResult, COMMITTEE_DIRECTORY};
+use crate::scenario::manifest::{self, Manifest};
+use chain_core::property::{Block as _, Serialize as _};
+use chain_impl_mockchain::certificate::{VotePlan, VoteTallyPayload};
+use chain_impl_mockchain::fragment::Fragment;
+use chain_impl_mockchain::vote::PayloadType;
+use jormungandr_lib::interfaces::{FragmentStatus, PrivateTallyState, Tally, VotePlanStatus};
+use jormungandr_testing_utils::testing::node::{JormungandrRest, RestError};
+use jormungandr_testing_utils::testing::FragmentBuilder;
+use jormungandr_testing_utils::wallet::Wallet;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+use structopt::StructOpt;
+use thiserror::Error;
+
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Tallies private vote plans of environment generated by vitup. Owner wallet of every vote
+/// plan sends encrypted tally, then decryption shares of all committee members are published
+/// with private vote tally. Node has to be in tally phase
+#[derive(StructOpt, Debug)]
+#[structopt(setting = structopt::clap::AppSettings::ColoredHelp)]
+pub struct PrivateTallyCommand {
+    /// environment directory, containing manifest.json and committees keys
+    #[structopt(long = "working-dir", default_value = "./data/vit_backend")]
+    pub working_dir: PathBuf,
+
+    /// alias of committee wallet used for vote plans which owner is not recorded in manifest,
+    /// its secret key has to be recorded in manifest
+    #[structopt(long = "committee-wallet", default_value = "committee_1")]
+    pub committee_wallet: String,
+
+    /// alias of node to send fragments to, first node from manifest is used if not defined
+    #[structopt(long = "node")]
+    pub node: Option<String>,
+
+    /// how long to wait for tally fragments and encrypted tally (in seconds)
+    #[structopt(long = "timeout", default_value = "300")]
+    pub timeout: u64,
+
+    /// results output, defaults to tally_results.json in environment directory
+    #[structopt(long = "output")]
+    pub output: Option<PathBuf>,
+}
+
+/// Decrypted results of private vote plan
+#[derive(Debug, Deserialize, Serialize)]
+pub struct VotePlanResult {
+    pub id: String,
+    pub proposals: Vec<ProposalResult>,
+}
+
+impl PrivateTallyCommand {
+    pub fn exec(self) -> Result<(), Error> {
+        let manifest = Manifest::read(&self.working_dir)?;
+        let committee = PrivateCommittee::read_from(self.working_dir.join(COMMITTEE_DIRECTORY))?;
+
+        let node = match &self.node {
+            Some(alias) => manifest.nodes.iter().find(|node| &node.alias == alias),
+            None => manifest.nodes.first(),
+        }
+        .ok_or_else(|| Error::NodeNotFound(self.node.clone().unwrap_or_default()))?;
+        let rest = JormungandrRest::new(node.rest.clone());
+
+        let block0_configuration: jormungandr_lib::interfaces::Block0Configuration =
+            serde_yaml::from_str(&std::fs::read_to_string(
+                self.working_dir.join(&manifest.genesis),
+            )?)?;
+        let block0 = block0_configuration.to_block();
+        let vote_plans: Vec<VotePlan> = block0
+            .contents
+            .iter()
+            .filter_map(|fragment| match fragment {
+                Fragment::VotePlan(tx) => Some(tx.as_slice().payload().into_payload()),
+                _ => None,
+            })
+            .filter(|vote_plan: &VotePlan| vote_plan.payload_type() == PayloadType::Private)
+            .collect();
+        if vote_plans.is_empty() {
+            return Err(Error::NoPrivateVotePlans);
+        }
+
+        let mut wallets: HashMap<String, Wallet> = HashMap::new();
+        for vote_plan in vote_plans.iter() {
+            let owner = self.owner(&manifest, vote_plan);
+            if !wallets.contains_key(&owner) {
+                let mut wallet =
+                    Wallet::import_account(self.committee_secret(&manifest, &owner)?, None);
+                let state = rest.account_state(&wallet)?;
+                wallet.update_counter(state.counter());
+                wallets.insert(owner, wallet);
+            }
+        }
+
+        let fragment_builder = FragmentBuilder::new(
+            &block0.id().into(),
+            &block0_configuration.blockchain_configuration.linear_fees,
+        );
+
+        println!("sending encrypted tally..");
+        let mut fragments = Vec::new();
+        for vote_plan in vote_plans.iter() {
+            let wallet = wallets
+                .get_mut(&self.owner(&manifest, vote_plan))
+                .expect("owner wallets are loaded");
+            let fragment = fragment_builder.encrypted_tally(wallet, vote_plan);
+            rest.send_raw_fragment(fragment.serialize_as_vec()?)?;
+            fragments.push(fragment.hash().to_string());
+            wallet.confirm_transaction();
+        }
+        self.wait_for_fragments(&rest, &fragments)?;
+
+        println!("waiting for encrypted tally..");
+        let statuses = self.wait_for_encrypted_tally(&rest, &vote_plans)?;
+
+        println!("decrypting tally..");
+        let mut results = Vec::new();
+        let mut fragments = Vec::new();
+        for vote_plan in vote_plans.iter() {
+            let id = vote_plan.to_id().to_string();
+            let status = statuses
+                .iter()
+                .find(|status| status.id.to_string() == id)
+                .ok_or_else(|| Error::VotePlanNotFound(id.clone()))?;
+            let (shares, proposals) =
+                committee.decrypt_vote_plan(&mut rand::rngs::OsRng, status)?;
+
+            let wallet = wallets
+                .get_mut(&self.owner(&manifest, vote_plan))
+                .expect("owner wallets are loaded");
+            let fragment = fragment_builder.vote_tally(
+                wallet,
+                vote_plan,
+                VoteTallyPayload::Private { inner: shares },
+            );
+            rest.send_raw_fragment(fragment.serialize_as_vec()?)?;
+            fragments.push(fragment.hash().to_string());
+            wallet.confirm_transaction();
+            results.push(VotePlanResult { id, proposals });
+        }
+        self.wait_for_fragments(&rest, &fragments)?;
+
+        let output = self
+            .output
+            .unwrap_or_else(|| self.working_dir.join("tally_results.json"));
+        let content = serde_json::to_string_pretty(&results)?;
+        std::fs::write(&output, &content)?;
+        println!("{}", content);
+        println!("results: {:?}", std::fs::canonicalize(&output)?);
+        Ok(())
+    }
+
+    /// Alias of committee wallet which owns vote plan, as recorded in manifest
+    fn owner(&self, manifest: &Manifest, vote_plan: &VotePlan) -> String {
+        let id = vote_plan.to_id().to_string();
+        manifest
+            .vote_plans
+            .iter()
+            .find(|details| details.id == id)
+            .map(|details| details.owner.clone())
+            .filter(|owner| !owner.is_empty())
+            .unwrap_or_else(|| self.committee_wallet.clone())
+    }
+
+    /// Secret key file of committee wallet, as located in environment directory
+    /// when manifest was written
+    fn committee_secret(&self, manifest: &Manifest, alias: &str) -> Result<PathBuf, Error> {
+        manifest
+            .wallets
+            .iter()
+            .find(|wallet| wallet.alias == alias)
+            .and_then(|wallet| wallet.secret.as_ref())
+            .map(|secret| self.working_dir.join(secret))
+            .ok_or_else(|| Error::CommitteeWalletNotFound(alias.to_string()))
+    }
+
+    /// Waits until all fragments are put in block, fails on first rejected fragment
+    fn wait_for_fragments(&self, rest: &JormungandrRest, ids: &[String]) -> Result<(), Error> {
+        let start = Instant::now();
+        loop {
+            let logs: HashMap<String, FragmentStatus> = rest
+                .fragment_logs()?
+                .into_iter()
+                .map(|(id, log)| (id.to_string(), log.status().clone()))
+                .collect();
+            let mut pending = false;
+            for id in ids {
+                match logs.get(id) {
+                    Some(FragmentStatus::InABlock { .. }) => {}
+                    Some(FragmentStatus::Rejected { reason }) => {
+                        return Err(Error::FragmentRejected {
+                            id: id.clone(),
+                            reason: reason.clone(),
+                        })
+                    }
+                    _ => pending = true,
+                }
+            }
+            if !pending {
+                return Ok(());
+            }
+            if start.elapsed() > Duration::from_secs(self.timeout) {
+                return Err(Error::FragmentsPending(self.timeout));
+            }
+            std::thread::sleep(POLL_INTERVAL);
+        }
+    }
+
+    fn wait_for_encrypted_tally(
+        &self,
+        rest: &JormungandrRest,
+        vote_plans: &[VotePlan],
+    ) -> Result<Vec<VotePlanStatus>, Error> {
+        let ids: Vec<String> = vote_plans
+            .iter()
+            .map(|vote_plan| vote_plan.to_id().to_string())
+            .collect();
+        let start = Instant::now();
+        loop {
+            let statuses = rest.vote_plan_statuses()?;
+            let ready = ids.iter().all(|id| {
+                statuses
+                    .iter()
+                    .find(|status| &status.id.to_string() == id)
+                    .map(|status| {
+                        status.proposals.iter().all(|proposal| {
+                            matches!(
+                                proposal.tally,
+                                Some(Tally::Private {
+                                    state: PrivateTallyState::Encrypted { .. }
+                                })
+                            )
+                        })
+                    })
+                    .unwrap_or(false)
+            });
+            if ready {
+                return Ok(statuses);
+            }
+            if start.elapsed() > Duration::from_secs(self.timeout) {
+                return Err(Error::Timeout(self.timeout));
+            }
+            std::thread::sleep(POLL_INTERVAL);
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("node {0} not found in manifest")]
+    NodeNotFound(String),
+    #[error("secret key of committee wallet {0} is not recorded in manifest")]
+    CommitteeWalletNotFound(String),
+    #[error("there are no private vote plans in block0")]
+    NoPrivateVotePlans,
+    #[error("vote plan {0} not found on node")]
+    VotePlanNotFound(String),
+    #[error("encrypted tally was not minted in {0} s, check if node is in tally phase")]
+    Timeout(u64),
+    #[error("fragment {id} was rejected: {reason}")]
+    FragmentRejected { id: String, reason: String },
+    #[error("tally fragments were not put in block in {0} s")]
+    FragmentsPending(u64),
+    #[error("node rest error")]
+    Rest(#[from] RestError),
+    #[error("manifest error")]
+    Manifest(#[from] manifest::Error),
+    #[error("private committee error")]
+    Committee(#[from] committee::Error),
+    #[error("IO error")]
+    Io(#[from] std::io::Error),
+    #[error("cannot serialize results")]
+    Serde(#[from] serde_json::Error),
+    #[error("cannot read genesis")]
+    SerdeYaml(#[from] serde_yaml::Error),
+}
@@ -0,0 +1,30 @@
+use crate::config::{DataGenerationConfig, ValidationError, VitStartParameters};
+use crate::Result;
+use std::path::PathBuf;
+use structopt::StructOpt;
+
+#[derive(StructOpt, Debug)]
+#[structopt(setting = structopt::clap::AppSettings::ColoredHelp)]
+pub struct ConfigValidateCommand {
+    /// data generation config (json) or vit start parameters (json or yaml)
+    #[structopt(long = "config")]
+    pub config: PathBuf,
+}
+
+impl ConfigValidateCommand {
+    pub fn exec(self) -> Result<()> {
+        let contents = std::fs::read_to_string(&self.config)?;
+
+        let problems = match serde_json::from_str::<DataGenerationConfig>(&contents) {
+            Ok(config) => config.problems(),
+            Err(_) => serde_yaml::from_str::<VitStartParameters>(&contents)?.problems(),
+        };
+
+        if problems.is_empty() {
+            println!("{:?} is valid", self.config);
+            Ok(())
+        } else {
+            Err(ValidationError(problems).into())
+        }
+    }
+}
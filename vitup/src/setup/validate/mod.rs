@@ -1,18 +1,22 @@
+mod config;
 mod ideascale;
 
 use crate::Result;
+use config::ConfigValidateCommand;
 use ideascale::IdeascaleValidateCommand;
 use structopt::StructOpt;
 
 #[derive(StructOpt, Debug)]
 #[structopt(setting = structopt::clap::AppSettings::ColoredHelp)]
 pub enum ValidateCommand {
+    Config(ConfigValidateCommand),
     Ideascale(IdeascaleValidateCommand),
 }
 
 impl ValidateCommand {
     pub fn exec(self) -> Result<()> {
         match self {
+            Self::Config(config) => config.exec(),
             Self::Ideascale(ideascale) => ideascale.exec(),
         }
     }
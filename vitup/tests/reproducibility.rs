@@ -0,0 +1,81 @@
+use assert_fs::TempDir;
+use jormungandr_scenario_tests::prepare_command;
+use jormungandr_scenario_tests::Seed;
+use jormungandr_scenario_tests::{Context, ProgressBarMode};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use vitup::setup::start::QuickVitBackendSettingsBuilder;
+
+const SEED: &str = "1b3c5d7e9f1b3c5d7e9f1b3c5d7e9f1b3c5d7e9f1b3c5d7e9f1b3c5d7e9f1b3c";
+
+#[test]
+pub fn the_same_seed_generates_the_same_environment() {
+    let first = TempDir::new().unwrap();
+    let second = TempDir::new().unwrap();
+
+    let first_root = generate(first.path());
+    let second_root = generate(second.path());
+
+    for file in &["genesis.yaml", "block0.bin"] {
+        assert_eq!(
+            std::fs::read(first_root.join(file)).unwrap(),
+            std::fs::read(second_root.join(file)).unwrap(),
+            "{} differs",
+            file
+        );
+    }
+
+    let first_qrs = files(&first_root.join("qr-codes"));
+    let second_qrs = files(&second_root.join("qr-codes"));
+    assert!(!first_qrs.is_empty(), "no qr codes generated");
+    assert_eq!(
+        first_qrs
+            .iter()
+            .map(|path| path.strip_prefix(&first_root).unwrap())
+            .collect::<Vec<_>>(),
+        second_qrs
+            .iter()
+            .map(|path| path.strip_prefix(&second_root).unwrap())
+            .collect::<Vec<_>>(),
+        "qr codes names differ"
+    );
+    for (first_qr, second_qr) in first_qrs.iter().zip(second_qrs.iter()) {
+        assert_eq!(
+            std::fs::read(first_qr).unwrap(),
+            std::fs::read(second_qr).unwrap(),
+            "{:?} differs",
+            first_qr
+        );
+    }
+}
+
+fn generate(testing_directory: &Path) -> PathBuf {
+    let context = Context::new(
+        Seed::from_str(SEED).unwrap(),
+        prepare_command(PathBuf::from_str("jormungandr").unwrap()),
+        prepare_command(PathBuf::from_str("jcli").unwrap()),
+        Some(testing_directory.to_path_buf()),
+        true,
+        ProgressBarMode::None,
+        "info".to_string(),
+    );
+
+    let mut quick_setup = QuickVitBackendSettingsBuilder::new();
+    quick_setup
+        .initials_count(10, "1234")
+        .proposals_count(10)
+        .block0_timestamp(Some("2021-10-01 10:00:00".to_string()))
+        .unwrap();
+    let title = quick_setup.title();
+    quick_setup.build(context).unwrap();
+    testing_directory.join(title)
+}
+
+fn files(directory: &Path) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = std::fs::read_dir(directory)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect();
+    files.sort();
+    files
+}